@@ -1,11 +1,14 @@
 multiversx_sc::imports!();
 
+use crate::pagination::get_page;
+
 #[multiversx_sc::module]
 pub trait BlacklistModule:
     crate::permissions::PermissionsModule
     + crate::launch_stage::LaunchStageModule
     + crate::tickets::TicketsModule
     + crate::token_send::TokenSendModule
+    + crate::launchpad_stats::LaunchpadStatsModule
     + crate::config::ConfigModule
     + crate::common_events::CommonEventsModule
 {
@@ -14,40 +17,77 @@ pub trait BlacklistModule:
         self.require_before_winner_selection();
 
         let blacklist_mapper = self.blacklist();
+        let mut blacklisted_users_mapper = self.blacklisted_users();
         for address in users_list {
             require!(
                 !blacklist_mapper.contains(&address),
                 "User already blacklisted"
             );
 
-            require!(
-                !self.ticket_range_for_address(&address).is_empty(),
-                "User has no ticket allowance"
-            );
+            let has_ticket_allowance = self.sale_round_ids().any(|sale_round_id| {
+                !self
+                    .ticket_ranges_for_address(sale_round_id, &address)
+                    .is_empty()
+            });
+            require!(has_ticket_allowance, "User has no ticket allowance");
 
-            let confirmed_tickets_mapper = self.nr_confirmed_tickets(&address);
-            let nr_confirmed_tickets = confirmed_tickets_mapper.get();
-            if nr_confirmed_tickets > 0 {
-                self.refund_ticket_payment(&address, nr_confirmed_tickets);
-                confirmed_tickets_mapper.clear();
+            // tickets of the sale rounds already in winner selection are kept
+            for sale_round_id in self.sale_round_ids() {
+                if !self.is_before_winner_selection(sale_round_id) {
+                    continue;
+                }
+
+                let confirmed_tickets_mapper = self.nr_confirmed_tickets(sale_round_id, &address);
+                let nr_confirmed_tickets = confirmed_tickets_mapper.get();
+                if nr_confirmed_tickets > 0 {
+                    self.refund_ticket_payment(sale_round_id, &address, nr_confirmed_tickets);
+                    confirmed_tickets_mapper.clear();
+                }
             }
 
             blacklist_mapper.add(&address);
+            blacklisted_users_mapper.insert(address);
         }
+
+        self.emit_add_users_to_blacklist_event(users_list.clone());
     }
 
     fn remove_users_from_blacklist(&self, users_list: MultiValueEncoded<ManagedAddress>) {
         self.require_extended_permissions();
         self.require_before_winner_selection();
 
+        let users_vec = users_list.to_vec();
         let blacklist_mapper = self.blacklist();
-        for address in users_list {
+        let mut blacklisted_users_mapper = self.blacklisted_users();
+        for address in &users_vec {
             require!(
                 blacklist_mapper.contains(&address),
                 "User is not blacklisted"
             );
             blacklist_mapper.remove(&address);
+            blacklisted_users_mapper.swap_remove(&address);
         }
+
+        self.emit_remove_users_from_blacklist_event(users_vec);
+    }
+
+    /// Copies the blacklist entries written before the enumerable copy existed,
+    /// e.g. by a contract upgraded in place, so they are listed and removed by the storage cleanup.
+    /// The owner may split the users over several calls. Users not in the blacklist are ignored.
+    /// Returns the number of users added.
+    #[only_owner]
+    #[endpoint(backfillBlacklistedUsers)]
+    fn backfill_blacklisted_users(&self, users_list: MultiValueEncoded<ManagedAddress>) -> usize {
+        let blacklist_mapper = self.blacklist();
+        let mut blacklisted_users_mapper = self.blacklisted_users();
+        let mut nr_users_added = 0;
+        for address in users_list {
+            if blacklist_mapper.contains(&address) && blacklisted_users_mapper.insert(address) {
+                nr_users_added += 1;
+            }
+        }
+
+        nr_users_added
     }
 
     #[view(isUserBlacklisted)]
@@ -55,6 +95,20 @@ pub trait BlacklistModule:
         self.blacklist().contains(address)
     }
 
+    /// Users blacklisted before the upgrade that added this view are only listed once backfilled
+    #[view(getBlacklistedUsers)]
+    fn get_blacklisted_users(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        get_page(&self.blacklisted_users(), offset, limit)
+    }
+
     #[storage_mapper("blacklisted")]
     fn blacklist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    /// Enumerable copy of the blacklist, which stays the source of truth
+    #[storage_mapper("blacklistedUsers")]
+    fn blacklisted_users(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
@@ -0,0 +1,77 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ClaimStakingEndpoint<M: ManagedTypeApi> {
+    pub sc_address: ManagedAddress<M>,
+    pub endpoint_name: ManagedBuffer<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ClaimAndStakeModule: crate::common_events::CommonEventsModule {
+    /// The endpoint receives the claimed launchpad tokens and must send the resulting
+    /// position back to this contract, which forwards it to the claiming user
+    #[only_owner]
+    #[endpoint(setClaimStakingEndpoint)]
+    fn set_claim_staking_endpoint(&self, sc_address: ManagedAddress, endpoint_name: ManagedBuffer) {
+        require!(
+            !sc_address.is_zero() && self.blockchain().is_smart_contract(&sc_address),
+            "Invalid SC address"
+        );
+        require!(!endpoint_name.is_empty(), "Invalid endpoint name");
+
+        let staking_endpoint = ClaimStakingEndpoint {
+            sc_address,
+            endpoint_name,
+        };
+        self.claim_staking_endpoint().set(&staking_endpoint);
+        self.emit_set_claim_staking_endpoint_event(Some(staking_endpoint));
+    }
+
+    #[only_owner]
+    #[endpoint(removeClaimStakingEndpoint)]
+    fn remove_claim_staking_endpoint(&self) {
+        self.claim_staking_endpoint().clear();
+        self.emit_set_claim_staking_endpoint_event(None);
+    }
+
+    fn require_claim_staking_endpoint_set(&self) {
+        require!(
+            !self.claim_staking_endpoint().is_empty(),
+            "Staking endpoint not set"
+        );
+    }
+
+    fn stake_launchpad_tokens_fn(
+        &self,
+        address: &ManagedAddress,
+        payment: &EsdtTokenPayment<Self::Api>,
+    ) {
+        let staking_endpoint = self.claim_staking_endpoint().get();
+        let ((), back_transfers) = self
+            .send()
+            .contract_call::<()>(staking_endpoint.sc_address, staking_endpoint.endpoint_name)
+            .with_esdt_transfer(payment.clone())
+            .execute_on_dest_context_with_back_transfers();
+
+        if back_transfers.total_egld_amount > 0 {
+            self.send()
+                .direct_egld(address, &back_transfers.total_egld_amount);
+        }
+        if !back_transfers.esdt_payments.is_empty() {
+            self.send()
+                .direct_multi(address, &back_transfers.esdt_payments);
+        }
+
+        self.emit_stake_launchpad_tokens_event(
+            address.clone(),
+            payment.clone(),
+            back_transfers.total_egld_amount,
+            back_transfers.esdt_payments,
+        );
+    }
+
+    #[view(getClaimStakingEndpoint)]
+    #[storage_mapper("claimStakingEndpoint")]
+    fn claim_staking_endpoint(&self) -> SingleValueMapper<ClaimStakingEndpoint<Self::Api>>;
+}
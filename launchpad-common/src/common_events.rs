@@ -1,28 +1,186 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::{
+    claim_and_stake::ClaimStakingEndpoint,
+    config::{SaleRoundId, TermsOfSale, TimelineConfig},
+    kyc::KycSigner,
+};
+
+/// Encoded as the first field of every event payload, so indexers can pick the right decoder.
+/// Must be bumped whenever an existing payload layout changes.
+pub const EVENTS_SCHEMA_VERSION: u8 = 2;
+
 #[derive(TypeAbi, TopEncode)]
 pub struct RefundTicketPaymentEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    sale_round_id: SaleRoundId,
     tickets_refunded: usize,
     token_payment: EgldOrEsdtTokenPayment<M>,
 }
 
 #[derive(TypeAbi, TopEncode)]
 pub struct SetTicketPriceEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    sale_round_id: SaleRoundId,
     ticket_price: EgldOrEsdtTokenPayment<M>,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct SetLaunchpadTokensPerWinningTicketEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    launchpad_tokens_per_winning_ticket: BigUint<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetTimelineEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    timeline: TimelineConfig,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetClaimDeadlineRoundEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    claim_deadline_round: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetSupportAddressEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    support_address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct DepositLaunchpadTokensEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    total_winning_tickets: usize,
+    token_payment: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct AddTicketsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    users_count: usize,
+    total_tickets_added: usize,
+    total_guaranteed_tickets_added: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct AddUserTicketsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    address: ManagedAddress<M>,
+    first_ticket_id: usize,
+    nr_tickets: usize,
+    guaranteed_tickets: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetUserTicketAllowanceEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    address: ManagedAddress<M>,
+    previous_nr_tickets: usize,
+    nr_tickets: usize,
+    guaranteed_tickets: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct TermsOfSaleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    terms: TermsOfSale<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetKycSignerEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    signer: Option<KycSigner<M>>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetClaimStakingEndpointEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    staking_endpoint: Option<ClaimStakingEndpoint<M>>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct StakeLaunchpadTokensEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    staked_tokens: EsdtTokenPayment<M>,
+    position_egld_amount: BigUint<M>,
+    position_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct AddUsersToBlacklistEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    users: ManagedVec<M, ManagedAddress<M>>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct RemoveUsersFromBlacklistEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    users: ManagedVec<M, ManagedAddress<M>>,
+}
+
 #[derive(TypeAbi, TopEncode)]
 pub struct ConfirmTicketsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    sale_round_id: SaleRoundId,
     tickets_confirmed: usize,
     total_confirmed: usize,
     total_tickets: usize,
@@ -31,24 +189,103 @@ pub struct ConfirmTicketsEvent<M: ManagedTypeApi> {
 
 #[derive(TypeAbi, TopEncode)]
 pub struct FilterTicketsCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    sale_round_id: SaleRoundId,
     total_tickets_after_filtering: usize,
 }
 
 #[derive(TypeAbi, TopEncode)]
 pub struct SelectWinnersCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    sale_round_id: SaleRoundId,
     total_winning_tickets: usize,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct AbortWinnerSelectionEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    abandoned_seed: ManagedBuffer<M>,
+    tickets_shuffled: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct DistributeGuaranteedTicketsCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    total_additional_winning_tickets: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct LaunchpadResultsComputedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    nr_confirmed_tickets: usize,
+    nr_winning_tickets: usize,
+    launchpad_tokens_won: BigUint<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimLaunchpadTokensEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    token_payment: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimTicketPaymentEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    ticket_payment: EgldOrEsdtTokenPayment<M>,
+    extra_launchpad_tokens: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct StorageCleanupCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    ticket_payment_forwarded: EgldOrEsdtTokenPayment<M>,
+    launchpad_tokens_forwarded: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct AddSaleRoundEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    sale_round_id: SaleRoundId,
+    ticket_price: EgldOrEsdtTokenPayment<M>,
+    nr_winning_tickets: usize,
+    timeline: TimelineConfig,
+}
+
 #[multiversx_sc::module]
 pub trait CommonEventsModule {
     fn emit_refund_ticket_payment_event(
         &self,
+        sale_round_id: SaleRoundId,
         tickets_refunded: usize,
         token_payment: EgldOrEsdtTokenPayment<Self::Api>,
     ) {
@@ -60,16 +297,22 @@ pub trait CommonEventsModule {
             round,
             epoch,
             RefundTicketPaymentEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
+                sale_round_id,
                 tickets_refunded,
                 token_payment,
             },
         )
     }
 
-    fn emit_set_ticket_price_event(&self, ticket_price: EgldOrEsdtTokenPayment<Self::Api>) {
+    fn emit_set_ticket_price_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_price: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
@@ -78,116 +321,829 @@ pub trait CommonEventsModule {
             round,
             epoch,
             SetTicketPriceEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
+                sale_round_id,
                 ticket_price,
             },
         )
     }
 
-    fn emit_confirm_tickets_event(
+    fn emit_set_launchpad_tokens_per_winning_ticket_event(
         &self,
-        tickets_confirmed: usize,
-        total_confirmed: usize,
-        total_tickets: usize,
-        token_payment: EgldOrEsdtTokenPayment<Self::Api>,
+        launchpad_tokens_per_winning_ticket: BigUint,
     ) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.confirm_tickets_event(
+        self.set_launchpad_tokens_per_winning_ticket_event(
             user.clone(),
             round,
             epoch,
-            ConfirmTicketsEvent {
+            SetLaunchpadTokensPerWinningTicketEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
-                tickets_confirmed,
-                total_confirmed,
-                total_tickets,
-                token_payment,
+                launchpad_tokens_per_winning_ticket,
             },
         )
     }
 
-    fn emit_filter_tickets_completed_event(&self, total_tickets_after_filtering: usize) {
+    fn emit_set_timeline_event(&self, sale_round_id: SaleRoundId, timeline: TimelineConfig) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.filter_tickets_completed_event(
+        self.set_timeline_event(
             user.clone(),
             round,
             epoch,
-            FilterTicketsCompletedEvent {
+            SetTimelineEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
-                total_tickets_after_filtering,
+                sale_round_id,
+                timeline,
             },
         )
     }
 
-    fn emit_select_winners_completed_event(&self, total_winning_tickets: usize) {
+    fn emit_set_claim_deadline_round_event(&self, claim_deadline_round: u64) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.select_winners_completed_event(
+        self.set_claim_deadline_round_event(
             user.clone(),
             round,
             epoch,
-            SelectWinnersCompletedEvent {
+            SetClaimDeadlineRoundEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
-                total_winning_tickets,
+                claim_deadline_round,
             },
         )
     }
 
-    #[event("refundTicketPayment")]
-    fn refund_ticket_payment_event(
-        &self,
-        #[indexed] caller: ManagedAddress,
-        #[indexed] round: u64,
-        #[indexed] epoch: u64,
-        claim_egld_event: RefundTicketPaymentEvent<Self::Api>,
-    );
+    fn emit_set_support_address_event(&self, support_address: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_support_address_event(
+            user.clone(),
+            round,
+            epoch,
+            SetSupportAddressEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                support_address,
+            },
+        )
+    }
 
-    #[event("setTicketPrice")]
-    fn set_ticket_price_event(
+    fn emit_deposit_launchpad_tokens_event(
         &self,
-        #[indexed] caller: ManagedAddress,
-        #[indexed] round: u64,
-        #[indexed] epoch: u64,
-        set_ticket_price_event: SetTicketPriceEvent<Self::Api>,
-    );
+        total_winning_tickets: usize,
+        token_payment: EsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.deposit_launchpad_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            DepositLaunchpadTokensEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                total_winning_tickets,
+                token_payment,
+            },
+        )
+    }
 
-    #[event("confirmTickets")]
-    fn confirm_tickets_event(
+    fn emit_add_tickets_event(
         &self,
-        #[indexed] caller: ManagedAddress,
-        #[indexed] round: u64,
-        #[indexed] epoch: u64,
-        confirm_tickets_event: ConfirmTicketsEvent<Self::Api>,
-    );
+        sale_round_id: SaleRoundId,
+        users_count: usize,
+        total_tickets_added: usize,
+        total_guaranteed_tickets_added: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.add_tickets_event(
+            user.clone(),
+            round,
+            epoch,
+            AddTicketsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                users_count,
+                total_tickets_added,
+                total_guaranteed_tickets_added,
+            },
+        )
+    }
 
-    #[event("filterTicketsCompleted")]
-    fn filter_tickets_completed_event(
+    fn emit_add_user_tickets_event(
         &self,
-        #[indexed] caller: ManagedAddress,
-        #[indexed] round: u64,
-        #[indexed] epoch: u64,
-        filter_tickets_completed_event: FilterTicketsCompletedEvent<Self::Api>,
-    );
+        sale_round_id: SaleRoundId,
+        address: ManagedAddress,
+        first_ticket_id: usize,
+        nr_tickets: usize,
+        guaranteed_tickets: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.add_user_tickets_event(
+            user.clone(),
+            round,
+            epoch,
+            AddUserTicketsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                address,
+                first_ticket_id,
+                nr_tickets,
+                guaranteed_tickets,
+            },
+        )
+    }
 
-    #[event("selectWinnersCompleted")]
-    fn select_winners_completed_event(
+    fn emit_set_user_ticket_allowance_event(
         &self,
-        #[indexed] caller: ManagedAddress,
-        #[indexed] round: u64,
-        #[indexed] epoch: u64,
-        select_winners_completed_event: SelectWinnersCompletedEvent<Self::Api>,
+        sale_round_id: SaleRoundId,
+        address: ManagedAddress,
+        previous_nr_tickets: usize,
+        nr_tickets: usize,
+        guaranteed_tickets: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_user_ticket_allowance_event(
+            user.clone(),
+            round,
+            epoch,
+            SetUserTicketAllowanceEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                address,
+                previous_nr_tickets,
+                nr_tickets,
+                guaranteed_tickets,
+            },
+        )
+    }
+
+    fn emit_set_terms_of_sale_event(&self, terms: TermsOfSale<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_terms_of_sale_event(
+            user.clone(),
+            round,
+            epoch,
+            TermsOfSaleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                terms,
+            },
+        )
+    }
+
+    fn emit_accept_terms_of_sale_event(&self, terms: TermsOfSale<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.accept_terms_of_sale_event(
+            user.clone(),
+            round,
+            epoch,
+            TermsOfSaleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                terms,
+            },
+        )
+    }
+
+    fn emit_set_kyc_signer_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        signer: Option<KycSigner<Self::Api>>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_kyc_signer_event(
+            user.clone(),
+            round,
+            epoch,
+            SetKycSignerEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                signer,
+            },
+        )
+    }
+
+    fn emit_set_claim_staking_endpoint_event(
+        &self,
+        staking_endpoint: Option<ClaimStakingEndpoint<Self::Api>>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_claim_staking_endpoint_event(
+            user.clone(),
+            round,
+            epoch,
+            SetClaimStakingEndpointEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                staking_endpoint,
+            },
+        )
+    }
+
+    fn emit_stake_launchpad_tokens_event(
+        &self,
+        user: ManagedAddress,
+        staked_tokens: EsdtTokenPayment<Self::Api>,
+        position_egld_amount: BigUint,
+        position_payments: ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.stake_launchpad_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            StakeLaunchpadTokensEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                staked_tokens,
+                position_egld_amount,
+                position_payments,
+            },
+        )
+    }
+
+    fn emit_add_users_to_blacklist_event(&self, users: ManagedVec<ManagedAddress>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.add_users_to_blacklist_event(
+            user.clone(),
+            round,
+            epoch,
+            AddUsersToBlacklistEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                users,
+            },
+        )
+    }
+
+    fn emit_remove_users_from_blacklist_event(&self, users: ManagedVec<ManagedAddress>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.remove_users_from_blacklist_event(
+            user.clone(),
+            round,
+            epoch,
+            RemoveUsersFromBlacklistEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                users,
+            },
+        )
+    }
+
+    fn emit_confirm_tickets_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        tickets_confirmed: usize,
+        total_confirmed: usize,
+        total_tickets: usize,
+        token_payment: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.confirm_tickets_event(
+            user.clone(),
+            round,
+            epoch,
+            ConfirmTicketsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                tickets_confirmed,
+                total_confirmed,
+                total_tickets,
+                token_payment,
+            },
+        )
+    }
+
+    fn emit_filter_tickets_completed_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        total_tickets_after_filtering: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.filter_tickets_completed_event(
+            user.clone(),
+            round,
+            epoch,
+            FilterTicketsCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                total_tickets_after_filtering,
+            },
+        )
+    }
+
+    fn emit_select_winners_completed_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        total_winning_tickets: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.select_winners_completed_event(
+            user.clone(),
+            round,
+            epoch,
+            SelectWinnersCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                total_winning_tickets,
+            },
+        )
+    }
+
+    fn emit_abort_winner_selection_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        abandoned_seed: ManagedBuffer,
+        tickets_shuffled: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.abort_winner_selection_event(
+            user.clone(),
+            round,
+            epoch,
+            AbortWinnerSelectionEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                abandoned_seed,
+                tickets_shuffled,
+            },
+        )
+    }
+
+    fn emit_distribute_guaranteed_tickets_completed_event(
+        &self,
+        total_additional_winning_tickets: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.distribute_guaranteed_tickets_completed_event(
+            user.clone(),
+            round,
+            epoch,
+            DistributeGuaranteedTicketsCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                total_additional_winning_tickets,
+            },
+        )
+    }
+
+    fn emit_launchpad_results_computed_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        nr_confirmed_tickets: usize,
+        nr_winning_tickets: usize,
+        launchpad_tokens_won: BigUint,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.launchpad_results_computed_event(
+            user.clone(),
+            round,
+            epoch,
+            LaunchpadResultsComputedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                nr_confirmed_tickets,
+                nr_winning_tickets,
+                launchpad_tokens_won,
+            },
+        )
+    }
+
+    fn emit_claim_launchpad_tokens_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        token_payment: EsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_launchpad_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimLaunchpadTokensEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                token_payment,
+            },
+        )
+    }
+
+    fn emit_claim_ticket_payment_event(
+        &self,
+        ticket_payment: EgldOrEsdtTokenPayment<Self::Api>,
+        extra_launchpad_tokens: EsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_ticket_payment_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimTicketPaymentEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                ticket_payment,
+                extra_launchpad_tokens,
+            },
+        )
+    }
+
+    fn emit_add_sale_round_event(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_price: EgldOrEsdtTokenPayment<Self::Api>,
+        nr_winning_tickets: usize,
+        timeline: TimelineConfig,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.add_sale_round_event(
+            user.clone(),
+            round,
+            epoch,
+            AddSaleRoundEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                sale_round_id,
+                ticket_price,
+                nr_winning_tickets,
+                timeline,
+            },
+        )
+    }
+
+    fn emit_storage_cleanup_completed_event(
+        &self,
+        ticket_payment_forwarded: EgldOrEsdtTokenPayment,
+        launchpad_tokens_forwarded: EsdtTokenPayment,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.storage_cleanup_completed_event(
+            user.clone(),
+            round,
+            epoch,
+            StorageCleanupCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                ticket_payment_forwarded,
+                launchpad_tokens_forwarded,
+            },
+        )
+    }
+
+    #[event("refundTicketPayment")]
+    fn refund_ticket_payment_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_egld_event: RefundTicketPaymentEvent<Self::Api>,
+    );
+
+    #[event("setTicketPrice")]
+    fn set_ticket_price_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_ticket_price_event: SetTicketPriceEvent<Self::Api>,
+    );
+
+    #[event("setLaunchpadTokensPerWinningTicket")]
+    fn set_launchpad_tokens_per_winning_ticket_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_launchpad_tokens_per_winning_ticket_event: SetLaunchpadTokensPerWinningTicketEvent<
+            Self::Api,
+        >,
+    );
+
+    #[event("setTimeline")]
+    fn set_timeline_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_timeline_event: SetTimelineEvent<Self::Api>,
+    );
+
+    #[event("setClaimDeadlineRound")]
+    fn set_claim_deadline_round_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_claim_deadline_round_event: SetClaimDeadlineRoundEvent<Self::Api>,
+    );
+
+    #[event("setSupportAddress")]
+    fn set_support_address_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_support_address_event: SetSupportAddressEvent<Self::Api>,
+    );
+
+    #[event("depositLaunchpadTokens")]
+    fn deposit_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        deposit_launchpad_tokens_event: DepositLaunchpadTokensEvent<Self::Api>,
+    );
+
+    #[event("addTickets")]
+    fn add_tickets_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        add_tickets_event: AddTicketsEvent<Self::Api>,
+    );
+
+    #[event("addUserTickets")]
+    fn add_user_tickets_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        add_user_tickets_event: AddUserTicketsEvent<Self::Api>,
+    );
+
+    #[event("setUserTicketAllowance")]
+    fn set_user_ticket_allowance_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_user_ticket_allowance_event: SetUserTicketAllowanceEvent<Self::Api>,
+    );
+
+    #[event("setTermsOfSale")]
+    fn set_terms_of_sale_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_terms_of_sale_event: TermsOfSaleEvent<Self::Api>,
+    );
+
+    #[event("acceptTermsOfSale")]
+    fn accept_terms_of_sale_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        accept_terms_of_sale_event: TermsOfSaleEvent<Self::Api>,
+    );
+
+    #[event("setKycSigner")]
+    fn set_kyc_signer_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_kyc_signer_event: SetKycSignerEvent<Self::Api>,
+    );
+
+    #[event("setClaimStakingEndpoint")]
+    fn set_claim_staking_endpoint_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_claim_staking_endpoint_event: SetClaimStakingEndpointEvent<Self::Api>,
+    );
+
+    #[event("stakeLaunchpadTokens")]
+    fn stake_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        stake_launchpad_tokens_event: StakeLaunchpadTokensEvent<Self::Api>,
+    );
+
+    #[event("addUsersToBlacklist")]
+    fn add_users_to_blacklist_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        add_users_to_blacklist_event: AddUsersToBlacklistEvent<Self::Api>,
+    );
+
+    #[event("removeUsersFromBlacklist")]
+    fn remove_users_from_blacklist_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        remove_users_from_blacklist_event: RemoveUsersFromBlacklistEvent<Self::Api>,
+    );
+
+    #[event("confirmTickets")]
+    fn confirm_tickets_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        confirm_tickets_event: ConfirmTicketsEvent<Self::Api>,
+    );
+
+    #[event("filterTicketsCompleted")]
+    fn filter_tickets_completed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        filter_tickets_completed_event: FilterTicketsCompletedEvent<Self::Api>,
+    );
+
+    #[event("selectWinnersCompleted")]
+    fn select_winners_completed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        select_winners_completed_event: SelectWinnersCompletedEvent<Self::Api>,
+    );
+
+    #[event("abortWinnerSelection")]
+    fn abort_winner_selection_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        abort_winner_selection_event: AbortWinnerSelectionEvent<Self::Api>,
+    );
+
+    #[event("distributeGuaranteedTicketsCompleted")]
+    fn distribute_guaranteed_tickets_completed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        distribute_guaranteed_tickets_completed_event: DistributeGuaranteedTicketsCompletedEvent<
+            Self::Api,
+        >,
+    );
+
+    #[event("launchpadResultsComputed")]
+    fn launchpad_results_computed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        launchpad_results_computed_event: LaunchpadResultsComputedEvent<Self::Api>,
+    );
+
+    #[event("claimLaunchpadTokens")]
+    fn claim_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_launchpad_tokens_event: ClaimLaunchpadTokensEvent<Self::Api>,
+    );
+
+    #[event("claimTicketPayment")]
+    fn claim_ticket_payment_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_ticket_payment_event: ClaimTicketPaymentEvent<Self::Api>,
+    );
+
+    #[event("storageCleanupCompleted")]
+    fn storage_cleanup_completed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        storage_cleanup_completed_event: StorageCleanupCompletedEvent<Self::Api>,
+    );
+
+    #[event("addSaleRound")]
+    fn add_sale_round_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        add_sale_round_event: AddSaleRoundEvent<Self::Api>,
     );
 }
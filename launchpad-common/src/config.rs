@@ -1,19 +1,54 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use core::ops::RangeInclusive;
+
+use multiversx_sc::{api::ErrorApi, storage::StorageKey};
+
+/// Identifies a sale round (e.g. seed, private, public) of the launchpad, not to be confused
+/// with the block rounds used for the timeline
+pub type SaleRoundId = u32;
+
+pub const FIRST_SALE_ROUND_ID: SaleRoundId = 1;
+
+/// The first sale round keeps the storage keys of the launchpads deployed before the sale rounds,
+/// while the other ones are suffixed with the sale round ID
+pub fn sale_round_storage_key<M: ManagedTypeApi + ErrorApi + 'static>(
+    base_key: &[u8],
+    sale_round_id: SaleRoundId,
+) -> StorageKey<M> {
+    let mut key = StorageKey::new(base_key);
+    if sale_round_id != FIRST_SALE_ROUND_ID {
+        key.append_item(&sale_round_id);
+    }
+
+    key
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode)]
 pub struct TokenAmountPair<M: ManagedTypeApi> {
     pub token_id: EgldOrEsdtTokenIdentifier<M>,
     pub amount: BigUint<M>,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode)]
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct TimelineConfig {
     pub confirmation_period_start_round: u64,
     pub winner_selection_start_round: u64,
     pub claim_start_round: u64,
 }
 
+/// About a week of 6 seconds block rounds, the shortest claim period a deadline may leave
+pub const MIN_CLAIM_PERIOD_ROUNDS: u64 = 100_800;
+
+pub const TERMS_OF_SALE_HASH_LEN: usize = 32;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct TermsOfSale<M: ManagedTypeApi> {
+    pub hash: ManagedByteArray<M, TERMS_OF_SALE_HASH_LEN>,
+    pub version: u32,
+}
+
 #[multiversx_sc::module]
 pub trait ConfigModule {
     #[inline]
@@ -21,9 +56,70 @@ pub trait ConfigModule {
         self.launchpad_tokens_deposited().get()
     }
 
+    /// Endpoints without an explicit sale round operate on the first one
+    fn resolve_sale_round_id(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) -> SaleRoundId {
+        let sale_round_id = match opt_sale_round_id {
+            OptionalValue::Some(sale_round_id) => sale_round_id,
+            OptionalValue::None => FIRST_SALE_ROUND_ID,
+        };
+        self.require_valid_sale_round(sale_round_id);
+
+        sale_round_id
+    }
+
+    fn require_valid_sale_round(&self, sale_round_id: SaleRoundId) {
+        require!(
+            (FIRST_SALE_ROUND_ID..=self.nr_sale_rounds().get()).contains(&sale_round_id),
+            "Invalid sale round"
+        );
+    }
+
+    #[inline]
+    fn sale_round_ids(&self) -> RangeInclusive<SaleRoundId> {
+        FIRST_SALE_ROUND_ID..=self.nr_sale_rounds().get()
+    }
+
+    /// The deposit and the owner claim cover the winning tickets of all the sale rounds
+    fn get_total_winning_tickets(&self) -> usize {
+        self.sale_round_ids()
+            .map(|sale_round_id| self.nr_winning_tickets(sale_round_id).get())
+            .sum()
+    }
+
+    #[view(getNumberOfSaleRounds)]
+    #[storage_mapper("nrSaleRounds")]
+    fn nr_sale_rounds(&self) -> SingleValueMapper<SaleRoundId>;
+
     #[view(getConfiguration)]
-    #[storage_mapper("configuration")]
-    fn configuration(&self) -> SingleValueMapper<TimelineConfig>;
+    fn get_configuration(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) -> TimelineConfig {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.configuration(sale_round_id).get()
+    }
+
+    #[view(getTicketPrice)]
+    fn get_ticket_price(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> TokenAmountPair<Self::Api> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.ticket_price(sale_round_id).get()
+    }
+
+    #[view(getNumberOfWinningTickets)]
+    fn get_number_of_winning_tickets(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.nr_winning_tickets(sale_round_id).get()
+    }
+
+    fn configuration(
+        &self,
+        sale_round_id: SaleRoundId,
+    ) -> SingleValueMapper<Self::Api, TimelineConfig> {
+        SingleValueMapper::new(sale_round_storage_key(b"configuration", sale_round_id))
+    }
 
     #[view(getLaunchpadTokenId)]
     #[storage_mapper("launchpadTokenId")]
@@ -33,13 +129,19 @@ pub trait ConfigModule {
     #[storage_mapper("launchpadTokensPerWinningTicket")]
     fn launchpad_tokens_per_winning_ticket(&self) -> SingleValueMapper<BigUint>;
 
-    #[view(getTicketPrice)]
-    #[storage_mapper("ticketPrice")]
-    fn ticket_price(&self) -> SingleValueMapper<TokenAmountPair<Self::Api>>;
+    fn ticket_price(
+        &self,
+        sale_round_id: SaleRoundId,
+    ) -> SingleValueMapper<Self::Api, TokenAmountPair<Self::Api>> {
+        SingleValueMapper::new(sale_round_storage_key(b"ticketPrice", sale_round_id))
+    }
 
-    #[view(getNumberOfWinningTickets)]
-    #[storage_mapper("nrWinningTickets")]
-    fn nr_winning_tickets(&self) -> SingleValueMapper<usize>;
+    fn nr_winning_tickets(
+        &self,
+        sale_round_id: SaleRoundId,
+    ) -> SingleValueMapper<Self::Api, usize> {
+        SingleValueMapper::new(sale_round_storage_key(b"nrWinningTickets", sale_round_id))
+    }
 
     #[view(getTotalLaunchpadTokensDeposited)]
     #[storage_mapper("totalLaunchpadTokensDeposited")]
@@ -48,6 +150,23 @@ pub trait ConfigModule {
     #[storage_mapper("launchpadTokensDeposited")]
     fn launchpad_tokens_deposited(&self) -> SingleValueMapper<bool>;
 
+    /// Once passed, users may no longer claim and the owner may clean up the storage
+    #[view(getClaimDeadlineRound)]
+    #[storage_mapper("claimDeadlineRound")]
+    fn claim_deadline_round(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTermsOfSale)]
+    #[storage_mapper("termsOfSale")]
+    fn terms_of_sale(&self) -> SingleValueMapper<TermsOfSale<Self::Api>>;
+
+    /// The last terms of sale accepted by the user, which may be older than the current ones
+    #[view(getAcceptedTermsOfSale)]
+    #[storage_mapper("acceptedTermsOfSale")]
+    fn accepted_terms_of_sale(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<TermsOfSale<Self::Api>>;
+
     #[storage_mapper("claimableTicketPayment")]
     fn claimable_ticket_payment(&self) -> SingleValueMapper<BigUint>;
 }
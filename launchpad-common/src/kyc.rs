@@ -0,0 +1,122 @@
+multiversx_sc::imports!();
+
+use multiversx_sc::api::{ED25519_KEY_BYTE_LEN, ED25519_SIGNATURE_BYTE_LEN};
+
+use crate::config::{sale_round_storage_key, SaleRoundId};
+
+pub type KycSigner<M> = ManagedByteArray<M, ED25519_KEY_BYTE_LEN>;
+pub type KycSignature<M> = ManagedByteArray<M, ED25519_SIGNATURE_BYTE_LEN>;
+
+/// (max tickets, expiry timestamp, signature)
+pub type KycApproval<M> = MultiValue3<usize, u64, KycSignature<M>>;
+
+#[multiversx_sc::module]
+pub trait KycModule:
+    crate::config::ConfigModule + crate::common_events::CommonEventsModule
+{
+    /// Once a signer is set for a sale round, its confirmations require an approval signed
+    /// with its key over `(contract address, caller, max tickets, expiry timestamp)`.
+    /// Each sale round needs its own signer, so an approval cannot be replayed in another round.
+    /// A signer stays bound to its sale round after being removed.
+    #[only_owner]
+    #[endpoint(setKycSigner)]
+    fn set_kyc_signer(
+        &self,
+        signer: KycSigner<Self::Api>,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let signer_sale_round_mapper = self.kyc_signer_sale_round(&signer);
+        require!(
+            signer_sale_round_mapper.is_empty() || signer_sale_round_mapper.get() == sale_round_id,
+            "KYC signer already used by another sale round"
+        );
+
+        signer_sale_round_mapper.set(sale_round_id);
+        self.kyc_signer(sale_round_id).set(&signer);
+        self.emit_set_kyc_signer_event(sale_round_id, Some(signer));
+    }
+
+    #[only_owner]
+    #[endpoint(removeKycSigner)]
+    fn remove_kyc_signer(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.kyc_signer(sale_round_id).clear();
+        self.emit_set_kyc_signer_event(sale_round_id, None);
+    }
+
+    /// Returns the maximum number of tickets approved for the caller in the sale round,
+    /// or `None` if the sale round has no KYC signer, in which case the approval is ignored
+    fn require_kyc_approval(
+        &self,
+        caller: &ManagedAddress,
+        sale_round_id: SaleRoundId,
+        opt_kyc_approval: OptionalValue<KycApproval<Self::Api>>,
+    ) -> Option<usize> {
+        let signer_mapper = self.kyc_signer(sale_round_id);
+        if signer_mapper.is_empty() {
+            return None;
+        }
+
+        let (max_tickets, expiry, signature) = match opt_kyc_approval {
+            OptionalValue::Some(kyc_approval) => kyc_approval.into_tuple(),
+            OptionalValue::None => sc_panic!("KYC approval required"),
+        };
+        require!(
+            self.blockchain().get_block_timestamp() <= expiry,
+            "KYC approval expired"
+        );
+
+        let message = self.kyc_approval_message(caller, max_tickets, expiry);
+        let signer = signer_mapper.get();
+        self.crypto().verify_ed25519(
+            signer.as_managed_buffer(),
+            &message,
+            signature.as_managed_buffer(),
+        );
+
+        Some(max_tickets)
+    }
+
+    fn kyc_approval_message(
+        &self,
+        caller: &ManagedAddress,
+        max_tickets: usize,
+        expiry: u64,
+    ) -> ManagedBuffer {
+        let mut message = ManagedBuffer::new();
+        let _ = (
+            self.blockchain().get_sc_address(),
+            caller,
+            max_tickets,
+            expiry,
+        )
+            .top_encode(&mut message);
+
+        message
+    }
+
+    #[view(getKycSigner)]
+    fn get_kyc_signer(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> Option<KycSigner<Self::Api>> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let signer_mapper = self.kyc_signer(sale_round_id);
+        if signer_mapper.is_empty() {
+            return None;
+        }
+
+        Some(signer_mapper.get())
+    }
+
+    fn kyc_signer(&self, sale_round_id: SaleRoundId) -> SingleValueMapper<KycSigner<Self::Api>> {
+        SingleValueMapper::new(sale_round_storage_key(b"kycSigner", sale_round_id))
+    }
+
+    #[storage_mapper("kycSignerSaleRound")]
+    fn kyc_signer_sale_round(
+        &self,
+        signer: &KycSigner<Self::Api>,
+    ) -> SingleValueMapper<SaleRoundId>;
+}
@@ -1,9 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::config::TimelineConfig;
+use crate::config::{sale_round_storage_key, SaleRoundId, TimelineConfig, MIN_CLAIM_PERIOD_ROUNDS};
 
-#[derive(PartialEq, PartialOrd)]
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, PartialOrd, Debug,
+)]
 pub enum LaunchStage {
     AddTickets,
     Confirm,
@@ -21,10 +23,10 @@ pub struct Flags {
 
 #[multiversx_sc::module]
 pub trait LaunchStageModule: crate::config::ConfigModule {
-    fn get_launch_stage(&self) -> LaunchStage {
+    fn get_launch_stage(&self, sale_round_id: SaleRoundId) -> LaunchStage {
         let current_round = self.blockchain().get_block_round();
-        let config: TimelineConfig = self.configuration().get();
-        let flags: Flags = self.flags().get();
+        let config: TimelineConfig = self.configuration(sale_round_id).get();
+        let flags: Flags = self.flags(sale_round_id).get();
 
         if current_round < config.confirmation_period_start_round {
             return LaunchStage::AddTickets;
@@ -48,46 +50,103 @@ pub trait LaunchStageModule: crate::config::ConfigModule {
     }
 
     #[inline]
-    fn require_add_tickets_period(&self) {
+    fn require_add_tickets_period(&self, sale_round_id: SaleRoundId) {
         require!(
-            self.get_launch_stage() == LaunchStage::AddTickets,
+            self.get_launch_stage(sale_round_id) == LaunchStage::AddTickets,
             "Add tickets period has passed"
         );
     }
 
     #[inline]
-    fn require_confirmation_period(&self) {
+    fn require_confirmation_period(&self, sale_round_id: SaleRoundId) {
         require!(
-            self.get_launch_stage() == LaunchStage::Confirm,
+            self.get_launch_stage(sale_round_id) == LaunchStage::Confirm,
             "Not in confirmation period"
         );
     }
 
     #[inline]
+    fn is_before_winner_selection(&self, sale_round_id: SaleRoundId) -> bool {
+        self.get_launch_stage(sale_round_id) < LaunchStage::WinnerSelection
+    }
+
+    /// The blacklist is shared, so it may be modified as long as any sale round
+    /// has not reached the winner selection
     fn require_before_winner_selection(&self) {
+        let any_round_before_selection = self
+            .sale_round_ids()
+            .any(|sale_round_id| self.is_before_winner_selection(sale_round_id));
         require!(
-            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            any_round_before_selection,
             "May only modify blacklist before winner selection"
         );
     }
 
     #[inline]
-    fn require_winner_selection_period(&self) {
+    fn require_winner_selection_period(&self, sale_round_id: SaleRoundId) {
         require!(
-            self.get_launch_stage() == LaunchStage::WinnerSelection,
+            self.get_launch_stage(sale_round_id) == LaunchStage::WinnerSelection,
             "Not in winner selection period"
         );
     }
 
     #[inline]
-    fn require_claim_period(&self) {
+    fn require_claim_period(&self, sale_round_id: SaleRoundId) {
         require!(
-            self.get_launch_stage() == LaunchStage::Claim,
+            self.get_launch_stage(sale_round_id) == LaunchStage::Claim,
             "Not in claim period"
         );
     }
 
+    /// Used for the operations shared by all the sale rounds, e.g. the owner claim
+    fn require_claim_period_for_all_sale_rounds(&self) {
+        for sale_round_id in self.sale_round_ids() {
+            self.require_claim_period(sale_round_id);
+        }
+    }
+
+    /// Used for the settings shared by all the sale rounds, which may not change
+    /// once any of them reached the claim period
+    fn require_before_claim_period_for_all_sale_rounds(&self) {
+        for sale_round_id in self.sale_round_ids() {
+            require!(
+                self.get_launch_stage(sale_round_id) < LaunchStage::Claim,
+                "Claim period has started"
+            );
+        }
+    }
+
+    fn is_claim_deadline_passed(&self) -> bool {
+        let deadline_mapper = self.claim_deadline_round();
+        if deadline_mapper.is_empty() {
+            return false;
+        }
+
+        self.blockchain().get_block_round() >= deadline_mapper.get()
+    }
+
+    #[inline]
+    fn require_before_claim_deadline(&self) {
+        require!(!self.is_claim_deadline_passed(), "Claim period has ended");
+    }
+
+    /// Tokens released later than the minimum claim period before the deadline could not be claimed
+    fn require_release_before_claim_deadline(&self, last_release_round: u64) {
+        let deadline_mapper = self.claim_deadline_round();
+        require!(
+            deadline_mapper.is_empty()
+                || last_release_round + MIN_CLAIM_PERIOD_ROUNDS <= deadline_mapper.get(),
+            "Release must leave the minimum claim period before the deadline"
+        );
+    }
+
     #[view(getLaunchStageFlags)]
-    #[storage_mapper("flags")]
-    fn flags(&self) -> SingleValueMapper<Flags>;
+    fn get_launch_stage_flags(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) -> Flags {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.flags(sale_round_id).get()
+    }
+
+    fn flags(&self, sale_round_id: SaleRoundId) -> SingleValueMapper<Self::Api, Flags> {
+        SingleValueMapper::new(sale_round_storage_key(b"flags", sale_round_id))
+    }
 }
@@ -0,0 +1,108 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct LaunchpadStats<M: ManagedTypeApi> {
+    pub total_tickets: usize,
+    pub nr_confirmed_users: usize,
+    pub nr_confirmed_tickets: usize,
+    pub total_ticket_payment: BigUint<M>,
+    pub nr_refunded_tickets: usize,
+    pub total_ticket_payment_refunded: BigUint<M>,
+    pub nr_claimed_users: usize,
+    pub total_launchpad_tokens_claimed: BigUint<M>,
+    pub nr_nft_confirmations: usize,
+}
+
+/// All the counters are cumulative, i.e. they are never decreased,
+/// not even when a user is blacklisted and refunded.
+#[multiversx_sc::module]
+pub trait LaunchpadStatsModule:
+    crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::launch_stage::LaunchStageModule
+    + crate::common_events::CommonEventsModule
+{
+    #[view(getLaunchpadStats)]
+    fn get_launchpad_stats(&self) -> LaunchpadStats<Self::Api> {
+        LaunchpadStats {
+            total_tickets: self
+                .sale_round_ids()
+                .map(|sale_round_id| self.last_ticket_id(sale_round_id).get())
+                .sum(),
+            nr_confirmed_users: self.stats_confirmed_users().get(),
+            nr_confirmed_tickets: self.stats_confirmed_tickets().get(),
+            total_ticket_payment: self.stats_ticket_payment().get(),
+            nr_refunded_tickets: self.stats_refunded_tickets().get(),
+            total_ticket_payment_refunded: self.stats_ticket_payment_refunded().get(),
+            nr_claimed_users: self.stats_claimed_users().get(),
+            total_launchpad_tokens_claimed: self.stats_launchpad_tokens_claimed().get(),
+            nr_nft_confirmations: self.stats_nft_confirmations().get(),
+        }
+    }
+
+    fn record_tickets_confirmation(
+        &self,
+        is_new_user: bool,
+        nr_tickets: usize,
+        payment_amount: &BigUint,
+    ) {
+        if is_new_user {
+            self.stats_confirmed_users()
+                .update(|nr_users| *nr_users += 1);
+        }
+
+        self.stats_confirmed_tickets()
+            .update(|nr_confirmed| *nr_confirmed += nr_tickets);
+        self.stats_ticket_payment()
+            .update(|total_payment| *total_payment += payment_amount);
+    }
+
+    fn record_ticket_payment_refund(&self, nr_tickets: usize, refund_amount: &BigUint) {
+        self.stats_refunded_tickets()
+            .update(|nr_refunded| *nr_refunded += nr_tickets);
+        self.stats_ticket_payment_refunded()
+            .update(|total_refunded| *total_refunded += refund_amount);
+    }
+
+    #[inline]
+    fn record_user_claim(&self) {
+        self.stats_claimed_users().update(|nr_users| *nr_users += 1);
+    }
+
+    #[inline]
+    fn record_launchpad_tokens_claim(&self, amount: &BigUint) {
+        self.stats_launchpad_tokens_claimed()
+            .update(|total_claimed| *total_claimed += amount);
+    }
+
+    #[inline]
+    fn record_nft_confirmation(&self) {
+        self.stats_nft_confirmations()
+            .update(|nr_confirmed| *nr_confirmed += 1);
+    }
+
+    #[storage_mapper("statsConfirmedUsers")]
+    fn stats_confirmed_users(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("statsConfirmedTickets")]
+    fn stats_confirmed_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("statsTicketPayment")]
+    fn stats_ticket_payment(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("statsRefundedTickets")]
+    fn stats_refunded_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("statsTicketPaymentRefunded")]
+    fn stats_ticket_payment_refunded(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("statsClaimedUsers")]
+    fn stats_claimed_users(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("statsLaunchpadTokensClaimed")]
+    fn stats_launchpad_tokens_claimed(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("statsNftConfirmations")]
+    fn stats_nft_confirmations(&self) -> SingleValueMapper<usize>;
+}
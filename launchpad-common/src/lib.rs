@@ -4,19 +4,28 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub mod blacklist;
+pub mod claim_and_stake;
 pub mod common_events;
 pub mod config;
+pub mod kyc;
 pub mod launch_stage;
+pub mod launchpad_stats;
 pub mod ongoing_operation;
+pub mod operation_progress;
+pub mod pagination;
 pub mod permissions;
 pub mod random;
+pub mod sale_rounds;
+pub mod selection_pipeline;
 pub mod setup;
+pub mod storage_cleanup;
 pub mod tickets;
 pub mod token_send;
+pub mod user_info;
 pub mod user_interactions;
 pub mod winner_selection;
 
-use config::TimelineConfig;
+use config::{TimelineConfig, FIRST_SALE_ROUND_ID};
 use launch_stage::Flags;
 use tickets::FIRST_TICKET_ID;
 
@@ -25,14 +34,19 @@ pub trait LaunchpadMain:
     launch_stage::LaunchStageModule
     + config::ConfigModule
     + setup::SetupModule
+    + storage_cleanup::StorageCleanupModule
     + tickets::TicketsModule
     + winner_selection::WinnerSelectionModule
     + ongoing_operation::OngoingOperationModule
+    + operation_progress::OperationProgressModule
     + permissions::PermissionsModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
+    + launchpad_stats::LaunchpadStatsModule
     + common_events::CommonEventsModule
+    + kyc::KycModule
     + user_interactions::UserInteractionsModule
+    + user_info::UserInfoModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -56,10 +70,11 @@ pub trait LaunchpadMain:
         }
 
         self.launchpad_token_id().set(&launchpad_token_id);
+        self.nr_sale_rounds().set_if_empty(FIRST_SALE_ROUND_ID);
 
         self.try_set_launchpad_tokens_per_winning_ticket(&launchpad_tokens_per_winning_ticket);
-        self.try_set_ticket_price(ticket_payment_token, ticket_price);
-        self.try_set_nr_winning_tickets(nr_winning_tickets);
+        self.try_set_ticket_price(FIRST_SALE_ROUND_ID, ticket_payment_token, ticket_price);
+        self.try_set_nr_winning_tickets(FIRST_SALE_ROUND_ID, nr_winning_tickets);
 
         let config = TimelineConfig {
             confirmation_period_start_round,
@@ -67,10 +82,15 @@ pub trait LaunchpadMain:
             claim_start_round,
         };
         self.require_valid_time_periods(&config);
-        self.configuration().set(&config);
-        self.flags().set_if_empty(flags);
+        self.configuration(FIRST_SALE_ROUND_ID).set(&config);
+        self.flags(FIRST_SALE_ROUND_ID).set_if_empty(flags);
 
         let caller = self.blockchain().get_caller();
         self.support_address().set_if_empty(&caller);
     }
+
+    /// Launchpads deployed before the sale rounds keep their state as the first sale round
+    fn upgrade_base(&self) {
+        self.nr_sale_rounds().set_if_empty(FIRST_SALE_ROUND_ID);
+    }
 }
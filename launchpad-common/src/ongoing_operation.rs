@@ -3,9 +3,14 @@ multiversx_sc::derive_imports!();
 
 use multiversx_sc::api::CryptoApi;
 
-use crate::{random::Random, FIRST_TICKET_ID};
+use crate::{
+    config::{SaleRoundId, FIRST_SALE_ROUND_ID},
+    random::Random,
+    FIRST_TICKET_ID,
+};
 
-const MIN_GAS_TO_SAVE_PROGRESS: u64 = 10_000_000;
+pub const MIN_GAS_TO_SAVE_PROGRESS: u64 = 10_000_000;
+pub const FIRST_USER_INDEX: usize = 1;
 static ANOTHER_OP_ERR_MSG: &[u8] = b"Another ongoing operation is in progress";
 
 #[derive(TypeAbi, TopEncode, TopDecode)]
@@ -22,6 +27,97 @@ pub enum OngoingOperationType<M: ManagedTypeApi + CryptoApi> {
     AdditionalSelection {
         encoded_data: ManagedBuffer<M>,
     },
+    AbortWinnerSelection {
+        sale_round_id: SaleRoundId,
+        ticket_position: usize,
+    },
+    StorageCleanup {
+        sale_round_id: SaleRoundId,
+        ticket_id: usize,
+    },
+    StorageMigration {
+        ticket_id: usize,
+        user_index: usize,
+    },
+    PushReleases {
+        user_index: usize,
+        nr_users: usize,
+    },
+    FilterTicketsForSaleRound {
+        sale_round_id: SaleRoundId,
+        first_ticket_id_in_batch: usize,
+        nr_removed: usize,
+    },
+    SelectWinnersForSaleRound {
+        sale_round_id: SaleRoundId,
+        rng: Random<M>,
+        ticket_position: usize,
+    },
+}
+
+/// The first sale round keeps the `FilterTickets` and `SelectWinners` encoding
+/// of the launchpads deployed before the sale rounds
+impl<M: ManagedTypeApi + CryptoApi> OngoingOperationType<M> {
+    pub fn filter_tickets(
+        sale_round_id: SaleRoundId,
+        first_ticket_id_in_batch: usize,
+        nr_removed: usize,
+    ) -> Self {
+        if sale_round_id == FIRST_SALE_ROUND_ID {
+            OngoingOperationType::FilterTickets {
+                first_ticket_id_in_batch,
+                nr_removed,
+            }
+        } else {
+            OngoingOperationType::FilterTicketsForSaleRound {
+                sale_round_id,
+                first_ticket_id_in_batch,
+                nr_removed,
+            }
+        }
+    }
+
+    pub fn select_winners(
+        sale_round_id: SaleRoundId,
+        rng: Random<M>,
+        ticket_position: usize,
+    ) -> Self {
+        if sale_round_id == FIRST_SALE_ROUND_ID {
+            OngoingOperationType::SelectWinners {
+                rng,
+                ticket_position,
+            }
+        } else {
+            OngoingOperationType::SelectWinnersForSaleRound {
+                sale_round_id,
+                rng,
+                ticket_position,
+            }
+        }
+    }
+
+    /// Converts the first sale round variants into their sale round counterparts
+    pub fn with_sale_round(self) -> Self {
+        match self {
+            OngoingOperationType::FilterTickets {
+                first_ticket_id_in_batch,
+                nr_removed,
+            } => OngoingOperationType::FilterTicketsForSaleRound {
+                sale_round_id: FIRST_SALE_ROUND_ID,
+                first_ticket_id_in_batch,
+                nr_removed,
+            },
+            OngoingOperationType::SelectWinners {
+                rng,
+                ticket_position,
+            } => OngoingOperationType::SelectWinnersForSaleRound {
+                sale_round_id: FIRST_SALE_ROUND_ID,
+                rng,
+                ticket_position,
+            },
+            other => other,
+        }
+    }
 }
 
 pub type LoopOp = bool;
@@ -49,6 +145,8 @@ pub trait OngoingOperationModule {
             }
 
             if !self.can_continue_operation(gas_per_iteration) {
+                self.measured_gas_per_iteration().set(gas_per_iteration);
+
                 return OperationCompletionStatus::InterruptedBeforeOutOfGas;
             }
 
@@ -80,28 +178,71 @@ pub trait OngoingOperationModule {
     #[inline]
     fn clear_operation(&self) {
         self.current_ongoing_operation().clear();
+        self.measured_gas_per_iteration().clear();
     }
 
-    fn load_filter_tickets_operation(&self) -> (usize, usize) {
-        let ongoing_operation = self.current_ongoing_operation().get();
+    fn load_filter_tickets_operation(&self, sale_round_id: SaleRoundId) -> (usize, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get().with_sale_round();
         match ongoing_operation {
             OngoingOperationType::None => (FIRST_TICKET_ID, 0),
-            OngoingOperationType::FilterTickets {
+            OngoingOperationType::FilterTicketsForSaleRound {
+                sale_round_id: op_sale_round_id,
                 first_ticket_id_in_batch,
                 nr_removed,
-            } => (first_ticket_id_in_batch, nr_removed),
+            } if op_sale_round_id == sale_round_id => (first_ticket_id_in_batch, nr_removed),
             _ => sc_panic!(ANOTHER_OP_ERR_MSG),
         }
     }
 
-    fn load_select_winners_operation(&self) -> (Random<Self::Api>, usize) {
-        let ongoing_operation = self.current_ongoing_operation().get();
+    fn load_select_winners_operation(
+        &self,
+        sale_round_id: SaleRoundId,
+    ) -> (Random<Self::Api>, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get().with_sale_round();
         match ongoing_operation {
             OngoingOperationType::None => (Random::default(), FIRST_TICKET_ID),
-            OngoingOperationType::SelectWinners {
+            OngoingOperationType::SelectWinnersForSaleRound {
+                sale_round_id: op_sale_round_id,
                 rng,
                 ticket_position,
-            } => (rng, ticket_position),
+            } if op_sale_round_id == sale_round_id => (rng, ticket_position),
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_storage_cleanup_operation(&self) -> (SaleRoundId, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => (FIRST_SALE_ROUND_ID, FIRST_TICKET_ID),
+            OngoingOperationType::StorageCleanup {
+                sale_round_id,
+                ticket_id,
+            } => (sale_round_id, ticket_id),
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_storage_migration_operation(&self) -> (usize, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => (FIRST_TICKET_ID, FIRST_USER_INDEX),
+            OngoingOperationType::StorageMigration {
+                ticket_id,
+                user_index,
+            } => (ticket_id, user_index),
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    /// `nr_users` is only used when a new push is started
+    fn load_push_releases_operation(&self, nr_users: usize) -> (usize, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => (FIRST_USER_INDEX, nr_users),
+            OngoingOperationType::PushReleases {
+                user_index,
+                nr_users,
+            } => (user_index, nr_users),
             _ => sc_panic!(ANOTHER_OP_ERR_MSG),
         }
     }
@@ -111,13 +252,29 @@ pub trait OngoingOperationModule {
         match ongoing_operation {
             OngoingOperationType::None => T::default(),
             OngoingOperationType::AdditionalSelection { encoded_data } => {
-                T::top_decode(encoded_data)
-                    .unwrap_or_else(|_| sc_panic!("Failed to deserialize custom ongoing operation"))
+                self.decode_additional_selection_data(encoded_data)
             }
             _ => sc_panic!(ANOTHER_OP_ERR_MSG),
         }
     }
 
+    /// An empty buffer means the additional selection has not started yet
+    fn decode_additional_selection_data<T: TopDecode + Default>(
+        &self,
+        encoded_data: ManagedBuffer,
+    ) -> T {
+        if encoded_data.is_empty() {
+            return T::default();
+        }
+
+        T::top_decode(encoded_data)
+            .unwrap_or_else(|_| sc_panic!("Failed to deserialize custom ongoing operation"))
+    }
+
     #[storage_mapper("operation")]
     fn current_ongoing_operation(&self) -> SingleValueMapper<OngoingOperationType<Self::Api>>;
+
+    // highest cost of a single iteration, as measured by the last interrupted run
+    #[storage_mapper("measuredGasPerIteration")]
+    fn measured_gas_per_iteration(&self) -> SingleValueMapper<u64>;
 }
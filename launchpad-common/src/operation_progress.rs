@@ -0,0 +1,229 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    config::{SaleRoundId, FIRST_SALE_ROUND_ID},
+    launch_stage::{Flags, LaunchStage},
+    ongoing_operation::{OngoingOperationType, FIRST_USER_INDEX, MIN_GAS_TO_SAVE_PROGRESS},
+    tickets::FIRST_TICKET_ID,
+};
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum OperationPhase {
+    None,
+    FilterTickets,
+    SelectWinners,
+    GuaranteedTicketsSelection,
+    LeftoverTicketsDistribution,
+    NftWinnersSelection,
+    AbortWinnerSelection,
+    StorageCleanup,
+    StorageMigration,
+    PushReleases,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct OperationProgress {
+    pub phase: OperationPhase,
+    pub items_processed: usize,
+    pub items_remaining: usize,
+    pub gas_per_iteration: u64,
+}
+
+impl Default for OperationProgress {
+    fn default() -> Self {
+        Self::new(OperationPhase::None, 0, 0)
+    }
+}
+
+impl OperationProgress {
+    pub fn new(phase: OperationPhase, items_processed: usize, items_remaining: usize) -> Self {
+        Self {
+            phase,
+            items_processed,
+            items_remaining,
+            gas_per_iteration: 0,
+        }
+    }
+}
+
+#[multiversx_sc::module]
+pub trait OperationProgressModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::common_events::CommonEventsModule
+    + crate::ongoing_operation::OngoingOperationModule
+{
+    /// Reports the operation currently in progress, or the next one to be started
+    /// if the previous step was completed. The additional selection payload is decoded
+    /// by `additional_progress_fn`, which receives an empty buffer if the step was not started.
+    /// An operation in progress is reported for the sale round it was started for,
+    /// while the pending one is computed for `sale_round_id`.
+    fn get_operation_progress<
+        AdditionalProgressFn: Fn(&Self, ManagedBuffer) -> OperationProgress,
+    >(
+        &self,
+        sale_round_id: SaleRoundId,
+        additional_progress_fn: AdditionalProgressFn,
+    ) -> OperationProgress {
+        let mut progress = match self.current_ongoing_operation().get() {
+            OngoingOperationType::None => {
+                self.get_pending_operation_progress(sale_round_id, additional_progress_fn)
+            }
+            OngoingOperationType::FilterTickets {
+                first_ticket_id_in_batch,
+                nr_removed: _,
+            } => self.get_filter_tickets_progress(FIRST_SALE_ROUND_ID, first_ticket_id_in_batch),
+            OngoingOperationType::FilterTicketsForSaleRound {
+                sale_round_id,
+                first_ticket_id_in_batch,
+                nr_removed: _,
+            } => self.get_filter_tickets_progress(sale_round_id, first_ticket_id_in_batch),
+            OngoingOperationType::SelectWinners {
+                rng: _,
+                ticket_position,
+            } => self.get_select_winners_progress(FIRST_SALE_ROUND_ID, ticket_position),
+            OngoingOperationType::SelectWinnersForSaleRound {
+                sale_round_id,
+                rng: _,
+                ticket_position,
+            } => self.get_select_winners_progress(sale_round_id, ticket_position),
+            OngoingOperationType::AdditionalSelection { encoded_data } => {
+                additional_progress_fn(self, encoded_data)
+            }
+            OngoingOperationType::AbortWinnerSelection {
+                sale_round_id,
+                ticket_position,
+            } => {
+                let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+                let items_processed = ticket_position - FIRST_TICKET_ID;
+                OperationProgress::new(
+                    OperationPhase::AbortWinnerSelection,
+                    items_processed,
+                    last_ticket_id - items_processed,
+                )
+            }
+            OngoingOperationType::StorageCleanup {
+                sale_round_id,
+                ticket_id,
+            } => {
+                let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+                let items_processed = ticket_id - FIRST_TICKET_ID;
+                OperationProgress::new(
+                    OperationPhase::StorageCleanup,
+                    items_processed,
+                    last_ticket_id - items_processed,
+                )
+            }
+            // the guaranteed ticket holders checked after the ticket batches
+            // are only counted once processed
+            OngoingOperationType::StorageMigration {
+                ticket_id,
+                user_index,
+            } => {
+                let last_ticket_id = self.last_ticket_id(FIRST_SALE_ROUND_ID).get();
+                let tickets_processed = ticket_id - FIRST_TICKET_ID;
+                OperationProgress::new(
+                    OperationPhase::StorageMigration,
+                    tickets_processed + user_index - FIRST_USER_INDEX,
+                    last_ticket_id - tickets_processed,
+                )
+            }
+            OngoingOperationType::PushReleases {
+                user_index,
+                nr_users,
+            } => {
+                let items_processed = user_index - FIRST_USER_INDEX;
+                OperationProgress::new(
+                    OperationPhase::PushReleases,
+                    items_processed,
+                    nr_users - items_processed,
+                )
+            }
+        };
+        progress.gas_per_iteration = self.measured_gas_per_iteration().get();
+
+        progress
+    }
+
+    fn get_filter_tickets_progress(
+        &self,
+        sale_round_id: SaleRoundId,
+        first_ticket_id_in_batch: usize,
+    ) -> OperationProgress {
+        let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+        let items_processed = first_ticket_id_in_batch - FIRST_TICKET_ID;
+        OperationProgress::new(
+            OperationPhase::FilterTickets,
+            items_processed,
+            last_ticket_id - items_processed,
+        )
+    }
+
+    fn get_select_winners_progress(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_position: usize,
+    ) -> OperationProgress {
+        let nr_winning_tickets = self.nr_winning_tickets(sale_round_id).get();
+        let items_processed = ticket_position - FIRST_TICKET_ID;
+        OperationProgress::new(
+            OperationPhase::SelectWinners,
+            items_processed,
+            nr_winning_tickets.saturating_sub(items_processed),
+        )
+    }
+
+    fn get_pending_operation_progress<
+        AdditionalProgressFn: Fn(&Self, ManagedBuffer) -> OperationProgress,
+    >(
+        &self,
+        sale_round_id: SaleRoundId,
+        additional_progress_fn: AdditionalProgressFn,
+    ) -> OperationProgress {
+        if self.get_launch_stage(sale_round_id) != LaunchStage::WinnerSelection {
+            return OperationProgress::default();
+        }
+
+        let flags: Flags = self.flags(sale_round_id).get();
+        if !flags.were_tickets_filtered {
+            let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+            return OperationProgress::new(OperationPhase::FilterTickets, 0, last_ticket_id);
+        }
+        if !flags.were_winners_selected {
+            let nr_winning_tickets = self.nr_winning_tickets(sale_round_id).get();
+            return OperationProgress::new(OperationPhase::SelectWinners, 0, nr_winning_tickets);
+        }
+        if !flags.was_additional_step_completed {
+            return additional_progress_fn(self, ManagedBuffer::new());
+        }
+
+        OperationProgress::default()
+    }
+
+    /// Without a measured iteration cost (i.e. no call was interrupted yet for this step),
+    /// at least one more call is reported as needed.
+    fn estimate_operation_calls(&self, progress: &OperationProgress, gas_limit: u64) -> usize {
+        if progress.items_remaining == 0 {
+            return 0;
+        }
+
+        require!(gas_limit > MIN_GAS_TO_SAVE_PROGRESS, "Gas limit too low");
+        if progress.gas_per_iteration == 0 {
+            return 1;
+        }
+
+        let iterations_per_call =
+            (gas_limit - MIN_GAS_TO_SAVE_PROGRESS) / progress.gas_per_iteration;
+        require!(iterations_per_call > 0, "Gas limit too low");
+
+        let nr_calls = (progress.items_remaining as u64).div_ceil(iterations_per_call);
+
+        nr_calls as usize
+    }
+
+    fn no_additional_selection_progress(&self, _encoded_data: ManagedBuffer) -> OperationProgress {
+        OperationProgress::default()
+    }
+}
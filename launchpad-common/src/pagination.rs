@@ -0,0 +1,31 @@
+multiversx_sc::imports!();
+
+use multiversx_sc::api::StorageMapperApi;
+
+const FIRST_ITEM_INDEX: usize = 1;
+
+/// Returns at most `limit` items, skipping the first `offset` ones.
+/// Removing items from the set (i.e. `swap_remove`) changes the order of the remaining ones.
+pub fn get_page<SA, T>(
+    mapper: &UnorderedSetMapper<SA, T>,
+    offset: usize,
+    limit: usize,
+) -> MultiValueEncoded<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode,
+{
+    let mut page = MultiValueEncoded::new();
+    let len = mapper.len();
+    if offset >= len {
+        return page;
+    }
+
+    let first_index = FIRST_ITEM_INDEX + offset;
+    let last_index = core::cmp::min(len, offset.saturating_add(limit));
+    for index in first_index..=last_index {
+        page.push(mapper.get_by_index(index));
+    }
+
+    page
+}
@@ -1,11 +1,13 @@
 multiversx_sc::imports!();
 
 #[multiversx_sc::module]
-pub trait PermissionsModule {
+pub trait PermissionsModule: crate::common_events::CommonEventsModule {
     #[only_owner]
     #[endpoint(setSupportAddress)]
     fn add_support_address(&self, address: ManagedAddress) {
         self.support_address().set(&address);
+
+        self.emit_set_support_address_event(address);
     }
 
     fn require_extended_permissions(&self) {
@@ -0,0 +1,109 @@
+multiversx_sc::imports!();
+
+use crate::{
+    config::{SaleRoundId, TimelineConfig, FIRST_SALE_ROUND_ID, MIN_CLAIM_PERIOD_ROUNDS},
+    launch_stage::Flags,
+};
+
+/// Allows running several sale rounds (e.g. seed, private, public) in the same contract.
+/// Each round has its own price, tickets, timeline and winner selection, while the
+/// launchpad token, the blacklist and the deposit are shared.
+///
+/// Only meant for launchpads without an additional selection step.
+#[multiversx_sc::module]
+pub trait SaleRoundsModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::setup::SetupModule
+    + crate::tickets::TicketsModule
+    + crate::common_events::CommonEventsModule
+{
+    /// Must be called before depositing the launchpad tokens, as the deposit covers
+    /// the winning tickets of all the rounds. The ticket payment token is the same for all rounds.
+    #[only_owner]
+    #[endpoint(addSaleRound)]
+    fn add_sale_round(
+        &self,
+        ticket_price: BigUint,
+        nr_winning_tickets: usize,
+        confirmation_period_start_round: u64,
+        winner_selection_start_round: u64,
+        claim_start_round: u64,
+    ) -> SaleRoundId {
+        require!(
+            !self.were_launchpad_tokens_deposited(),
+            "Tokens already deposited"
+        );
+
+        let current_round = self.blockchain().get_block_round();
+        require!(
+            confirmation_period_start_round > current_round,
+            "Start round cannot be in the past"
+        );
+
+        let config = TimelineConfig {
+            confirmation_period_start_round,
+            winner_selection_start_round,
+            claim_start_round,
+        };
+        self.require_valid_time_periods(&config);
+
+        let deadline_mapper = self.claim_deadline_round();
+        require!(
+            deadline_mapper.is_empty()
+                || claim_start_round + MIN_CLAIM_PERIOD_ROUNDS <= deadline_mapper.get(),
+            "Claim deadline must leave the minimum claim period"
+        );
+
+        let ticket_payment_token = self.ticket_price(FIRST_SALE_ROUND_ID).get().token_id;
+        let sale_round_id = self.nr_sale_rounds().update(|nr_sale_rounds| {
+            *nr_sale_rounds += 1;
+            *nr_sale_rounds
+        });
+
+        self.try_set_ticket_price(
+            sale_round_id,
+            ticket_payment_token.clone(),
+            ticket_price.clone(),
+        );
+        self.try_set_nr_winning_tickets(sale_round_id, nr_winning_tickets);
+        self.configuration(sale_round_id).set(&config);
+        self.flags(sale_round_id).set(Flags {
+            has_winner_selection_process_started: false,
+            were_tickets_filtered: false,
+            were_winners_selected: false,
+            was_additional_step_completed: true,
+        });
+
+        self.emit_add_sale_round_event(
+            sale_round_id,
+            EgldOrEsdtTokenPayment::new(ticket_payment_token, 0, ticket_price),
+            nr_winning_tickets,
+            config,
+        );
+
+        sale_round_id
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsForSaleRound)]
+    fn add_tickets_for_sale_round(
+        &self,
+        sale_round_id: SaleRoundId,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.require_valid_sale_round(sale_round_id);
+        self.add_tickets(sale_round_id, address_number_pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(setTicketAllowancesForSaleRound)]
+    fn set_ticket_allowances_for_sale_round(
+        &self,
+        sale_round_id: SaleRoundId,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.require_valid_sale_round(sale_round_id);
+        self.set_ticket_allowances(sale_round_id, address_number_pairs);
+    }
+}
@@ -0,0 +1,152 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    config::FIRST_SALE_ROUND_ID,
+    ongoing_operation::{OngoingOperationModule, OngoingOperationType},
+    operation_progress::OperationProgress,
+};
+
+/// A secondary selection step, run after the base winners selection.
+/// `Operation` holds the step's progress, which is saved between calls.
+pub trait AdditionalSelectionStep<Sc: ContractBase> {
+    type Operation: TopEncode + TopDecode + Default;
+
+    fn run(&self, sc: &Sc, op: &mut Self::Operation) -> OperationCompletionStatus;
+
+    /// Called once, after `run` completed the step
+    fn on_complete(&self, sc: &Sc, op: &Self::Operation);
+
+    fn progress(&self, sc: &Sc, op: &Self::Operation) -> OperationProgress;
+}
+
+/// Object safe version of `AdditionalSelectionStep`, working on the encoded operation,
+/// so steps with different operation types can be listed together
+pub trait EncodedSelectionStep<Sc: ContractBase> {
+    fn run_encoded(
+        &self,
+        sc: &Sc,
+        encoded_op: &mut ManagedBuffer<Sc::Api>,
+    ) -> OperationCompletionStatus;
+
+    fn progress_encoded(&self, sc: &Sc, encoded_op: ManagedBuffer<Sc::Api>) -> OperationProgress;
+}
+
+impl<Sc, Step> EncodedSelectionStep<Sc> for Step
+where
+    Sc: OngoingOperationModule,
+    Step: AdditionalSelectionStep<Sc>,
+{
+    fn run_encoded(
+        &self,
+        sc: &Sc,
+        encoded_op: &mut ManagedBuffer<Sc::Api>,
+    ) -> OperationCompletionStatus {
+        let mut op: Step::Operation = sc.decode_additional_selection_data(encoded_op.clone());
+        let run_result = self.run(sc, &mut op);
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                let mut new_encoded_op = ManagedBuffer::new();
+                let _ = op.top_encode(&mut new_encoded_op);
+                *encoded_op = new_encoded_op;
+            }
+            OperationCompletionStatus::Completed => self.on_complete(sc, &op),
+        }
+
+        run_result
+    }
+
+    fn progress_encoded(&self, sc: &Sc, encoded_op: ManagedBuffer<Sc::Api>) -> OperationProgress {
+        let op: Step::Operation = sc.decode_additional_selection_data(encoded_op);
+        self.progress(sc, &op)
+    }
+}
+
+/// The steps of a contract, in the order they are run
+pub type SelectionSteps<'a, Sc> = &'a [&'a (dyn EncodedSelectionStep<Sc> + 'a)];
+
+/// Position in the list of steps, along with the encoded operation of the current step.
+/// An empty `step_data` means the current step was not started yet.
+#[derive(TopEncode, TopDecode)]
+pub struct SelectionPipelineOperation<M: ManagedTypeApi> {
+    pub step_index: usize,
+    pub step_data: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> Default for SelectionPipelineOperation<M> {
+    fn default() -> Self {
+        Self {
+            step_index: 0,
+            step_data: ManagedBuffer::new(),
+        }
+    }
+}
+
+#[multiversx_sc::module]
+pub trait SelectionPipelineModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::ongoing_operation::OngoingOperationModule
+{
+    /// Runs the steps in order, resuming from the saved progress.
+    /// `was_additional_step_completed` is set once all the steps are completed.
+    fn run_selection_pipeline(&self, steps: SelectionSteps<Self>) -> OperationCompletionStatus {
+        self.require_winner_selection_period(FIRST_SALE_ROUND_ID);
+
+        let flags_mapper = self.flags(FIRST_SALE_ROUND_ID);
+        let mut flags = flags_mapper.get();
+        require!(
+            flags.were_winners_selected,
+            "Must select winners for base launchpad first"
+        );
+        require!(
+            !flags.was_additional_step_completed,
+            "Already performed this step"
+        );
+
+        let mut current_operation: SelectionPipelineOperation<Self::Api> =
+            self.load_additional_selection_operation();
+        while let Some(step) = steps.get(current_operation.step_index) {
+            let run_result = step.run_encoded(self, &mut current_operation.step_data);
+            if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                self.save_additional_selection_progress(&current_operation);
+
+                return run_result;
+            }
+
+            current_operation.step_index += 1;
+            current_operation.step_data = ManagedBuffer::new();
+        }
+
+        flags.was_additional_step_completed = true;
+        flags_mapper.set(&flags);
+
+        OperationCompletionStatus::Completed
+    }
+
+    /// Before the pipeline, single step contracts saved the step's operation as is,
+    /// so they must not be upgraded while their additional selection is in progress
+    fn require_no_additional_selection_in_progress(&self) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        require!(
+            !matches!(
+                ongoing_operation,
+                OngoingOperationType::AdditionalSelection { .. }
+            ),
+            "Cannot upgrade during the additional selection"
+        );
+    }
+
+    fn get_selection_pipeline_progress(
+        &self,
+        steps: SelectionSteps<Self>,
+        encoded_data: ManagedBuffer,
+    ) -> OperationProgress {
+        let current_operation: SelectionPipelineOperation<Self::Api> =
+            self.decode_additional_selection_data(encoded_data);
+        match steps.get(current_operation.step_index) {
+            Some(step) => step.progress_encoded(self, current_operation.step_data),
+            None => OperationProgress::default(),
+        }
+    }
+}
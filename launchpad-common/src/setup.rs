@@ -1,6 +1,9 @@
 multiversx_sc::imports!();
 
-use crate::config::{TimelineConfig, TokenAmountPair};
+use crate::config::{
+    SaleRoundId, TermsOfSale, TimelineConfig, TokenAmountPair, FIRST_SALE_ROUND_ID,
+    MIN_CLAIM_PERIOD_ROUNDS, TERMS_OF_SALE_HASH_LEN,
+};
 
 #[multiversx_sc::module]
 pub trait SetupModule:
@@ -23,34 +26,52 @@ pub trait SetupModule:
         require!(payment_amount == amount_needed, "Wrong amount");
 
         self.launchpad_tokens_deposited().set(true);
-        self.total_launchpad_tokens_deposited().set(payment_amount);
+        self.total_launchpad_tokens_deposited().set(&payment_amount);
+
+        self.emit_deposit_launchpad_tokens_event(
+            total_winning_tickets,
+            EsdtTokenPayment::new(payment_token, 0, payment_amount),
+        );
     }
 
     #[only_owner]
     #[endpoint(setTicketPrice)]
-    fn set_ticket_price(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
-        self.require_add_tickets_period();
-        self.try_set_ticket_price(token_id.clone(), amount.clone());
+    fn set_ticket_price(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.require_add_tickets_period(sale_round_id);
+        self.try_set_ticket_price(sale_round_id, token_id.clone(), amount.clone());
 
         let ticket_price = EgldOrEsdtTokenPayment::new(token_id, 0, amount);
-        self.emit_set_ticket_price_event(ticket_price);
+        self.emit_set_ticket_price_event(sale_round_id, ticket_price);
     }
 
     #[only_owner]
     #[endpoint(setLaunchpadTokensPerWinningTicket)]
     fn set_launchpad_tokens_per_winning_ticket(&self, amount: BigUint) {
-        self.require_add_tickets_period();
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
         require!(
             !self.were_launchpad_tokens_deposited(),
             "Tokens already deposited"
         );
         self.try_set_launchpad_tokens_per_winning_ticket(&amount);
+
+        self.emit_set_launchpad_tokens_per_winning_ticket_event(amount);
     }
 
     #[only_owner]
     #[endpoint(setConfirmationPeriodStartRound)]
-    fn set_confirmation_period_start_round(&self, new_start_round: u64) {
-        self.configuration().update(|config| {
+    fn set_confirmation_period_start_round(
+        &self,
+        new_start_round: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let timeline = self.configuration(sale_round_id).update(|config| {
             self.require_valid_config_timeline_change(
                 config.confirmation_period_start_round,
                 new_start_round,
@@ -58,13 +79,22 @@ pub trait SetupModule:
 
             config.confirmation_period_start_round = new_start_round;
             self.require_valid_time_periods(config);
+
+            config.clone()
         });
+
+        self.emit_set_timeline_event(sale_round_id, timeline);
     }
 
     #[only_owner]
     #[endpoint(setWinnerSelectionStartRound)]
-    fn set_winner_selection_start_round(&self, new_start_round: u64) {
-        self.configuration().update(|config| {
+    fn set_winner_selection_start_round(
+        &self,
+        new_start_round: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let timeline = self.configuration(sale_round_id).update(|config| {
             self.require_valid_config_timeline_change(
                 config.winner_selection_start_round,
                 new_start_round,
@@ -72,25 +102,76 @@ pub trait SetupModule:
 
             config.winner_selection_start_round = new_start_round;
             self.require_valid_time_periods(config);
+
+            config.clone()
         });
+
+        self.emit_set_timeline_event(sale_round_id, timeline);
     }
 
     #[only_owner]
     #[endpoint(setClaimStartRound)]
-    fn set_claim_start_round(&self, new_start_round: u64) {
-        self.configuration().update(|config| {
+    fn set_claim_start_round(
+        &self,
+        new_start_round: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let timeline = self.configuration(sale_round_id).update(|config| {
             self.require_valid_config_timeline_change(config.claim_start_round, new_start_round);
 
             config.claim_start_round = new_start_round;
             self.require_valid_time_periods(config);
+
+            let deadline_mapper = self.claim_deadline_round();
+            require!(
+                deadline_mapper.is_empty()
+                    || new_start_round + MIN_CLAIM_PERIOD_ROUNDS <= deadline_mapper.get(),
+                "Claim deadline must leave the minimum claim period"
+            );
+
+            config.clone()
         });
+
+        self.emit_set_timeline_event(sale_round_id, timeline);
     }
 
-    fn try_set_ticket_price(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+    /// Users who accepted older terms must accept the new ones before confirming more tickets
+    #[only_owner]
+    #[endpoint(setTermsOfSale)]
+    fn set_terms_of_sale(&self, hash: ManagedByteArray<TERMS_OF_SALE_HASH_LEN>, version: u32) {
+        let terms_mapper = self.terms_of_sale();
+        if !terms_mapper.is_empty() {
+            require!(
+                version > terms_mapper.get().version,
+                "Terms of sale version must increase"
+            );
+        }
+
+        let terms = TermsOfSale { hash, version };
+        terms_mapper.set(&terms);
+
+        self.emit_set_terms_of_sale_event(terms);
+    }
+
+    fn try_set_ticket_price(
+        &self,
+        sale_round_id: SaleRoundId,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
         require!(token_id.is_valid(), "Invalid token ID");
         require!(amount > 0, "Ticket price must be higher than 0");
-
-        self.ticket_price()
+        for other_sale_round_id in self.sale_round_ids() {
+            if other_sale_round_id != sale_round_id {
+                require!(
+                    self.ticket_price(other_sale_round_id).get().token_id == token_id,
+                    "All sale rounds must use the same payment token"
+                );
+            }
+        }
+
+        self.ticket_price(sale_round_id)
             .set(&TokenAmountPair { token_id, amount });
     }
 
@@ -103,13 +184,14 @@ pub trait SetupModule:
         self.launchpad_tokens_per_winning_ticket().set(amount);
     }
 
-    fn try_set_nr_winning_tickets(&self, nr_winning_tickets: usize) {
+    fn try_set_nr_winning_tickets(&self, sale_round_id: SaleRoundId, nr_winning_tickets: usize) {
         require!(
             nr_winning_tickets > 0,
             "Cannot set number of winning tickets to zero"
         );
 
-        self.nr_winning_tickets().set(nr_winning_tickets);
+        self.nr_winning_tickets(sale_round_id)
+            .set(nr_winning_tickets);
     }
 
     fn require_valid_config_timeline_change(&self, old_start_round: u64, new_start_round: u64) {
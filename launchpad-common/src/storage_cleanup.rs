@@ -0,0 +1,253 @@
+multiversx_sc::imports!();
+
+use crate::{
+    config::{
+        SaleRoundId, TimelineConfig, TokenAmountPair, FIRST_SALE_ROUND_ID, MIN_CLAIM_PERIOD_ROUNDS,
+    },
+    ongoing_operation::{LoopOp, OngoingOperationType, CONTINUE_OP, STOP_OP},
+    tickets::{TicketBatch, FIRST_TICKET_ID},
+};
+
+#[multiversx_sc::module]
+pub trait StorageCleanupModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::blacklist::BlacklistModule
+    + crate::user_interactions::UserInteractionsModule
+    + crate::token_send::TokenSendModule
+    + crate::launchpad_stats::LaunchpadStatsModule
+    + crate::permissions::PermissionsModule
+    + crate::common_events::CommonEventsModule
+    + crate::kyc::KycModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// `last_release_round_fn` returns the round from which all the won tokens are released,
+    /// so the users are left at least the minimum claim period after it
+    fn set_claim_deadline_round<LastReleaseRoundFn: Fn(&Self) -> u64>(
+        &self,
+        deadline_round: u64,
+        last_release_round_fn: LastReleaseRoundFn,
+    ) {
+        require!(
+            !self.is_claim_deadline_passed(),
+            "Claim deadline has already passed"
+        );
+
+        let current_round = self.blockchain().get_block_round();
+        require!(
+            deadline_round >= current_round + MIN_CLAIM_PERIOD_ROUNDS,
+            "Claim deadline is too close"
+        );
+
+        let deadline_mapper = self.claim_deadline_round();
+        require!(
+            deadline_mapper.is_empty() || deadline_round >= deadline_mapper.get(),
+            "Claim deadline cannot be moved earlier"
+        );
+
+        for sale_round_id in self.sale_round_ids() {
+            let config: TimelineConfig = self.configuration(sale_round_id).get();
+            require!(
+                deadline_round >= config.claim_start_round + MIN_CLAIM_PERIOD_ROUNDS,
+                "Claim deadline must leave the minimum claim period"
+            );
+        }
+
+        require!(
+            deadline_round >= last_release_round_fn(self) + MIN_CLAIM_PERIOD_ROUNDS,
+            "Claim deadline must leave the minimum claim period after the last release"
+        );
+
+        self.claim_deadline_round().set(deadline_round);
+
+        self.emit_set_claim_deadline_round_event(deadline_round);
+    }
+
+    /// Walks all the ticket IDs of every sale round, clearing the ticket entries and the data
+    /// of the users who never claimed, then empties the claimed users of the sale round.
+    /// The blacklisted users and the participants are emptied last, after which all the remaining
+    /// tokens are sent to the owner.
+    /// `cleanup_user_fn` clears any additional per-user storage of the specific contract,
+    /// and `reserved_launchpad_tokens_fn` returns the launchpad tokens still owed to users,
+    /// which are kept in the contract.
+    fn cleanup_storage<
+        CleanupUserFn: Fn(&Self, &ManagedAddress),
+        ReservedLaunchpadTokensFn: Fn(&Self) -> BigUint,
+    >(
+        &self,
+        cleanup_user_fn: CleanupUserFn,
+        reserved_launchpad_tokens_fn: ReservedLaunchpadTokensFn,
+    ) -> OperationCompletionStatus {
+        self.require_claim_period_for_all_sale_rounds();
+        require!(
+            self.is_claim_deadline_passed(),
+            "Claim deadline has not passed yet"
+        );
+        require!(
+            !self.storage_cleanup_completed().get(),
+            "Storage already cleaned up"
+        );
+
+        let nr_sale_rounds = self.nr_sale_rounds().get();
+        let (mut sale_round_id, mut ticket_id) = self.load_storage_cleanup_operation();
+        let mut last_ticket_id = self.last_ticket_id(sale_round_id).get();
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_id > last_ticket_id {
+                if self.pop_claimed_user(sale_round_id, &cleanup_user_fn) {
+                    return CONTINUE_OP;
+                }
+
+                if sale_round_id == nr_sale_rounds {
+                    return self.pop_remaining_user(&cleanup_user_fn);
+                }
+
+                sale_round_id += 1;
+                ticket_id = FIRST_TICKET_ID;
+                last_ticket_id = self.last_ticket_id(sale_round_id).get();
+
+                return CONTINUE_OP;
+            }
+
+            self.ticket_status(sale_round_id, ticket_id).clear();
+            self.ticket_pos_to_id(sale_round_id, ticket_id).clear();
+
+            let ticket_batch_mapper = self.ticket_batch(sale_round_id, ticket_id);
+            if !ticket_batch_mapper.is_empty() {
+                let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.take();
+                let address = &ticket_batch.address;
+
+                self.ticket_ranges_for_address(sale_round_id, address)
+                    .clear();
+                self.nr_confirmed_tickets(sale_round_id, address).clear();
+                cleanup_user_fn(self, address);
+            }
+
+            ticket_id += 1;
+
+            CONTINUE_OP
+        });
+
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_progress(&OngoingOperationType::StorageCleanup {
+                    sale_round_id,
+                    ticket_id,
+                });
+            }
+            OperationCompletionStatus::Completed => {
+                self.storage_cleanup_completed().set(true);
+                let reserved_launchpad_tokens = reserved_launchpad_tokens_fn(self);
+                self.forward_remaining_balances(&reserved_launchpad_tokens);
+            }
+        };
+
+        run_result
+    }
+
+    fn pop_claimed_user<CleanupUserFn: Fn(&Self, &ManagedAddress)>(
+        &self,
+        sale_round_id: SaleRoundId,
+        cleanup_user_fn: &CleanupUserFn,
+    ) -> bool {
+        let mut claimed_users_mapper = self.claimed_users(sale_round_id);
+        let nr_claimed_users = claimed_users_mapper.len();
+        if nr_claimed_users == 0 {
+            return false;
+        }
+
+        let address = claimed_users_mapper.get_by_index(nr_claimed_users);
+        let _ = claimed_users_mapper.swap_remove(&address);
+        self.claim_list(sale_round_id).remove(&address);
+        self.cleanup_user_entries(&address, cleanup_user_fn);
+
+        true
+    }
+
+    /// Users may have several ticket batches, so the tickets walk keeps their other entries
+    fn pop_remaining_user<CleanupUserFn: Fn(&Self, &ManagedAddress)>(
+        &self,
+        cleanup_user_fn: &CleanupUserFn,
+    ) -> LoopOp {
+        let mut blacklisted_users_mapper = self.blacklisted_users();
+        let nr_blacklisted_users = blacklisted_users_mapper.len();
+        if nr_blacklisted_users > 0 {
+            let address = blacklisted_users_mapper.get_by_index(nr_blacklisted_users);
+            let _ = blacklisted_users_mapper.swap_remove(&address);
+            self.blacklist().remove(&address);
+            self.cleanup_user_entries(&address, cleanup_user_fn);
+
+            return CONTINUE_OP;
+        }
+
+        let mut participants_mapper = self.participants();
+        let nr_participants = participants_mapper.len();
+        if nr_participants > 0 {
+            let address = participants_mapper.get_by_index(nr_participants);
+            let _ = participants_mapper.swap_remove(&address);
+            self.cleanup_user_entries(&address, cleanup_user_fn);
+
+            return CONTINUE_OP;
+        }
+
+        STOP_OP
+    }
+
+    fn cleanup_user_entries<CleanupUserFn: Fn(&Self, &ManagedAddress)>(
+        &self,
+        address: &ManagedAddress,
+        cleanup_user_fn: &CleanupUserFn,
+    ) {
+        self.accepted_terms_of_sale(address).clear();
+        cleanup_user_fn(self, address);
+    }
+
+    fn forward_remaining_balances(&self, reserved_launchpad_tokens: &BigUint) {
+        let owner = self.blockchain().get_caller();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
+        let ticket_payment_balance = self.blockchain().get_sc_balance(&ticket_price.token_id, 0);
+        if ticket_payment_balance > 0 {
+            self.send()
+                .direct(&owner, &ticket_price.token_id, 0, &ticket_payment_balance);
+        }
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let mut launchpad_tokens_balance = self.blockchain().get_esdt_balance(
+            &self.blockchain().get_sc_address(),
+            &launchpad_token_id,
+            0,
+        );
+        if launchpad_tokens_balance > *reserved_launchpad_tokens {
+            launchpad_tokens_balance -= reserved_launchpad_tokens;
+        } else {
+            launchpad_tokens_balance = BigUint::zero();
+        }
+        if launchpad_tokens_balance > 0 {
+            self.send()
+                .direct_esdt(&owner, &launchpad_token_id, 0, &launchpad_tokens_balance);
+        }
+
+        self.claimable_ticket_payment().clear();
+        self.total_launchpad_tokens_deposited().clear();
+
+        self.emit_storage_cleanup_completed_event(
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, ticket_payment_balance),
+            EsdtTokenPayment::new(launchpad_token_id, 0, launchpad_tokens_balance),
+        );
+    }
+
+    fn no_additional_user_cleanup(&self, _address: &ManagedAddress) {}
+
+    fn no_reserved_launchpad_tokens(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    fn no_vesting_last_release_round(&self) -> u64 {
+        0
+    }
+
+    #[view(isStorageCleanupCompleted)]
+    #[storage_mapper("storageCleanupCompleted")]
+    fn storage_cleanup_completed(&self) -> SingleValueMapper<bool>;
+}
@@ -1,4 +1,7 @@
-use crate::config::TokenAmountPair;
+use crate::{
+    config::{sale_round_storage_key, SaleRoundId, TokenAmountPair, FIRST_SALE_ROUND_ID},
+    pagination::get_page,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -8,46 +11,101 @@ pub const FIRST_TICKET_ID: usize = 1;
 pub type TicketStatus = bool;
 pub const WINNING_TICKET: TicketStatus = true;
 
-#[derive(TopEncode, TopDecode)]
+// range is [first_id, last_id], both inclusive
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
 pub struct TicketRange {
     pub first_id: usize,
     pub last_id: usize,
 }
 
+impl TicketRange {
+    #[inline]
+    pub fn nr_tickets(&self) -> usize {
+        self.last_id - self.first_id + 1
+    }
+}
+
+/// The ticket batches of an address, in increasing ID order.
+/// The IDs of a batch are contiguous, so each allowance top-up gets its own batch.
+pub type TicketRanges<M> = ManagedVec<M, TicketRange>;
+
+pub fn total_nr_tickets<M: ManagedTypeApi>(ticket_ranges: &TicketRanges<M>) -> usize {
+    ticket_ranges
+        .iter()
+        .map(|ticket_range| ticket_range.nr_tickets())
+        .sum()
+}
+
 #[derive(TopEncode, TopDecode)]
 pub struct TicketBatch<M: ManagedTypeApi> {
     pub address: ManagedAddress<M>,
     pub nr_tickets: usize,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct TicketBatchInfo<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub ticket_ranges: TicketRanges<M>,
+    pub nr_tickets: usize,
+    pub nr_confirmed_tickets: usize,
+}
+
 #[multiversx_sc::module]
 pub trait TicketsModule:
-    crate::launch_stage::LaunchStageModule + crate::config::ConfigModule
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::common_events::CommonEventsModule
 {
     fn add_tickets(
         &self,
+        sale_round_id: SaleRoundId,
         address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) {
-        self.require_add_tickets_period();
+        self.require_add_tickets_period(sale_round_id);
 
+        let mut total_users_count = 0;
+        let mut total_tickets_added = 0;
         for multi_arg in address_number_pairs {
             let (buyer, nr_tickets) = multi_arg.into_tuple();
 
-            self.try_create_tickets(buyer, nr_tickets);
+            let first_ticket_id = self.try_create_tickets(sale_round_id, buyer.clone(), nr_tickets);
+            self.emit_add_user_tickets_event(sale_round_id, buyer, first_ticket_id, nr_tickets, 0);
+
+            total_users_count += 1;
+            total_tickets_added += nr_tickets;
         }
+
+        self.emit_add_tickets_event(sale_round_id, total_users_count, total_tickets_added, 0);
     }
 
+    /// Covers the ticket payments of all the sale rounds, which share the same payment token
     fn claim_ticket_payment(&self) {
-        self.require_claim_period();
+        self.claim_ticket_payment_with_reserved_tokens(&BigUint::zero());
+    }
+
+    /// `reserved_launchpad_tokens` are kept in the contract on top of the tokens
+    /// for the unclaimed winning tickets, e.g. tokens that are still vesting
+    fn claim_ticket_payment_with_reserved_tokens(&self, reserved_launchpad_tokens: &BigUint) {
+        self.require_claim_period_for_all_sale_rounds();
 
         let owner = self.blockchain().get_caller();
 
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
         let ticket_payment_mapper = self.claimable_ticket_payment();
         let claimable_ticket_payment = ticket_payment_mapper.get();
         if claimable_ticket_payment > 0 {
             ticket_payment_mapper.clear();
 
-            let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
             self.send()
                 .direct(&owner, &ticket_price.token_id, 0, &claimable_ticket_payment);
         }
@@ -59,48 +117,195 @@ pub trait TicketsModule:
             0,
         );
 
-        let nr_winning_tickets = self.nr_winning_tickets().get();
+        let nr_winning_tickets = self.get_total_winning_tickets();
         let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
-        let launchpad_tokens_needed = amount_per_ticket * (nr_winning_tickets as u32);
+        let launchpad_tokens_needed =
+            amount_per_ticket * (nr_winning_tickets as u32) + reserved_launchpad_tokens;
 
         let extra_launchpad_tokens = launchpad_tokens_balance - launchpad_tokens_needed;
         if extra_launchpad_tokens > 0 {
             self.send()
                 .direct_esdt(&owner, &launchpad_token_id, 0, &extra_launchpad_tokens);
         }
+
+        self.emit_claim_ticket_payment_event(
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, claimable_ticket_payment),
+            EsdtTokenPayment::new(launchpad_token_id, 0, extra_launchpad_tokens),
+        );
     }
 
-    // range is [min, max], both inclusive
+    /// One `(first, last)` ID pair for each batch of the address, both inclusive
     #[view(getTicketRangeForAddress)]
     fn get_ticket_range_for_address(
         &self,
         address: &ManagedAddress,
-    ) -> OptionalValue<MultiValue2<usize, usize>> {
-        let ticket_range_mapper = self.ticket_range_for_address(address);
-        if ticket_range_mapper.is_empty() {
-            return OptionalValue::None;
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> MultiValueEncoded<MultiValue2<usize, usize>> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let mut result = MultiValueEncoded::new();
+        for ticket_range in self
+            .ticket_ranges_for_address(sale_round_id, address)
+            .get()
+            .iter()
+        {
+            result.push((ticket_range.first_id, ticket_range.last_id).into());
         }
 
-        let ticket_range: TicketRange = ticket_range_mapper.get();
-        OptionalValue::Some((ticket_range.first_id, ticket_range.last_id).into())
+        result
     }
 
     #[view(getTotalNumberOfTicketsForAddress)]
-    fn get_total_number_of_tickets_for_address(&self, address: &ManagedAddress) -> usize {
-        let ticket_range_mapper = self.ticket_range_for_address(address);
-        if ticket_range_mapper.is_empty() {
-            return 0;
+    fn get_total_number_of_tickets_for_address_view(
+        &self,
+        address: &ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_total_number_of_tickets_for_address(sale_round_id, address)
+    }
+
+    fn get_total_number_of_tickets_for_address(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+    ) -> usize {
+        let ticket_ranges = self.ticket_ranges_for_address(sale_round_id, address).get();
+        total_nr_tickets(&ticket_ranges)
+    }
+
+    /// `offset` and `limit` page the participants of all the sale rounds. Participants without
+    /// tickets in the given sale round, e.g. whose allowance was removed, whose tickets were
+    /// filtered out or who already claimed, are skipped, so a page may hold fewer entries.
+    #[view(getTicketBatches)]
+    fn get_ticket_batches(
+        &self,
+        offset: usize,
+        limit: usize,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> MultiValueEncoded<TicketBatchInfo<Self::Api>> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        let mut ticket_batches = MultiValueEncoded::new();
+        for address in get_page(&self.participants(), offset, limit) {
+            let ticket_ranges = self
+                .ticket_ranges_for_address(sale_round_id, &address)
+                .get();
+            if ticket_ranges.is_empty() {
+                continue;
+            }
+
+            let nr_tickets = total_nr_tickets(&ticket_ranges);
+            let nr_confirmed_tickets = self.nr_confirmed_tickets(sale_round_id, &address).get();
+
+            ticket_batches.push(TicketBatchInfo {
+                address,
+                ticket_ranges,
+                nr_tickets,
+                nr_confirmed_tickets,
+            });
         }
 
-        let ticket_range: TicketRange = ticket_range_mapper.get();
-        ticket_range.last_id - ticket_range.first_id + 1
+        ticket_batches
+    }
+
+    /// Returns the ID of the first ticket created
+    fn try_create_tickets(
+        &self,
+        sale_round_id: SaleRoundId,
+        buyer: ManagedAddress,
+        nr_tickets: usize,
+    ) -> usize {
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(sale_round_id, &buyer);
+        require!(ticket_ranges_mapper.is_empty(), "Duplicate entry for user");
+
+        let ticket_range = self.create_ticket_batch(sale_round_id, &buyer, nr_tickets);
+        let first_ticket_id = ticket_range.first_id;
+        ticket_ranges_mapper.set(ManagedVec::from_single_item(ticket_range));
+
+        first_ticket_id
+    }
+
+    fn set_ticket_allowances(
+        &self,
+        sale_round_id: SaleRoundId,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.require_add_tickets_period(sale_round_id);
+
+        for multi_arg in address_number_pairs {
+            let (address, nr_tickets) = multi_arg.into_tuple();
+            let previous_nr_tickets =
+                self.try_set_ticket_allowance(sale_round_id, &address, nr_tickets);
+            self.emit_set_user_ticket_allowance_event(
+                sale_round_id,
+                address,
+                previous_nr_tickets,
+                nr_tickets,
+                0,
+            );
+        }
     }
 
-    fn try_create_tickets(&self, buyer: ManagedAddress, nr_tickets: usize) {
-        let ticket_range_mapper = self.ticket_range_for_address(&buyer);
-        require!(ticket_range_mapper.is_empty(), "Duplicate entry for user");
+    /// Grows the allowance through a new batch, or shrinks it starting from the last batch.
+    /// The tickets taken out keep their IDs, in batches no longer listed for the address,
+    /// which are dropped when filtering the tickets. Returns the previous number of tickets.
+    fn try_set_ticket_allowance(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+        nr_tickets: usize,
+    ) -> usize {
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(sale_round_id, address);
+        let mut ticket_ranges = ticket_ranges_mapper.get();
+        let previous_nr_tickets = total_nr_tickets(&ticket_ranges);
+        if nr_tickets > previous_nr_tickets {
+            let ticket_range =
+                self.create_ticket_batch(sale_round_id, address, nr_tickets - previous_nr_tickets);
+            ticket_ranges.push(ticket_range);
+        }
+
+        let mut nr_tickets_to_remove = previous_nr_tickets.saturating_sub(nr_tickets);
+        while nr_tickets_to_remove > 0 {
+            let last_index = ticket_ranges.len() - 1;
+            let mut last_range = ticket_ranges.get(last_index);
+            let nr_tickets_in_range = last_range.nr_tickets();
+            if nr_tickets_in_range <= nr_tickets_to_remove {
+                ticket_ranges.remove(last_index);
+                nr_tickets_to_remove -= nr_tickets_in_range;
+
+                continue;
+            }
+
+            let new_last_id = last_range.last_id - nr_tickets_to_remove;
+            self.ticket_batch(sale_round_id, last_range.first_id)
+                .update(|ticket_batch| ticket_batch.nr_tickets -= nr_tickets_to_remove);
+            self.ticket_batch(sale_round_id, new_last_id + 1)
+                .set(&TicketBatch {
+                    address: address.clone(),
+                    nr_tickets: nr_tickets_to_remove,
+                });
+
+            last_range.last_id = new_last_id;
+            let _ = ticket_ranges.set(last_index, last_range);
+            nr_tickets_to_remove = 0;
+        }
 
-        let last_ticket_id_mapper = self.last_ticket_id();
+        if ticket_ranges.is_empty() {
+            ticket_ranges_mapper.clear();
+        } else {
+            ticket_ranges_mapper.set(&ticket_ranges);
+        }
+
+        previous_nr_tickets
+    }
+
+    /// Creates a batch of new ticket IDs, after all the existing ones
+    fn create_ticket_batch(
+        &self,
+        sale_round_id: SaleRoundId,
+        buyer: &ManagedAddress,
+        nr_tickets: usize,
+    ) -> TicketRange {
+        let last_ticket_id_mapper = self.last_ticket_id(sale_round_id);
         let first_ticket_id = last_ticket_id_mapper.get() + 1;
 
         require!(
@@ -110,26 +315,33 @@ pub trait TicketsModule:
 
         let last_ticket_id = first_ticket_id + nr_tickets - 1;
 
-        ticket_range_mapper.set(TicketRange {
+        self.ticket_batch(sale_round_id, first_ticket_id)
+            .set(&TicketBatch {
+                address: buyer.clone(),
+                nr_tickets,
+            });
+        self.participants().insert(buyer.clone());
+        last_ticket_id_mapper.set(last_ticket_id);
+
+        TicketRange {
             first_id: first_ticket_id,
             last_id: last_ticket_id,
-        });
-        self.ticket_batch(first_ticket_id).set(&TicketBatch {
-            address: buyer,
-            nr_tickets,
-        });
-        last_ticket_id_mapper.set(last_ticket_id);
+        }
     }
 
-    fn try_get_ticket_range(&self, address: &ManagedAddress) -> TicketRange {
-        let ticket_range_mapper = self.ticket_range_for_address(address);
-        require!(!ticket_range_mapper.is_empty(), "You have no tickets");
+    fn try_get_ticket_ranges(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+    ) -> TicketRanges<Self::Api> {
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(sale_round_id, address);
+        require!(!ticket_ranges_mapper.is_empty(), "You have no tickets");
 
-        ticket_range_mapper.get()
+        ticket_ranges_mapper.get()
     }
 
-    fn get_ticket_id_from_pos(&self, ticket_pos: usize) -> usize {
-        let ticket_id = self.ticket_pos_to_id(ticket_pos).get();
+    fn get_ticket_id_from_pos(&self, sale_round_id: SaleRoundId, ticket_pos: usize) -> usize {
+        let ticket_id = self.ticket_pos_to_id(sale_round_id, ticket_pos).get();
         if ticket_id == 0 {
             ticket_pos
         } else {
@@ -138,28 +350,83 @@ pub trait TicketsModule:
     }
 
     #[inline]
-    fn get_total_tickets(&self) -> usize {
-        self.last_ticket_id().get()
+    fn get_total_tickets(&self, sale_round_id: SaleRoundId) -> usize {
+        self.last_ticket_id(sale_round_id).get()
     }
 
-    #[storage_mapper("ticketStatus")]
-    fn ticket_status(&self, ticket_id: usize) -> SingleValueMapper<TicketStatus>;
+    fn ticket_status(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_id: usize,
+    ) -> SingleValueMapper<Self::Api, TicketStatus> {
+        let mut key = sale_round_storage_key(b"ticketStatus", sale_round_id);
+        key.append_item(&ticket_id);
+        SingleValueMapper::new(key)
+    }
 
     #[view(getTotalNumberOfTickets)]
-    #[storage_mapper("lastTicketId")]
-    fn last_ticket_id(&self) -> SingleValueMapper<usize>;
+    fn get_total_number_of_tickets(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) -> usize {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.last_ticket_id(sale_round_id).get()
+    }
 
-    #[storage_mapper("ticketBatch")]
-    fn ticket_batch(&self, start_index: usize) -> SingleValueMapper<TicketBatch<Self::Api>>;
+    fn last_ticket_id(&self, sale_round_id: SaleRoundId) -> SingleValueMapper<Self::Api, usize> {
+        SingleValueMapper::new(sale_round_storage_key(b"lastTicketId", sale_round_id))
+    }
 
-    #[storage_mapper("ticketRangeForAddress")]
-    fn ticket_range_for_address(&self, address: &ManagedAddress) -> SingleValueMapper<TicketRange>;
+    fn ticket_batch(
+        &self,
+        sale_round_id: SaleRoundId,
+        start_index: usize,
+    ) -> SingleValueMapper<Self::Api, TicketBatch<Self::Api>> {
+        let mut key = sale_round_storage_key(b"ticketBatch", sale_round_id);
+        key.append_item(&start_index);
+        SingleValueMapper::new(key)
+    }
+
+    #[storage_mapper("participants")]
+    fn participants(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stored as a single `TicketRange` before the allowance top-ups,
+    /// which decodes the same as a list with one range
+    fn ticket_ranges_for_address(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<Self::Api, TicketRanges<Self::Api>> {
+        let mut key = sale_round_storage_key(b"ticketRangeForAddress", sale_round_id);
+        key.append_item(address);
+        SingleValueMapper::new(key)
+    }
 
     #[view(getNumberOfConfirmedTicketsForAddress)]
-    #[storage_mapper("nrConfirmedTickets")]
-    fn nr_confirmed_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+    fn get_number_of_confirmed_tickets_for_address(
+        &self,
+        address: ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.nr_confirmed_tickets(sale_round_id, &address).get()
+    }
+
+    fn nr_confirmed_tickets(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<Self::Api, usize> {
+        let mut key = sale_round_storage_key(b"nrConfirmedTickets", sale_round_id);
+        key.append_item(address);
+        SingleValueMapper::new(key)
+    }
 
     // only used during shuffling. Default (0) means ticket pos = ticket ID.
-    #[storage_mapper("ticketPosToId")]
-    fn ticket_pos_to_id(&self, ticket_pos: usize) -> SingleValueMapper<usize>;
+    fn ticket_pos_to_id(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_pos: usize,
+    ) -> SingleValueMapper<Self::Api, usize> {
+        let mut key = sale_round_storage_key(b"ticketPosToId", sale_round_id);
+        key.append_item(&ticket_pos);
+        SingleValueMapper::new(key)
+    }
 }
@@ -1,15 +1,29 @@
 multiversx_sc::imports!();
 
-use crate::{common_events, config::TokenAmountPair};
+use crate::{
+    common_events,
+    config::{SaleRoundId, TokenAmountPair},
+};
 
 #[multiversx_sc::module]
-pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEventsModule {
-    fn refund_ticket_payment(&self, address: &ManagedAddress, nr_tickets_to_refund: usize) {
+pub trait TokenSendModule:
+    crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::launch_stage::LaunchStageModule
+    + crate::launchpad_stats::LaunchpadStatsModule
+    + common_events::CommonEventsModule
+{
+    fn refund_ticket_payment(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+        nr_tickets_to_refund: usize,
+    ) {
         if nr_tickets_to_refund == 0 {
             return;
         }
 
-        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(sale_round_id).get();
         let ticket_payment_refund_amount = ticket_price.amount * nr_tickets_to_refund as u32;
         self.send().direct(
             address,
@@ -17,8 +31,10 @@ pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEv
             0,
             &ticket_payment_refund_amount,
         );
+        self.record_ticket_payment_refund(nr_tickets_to_refund, &ticket_payment_refund_amount);
 
         self.emit_refund_ticket_payment_event(
+            sale_round_id,
             nr_tickets_to_refund,
             EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, ticket_payment_refund_amount),
         );
@@ -28,6 +44,7 @@ pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEv
         SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
     >(
         &self,
+        sale_round_id: SaleRoundId,
         address: &ManagedAddress,
         nr_claimed_tickets: usize,
         send_fn: SendLaunchpadTokensFn,
@@ -41,8 +58,12 @@ pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEv
         let launchpad_tokens_amount_to_send =
             BigUint::from(nr_claimed_tickets as u32) * tokens_per_winning_ticket;
 
+        self.record_launchpad_tokens_claim(&launchpad_tokens_amount_to_send);
+
         let payment = EsdtTokenPayment::new(launchpad_token_id, 0, launchpad_tokens_amount_to_send);
         send_fn(self, address, &payment);
+
+        self.emit_claim_launchpad_tokens_event(sale_round_id, payment);
     }
 
     fn default_send_launchpad_tokens_fn(
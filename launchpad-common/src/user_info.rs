@@ -0,0 +1,100 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    config::{SaleRoundId, TokenAmountPair},
+    launch_stage::LaunchStage,
+};
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum UserNftStatus {
+    None,
+    Confirmed,
+    Won,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct UserInfo<M: ManagedTypeApi> {
+    pub launch_stage: LaunchStage,
+    pub ticket_allowance: usize,
+    pub confirmed_tickets: usize,
+    pub guaranteed_tickets: usize,
+    pub is_blacklisted: bool,
+    pub winning_tickets: usize,
+    pub ticket_payment_refund: BigUint<M>,
+    pub claimable_tokens: BigUint<M>,
+    pub claimed_tokens: BigUint<M>,
+    pub has_claimed: bool,
+    pub nft_status: UserNftStatus,
+}
+
+#[multiversx_sc::module]
+pub trait UserInfoModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::winner_selection::WinnerSelectionModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::blacklist::BlacklistModule
+    + crate::user_interactions::UserInteractionsModule
+    + crate::token_send::TokenSendModule
+    + crate::launchpad_stats::LaunchpadStatsModule
+    + crate::permissions::PermissionsModule
+    + crate::common_events::CommonEventsModule
+    + crate::kyc::KycModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Fills in the fields common to all launchpad variants. Claimable tokens are reported
+    /// as the full amount won, which `extend_user_info_fn` may adjust, e.g. for vesting.
+    /// The ticket related fields refer to the given sale round, the others are shared.
+    fn get_user_info_base<
+        ExtendUserInfoFn: Fn(&Self, &ManagedAddress, &mut UserInfo<Self::Api>),
+    >(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: &ManagedAddress,
+        extend_user_info_fn: ExtendUserInfoFn,
+    ) -> UserInfo<Self::Api> {
+        let launch_stage = self.get_launch_stage(sale_round_id);
+        let can_claim = launch_stage == LaunchStage::Claim && !self.is_claim_deadline_passed();
+        let has_claimed = self.has_user_claimed(sale_round_id, address);
+        let confirmed_tickets = self.nr_confirmed_tickets(sale_round_id, address).get();
+        let winning_tickets =
+            self.get_number_of_winning_tickets_for_address(sale_round_id, address.clone());
+
+        let mut ticket_payment_refund = BigUint::zero();
+        let mut claimable_tokens = BigUint::zero();
+        if can_claim && !has_claimed {
+            let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(sale_round_id).get();
+            ticket_payment_refund =
+                ticket_price.amount * (confirmed_tickets - winning_tickets) as u32;
+
+            let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
+            claimable_tokens = tokens_per_winning_ticket * winning_tickets as u32;
+        }
+
+        let mut user_info = UserInfo {
+            launch_stage,
+            ticket_allowance: self.get_total_number_of_tickets_for_address(sale_round_id, address),
+            confirmed_tickets,
+            guaranteed_tickets: 0,
+            is_blacklisted: self.is_user_blacklisted(address),
+            winning_tickets,
+            ticket_payment_refund,
+            claimable_tokens,
+            claimed_tokens: BigUint::zero(),
+            has_claimed,
+            nft_status: UserNftStatus::None,
+        };
+        extend_user_info_fn(self, address, &mut user_info);
+
+        user_info
+    }
+
+    fn no_additional_user_info(
+        &self,
+        _address: &ManagedAddress,
+        _user_info: &mut UserInfo<Self::Api>,
+    ) {
+    }
+}
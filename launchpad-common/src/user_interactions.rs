@@ -1,6 +1,11 @@
 multiversx_sc::imports!();
 
-use crate::{config::TokenAmountPair, tickets::WINNING_TICKET};
+use crate::{
+    config::{sale_round_storage_key, SaleRoundId, TokenAmountPair, FIRST_SALE_ROUND_ID},
+    kyc::KycApproval,
+    pagination::get_page,
+    tickets::WINNING_TICKET,
+};
 
 #[multiversx_sc::module]
 pub trait UserInteractionsModule:
@@ -11,15 +16,30 @@ pub trait UserInteractionsModule:
     + crate::token_send::TokenSendModule
     + crate::permissions::PermissionsModule
     + crate::common_events::CommonEventsModule
+    + crate::kyc::KycModule
+    + crate::launchpad_stats::LaunchpadStatsModule
     + multiversx_sc_modules::pause::PauseModule
 {
+    /// Once terms of sale are published, users must pass their hash as `opt_accepted_terms_hash`
+    /// until they accept the current version. An empty hash accepts nothing, e.g. when only
+    /// the KYC approval is needed.
+    /// Once a KYC signer is set, `opt_kyc_approval` is required,
+    /// and caps the number of tickets the caller may confirm in the sale round.
     #[payable("*")]
+    #[allow_multiple_var_args]
     #[endpoint(confirmTickets)]
-    fn confirm_tickets(&self, nr_tickets_to_confirm: usize) {
+    fn confirm_tickets(
+        &self,
+        nr_tickets_to_confirm: usize,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+        opt_accepted_terms_hash: OptionalValue<ManagedBuffer>,
+        opt_kyc_approval: OptionalValue<KycApproval<Self::Api>>,
+    ) {
         self.require_not_paused();
         let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
 
-        self.require_confirmation_period();
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.require_confirmation_period(sale_round_id);
         require!(
             self.were_launchpad_tokens_deposited(),
             "Launchpad tokens not deposited yet"
@@ -31,15 +51,26 @@ pub trait UserInteractionsModule:
             "You have been put into the blacklist and may not confirm tickets"
         );
 
-        let total_tickets = self.get_total_number_of_tickets_for_address(&caller);
-        let nr_confirmed = self.nr_confirmed_tickets(&caller).get();
+        let total_tickets = self.get_total_number_of_tickets_for_address(sale_round_id, &caller);
+        let nr_confirmed = self.nr_confirmed_tickets(sale_round_id, &caller).get();
         let total_confirmed = nr_confirmed + nr_tickets_to_confirm;
         require!(
             total_confirmed <= total_tickets,
             "Trying to confirm too many tickets"
         );
 
-        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let accepted_terms_hash = opt_accepted_terms_hash.into_option().unwrap_or_default();
+        self.require_terms_of_sale_accepted(&caller, &accepted_terms_hash);
+        if let Some(max_tickets) =
+            self.require_kyc_approval(&caller, sale_round_id, opt_kyc_approval)
+        {
+            require!(
+                total_confirmed <= max_tickets,
+                "Trying to confirm more tickets than KYC approved"
+            );
+        }
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(sale_round_id).get();
         let total_ticket_price = ticket_price.amount * nr_tickets_to_confirm as u32;
         require!(
             payment_token == ticket_price.token_id,
@@ -47,10 +78,20 @@ pub trait UserInteractionsModule:
         );
         require!(payment_amount == total_ticket_price, "Wrong amount sent");
 
-        self.nr_confirmed_tickets(&caller).set(total_confirmed);
+        // users confirming in several sale rounds are counted once,
+        // even if they already claimed (and thus cleared) their previous tickets
+        let is_new_user = self.sale_round_ids().all(|other_sale_round_id| {
+            self.nr_confirmed_tickets(other_sale_round_id, &caller)
+                .is_empty()
+                && !self.has_user_claimed(other_sale_round_id, &caller)
+        });
+        self.nr_confirmed_tickets(sale_round_id, &caller)
+            .set(total_confirmed);
+        self.record_tickets_confirmation(is_new_user, nr_tickets_to_confirm, &payment_amount);
 
         let token_payment = EgldOrEsdtTokenPayment::new(payment_token, 0, payment_amount);
         self.emit_confirm_tickets_event(
+            sale_round_id,
             nr_tickets_to_confirm,
             total_confirmed,
             total_tickets,
@@ -58,55 +99,172 @@ pub trait UserInteractionsModule:
         );
     }
 
+    /// Records the acceptance of the current terms of sale, if any are published
+    fn require_terms_of_sale_accepted(
+        &self,
+        caller: &ManagedAddress,
+        accepted_terms_hash: &ManagedBuffer,
+    ) {
+        let terms_mapper = self.terms_of_sale();
+        if terms_mapper.is_empty() {
+            require!(
+                accepted_terms_hash.is_empty(),
+                "Terms of sale hash mismatch"
+            );
+            return;
+        }
+
+        let terms = terms_mapper.get();
+        let accepted_terms_mapper = self.accepted_terms_of_sale(caller);
+        let has_accepted_current_terms =
+            !accepted_terms_mapper.is_empty() && accepted_terms_mapper.get() == terms;
+        if accepted_terms_hash.is_empty() {
+            require!(
+                has_accepted_current_terms,
+                "Must accept the current terms of sale"
+            );
+            return;
+        }
+
+        require!(
+            accepted_terms_hash == terms.hash.as_managed_buffer(),
+            "Terms of sale hash mismatch"
+        );
+        if !has_accepted_current_terms {
+            accepted_terms_mapper.set(&terms);
+            self.emit_accept_terms_of_sale_event(terms);
+        }
+    }
+
     fn claim_launchpad_tokens<
         SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
     >(
         &self,
+        sale_round_id: SaleRoundId,
         send_fn: SendLaunchpadTokensFn,
     ) {
-        self.require_claim_period();
+        self.require_claim_period(sale_round_id);
+        self.require_before_claim_deadline();
 
         let caller = self.blockchain().get_caller();
-        require!(!self.has_user_claimed(&caller), "Already claimed");
+        require!(
+            !self.has_user_claimed(sale_round_id, &caller),
+            "Already claimed"
+        );
+
+        let nr_redeemable_tickets = self.compute_user_launchpad_results(sale_round_id, &caller);
+        self.send_launchpad_tokens(sale_round_id, &caller, nr_redeemable_tickets, send_fn);
+    }
 
-        let ticket_range = self.try_get_ticket_range(&caller);
-        let nr_confirmed_tickets = self.nr_confirmed_tickets(&caller).get();
+    /// Clears the user's tickets, marks the user as claimed and refunds the losing tickets.
+    /// Returns the number of winning tickets, for which the launchpad tokens are still to be sent.
+    fn compute_user_launchpad_results(
+        &self,
+        sale_round_id: SaleRoundId,
+        caller: &ManagedAddress,
+    ) -> usize {
+        let ticket_ranges = self.try_get_ticket_ranges(sale_round_id, caller);
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(sale_round_id, caller).get();
         let mut nr_redeemable_tickets = 0;
 
-        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
-            if ticket_status == WINNING_TICKET {
-                self.ticket_status(ticket_id).clear();
+        for ticket_range in ticket_ranges.iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                let ticket_status_mapper = self.ticket_status(sale_round_id, ticket_id);
+                if ticket_status_mapper.get() == WINNING_TICKET {
+                    ticket_status_mapper.clear();
+
+                    nr_redeemable_tickets += 1;
+                }
 
-                nr_redeemable_tickets += 1;
+                self.ticket_pos_to_id(sale_round_id, ticket_id).clear();
             }
 
-            self.ticket_pos_to_id(ticket_id).clear();
+            self.ticket_batch(sale_round_id, ticket_range.first_id)
+                .clear();
         }
 
-        self.nr_confirmed_tickets(&caller).clear();
-        self.ticket_range_for_address(&caller).clear();
-        self.ticket_batch(ticket_range.first_id).clear();
+        self.nr_confirmed_tickets(sale_round_id, caller).clear();
+        self.ticket_ranges_for_address(sale_round_id, caller)
+            .clear();
 
         if nr_redeemable_tickets > 0 {
-            self.nr_winning_tickets()
+            self.nr_winning_tickets(sale_round_id)
                 .update(|nr_winning_tickets| *nr_winning_tickets -= nr_redeemable_tickets);
         }
 
-        self.claim_list().add(&caller);
+        let is_first_claim = self
+            .sale_round_ids()
+            .all(|other_sale_round_id| !self.has_user_claimed(other_sale_round_id, caller));
+        if is_first_claim {
+            self.record_user_claim();
+        }
+        self.claim_list(sale_round_id).add(caller);
+        self.claimed_users(sale_round_id).insert(caller.clone());
+
+        let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
+        self.emit_launchpad_results_computed_event(
+            sale_round_id,
+            nr_confirmed_tickets,
+            nr_redeemable_tickets,
+            tokens_per_winning_ticket * nr_redeemable_tickets as u32,
+        );
 
         let nr_tickets_to_refund = nr_confirmed_tickets - nr_redeemable_tickets;
-        self.refund_ticket_payment(&caller, nr_tickets_to_refund);
-        self.send_launchpad_tokens(&caller, nr_redeemable_tickets, send_fn);
+        self.refund_ticket_payment(sale_round_id, caller, nr_tickets_to_refund);
+
+        nr_redeemable_tickets
     }
 
     #[view(hasUserClaimedTokens)]
-    fn has_user_claimed(&self, address: &ManagedAddress) -> bool {
-        self.claim_list().contains(address)
+    fn has_user_claimed_view(
+        &self,
+        address: &ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> bool {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.has_user_claimed(sale_round_id, address)
+    }
+
+    fn has_user_claimed(&self, sale_round_id: SaleRoundId, address: &ManagedAddress) -> bool {
+        self.claim_list(sale_round_id).contains(address)
     }
 
-    // flags
+    /// Copies the claim list entries of the first sale round written before the enumerable copy existed,
+    /// e.g. by a contract upgraded in place, so they are listed and removed by the storage cleanup.
+    /// The other sale rounds always write both. The owner may split the users over several calls.
+    /// Users who did not claim are ignored. Returns the number of users added.
+    #[only_owner]
+    #[endpoint(backfillClaimedUsers)]
+    fn backfill_claimed_users(&self, users_list: MultiValueEncoded<ManagedAddress>) -> usize {
+        let claim_list_mapper = self.claim_list(FIRST_SALE_ROUND_ID);
+        let mut claimed_users_mapper = self.claimed_users(FIRST_SALE_ROUND_ID);
+        let mut nr_users_added = 0;
+        for address in users_list {
+            if claim_list_mapper.contains(&address) && claimed_users_mapper.insert(address) {
+                nr_users_added += 1;
+            }
+        }
+
+        nr_users_added
+    }
+
+    /// Users who claimed before the upgrade that added this view are only listed once backfilled
+    #[view(getClaimedUsers)]
+    fn get_claimed_users(
+        &self,
+        offset: usize,
+        limit: usize,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        get_page(&self.claimed_users(sale_round_id), offset, limit)
+    }
+
+    fn claim_list(&self, sale_round_id: SaleRoundId) -> WhitelistMapper<Self::Api, ManagedAddress> {
+        WhitelistMapper::new(sale_round_storage_key(b"claimedTokens", sale_round_id))
+    }
 
-    #[storage_mapper("claimedTokens")]
-    fn claim_list(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+    /// Enumerable copy of the claim list, which stays the source of truth
+    #[storage_mapper("claimedUsers")]
+    fn claimed_users(&self, sale_round_id: SaleRoundId) -> UnorderedSetMapper<ManagedAddress>;
 }
@@ -1,6 +1,7 @@
 multiversx_sc::imports!();
 
 use crate::{
+    config::SaleRoundId,
     launch_stage::Flags,
     ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
     random::Random,
@@ -15,21 +16,27 @@ pub trait WinnerSelectionModule:
     + crate::config::ConfigModule
     + crate::blacklist::BlacklistModule
     + crate::token_send::TokenSendModule
+    + crate::launchpad_stats::LaunchpadStatsModule
     + crate::permissions::PermissionsModule
     + crate::common_events::CommonEventsModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[endpoint(filterTickets)]
-    fn filter_tickets(&self) -> OperationCompletionStatus {
+    fn filter_tickets(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> OperationCompletionStatus {
         self.require_not_paused();
-        self.require_winner_selection_period();
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.require_winner_selection_period(sale_round_id);
 
-        let flags_mapper = self.flags();
+        let flags_mapper = self.flags(sale_round_id);
         let mut flags: Flags = flags_mapper.get();
         require!(!flags.were_tickets_filtered, "Tickets already filtered");
 
-        let last_ticket_id = self.last_ticket_id().get();
-        let (mut first_ticket_id_in_batch, mut nr_removed) = self.load_filter_tickets_operation();
+        let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+        let (mut first_ticket_id_in_batch, mut nr_removed) =
+            self.load_filter_tickets_operation(sale_round_id);
 
         if first_ticket_id_in_batch == FIRST_TICKET_ID {
             flags.has_winner_selection_process_started = true;
@@ -40,32 +47,18 @@ pub trait WinnerSelectionModule:
                 return STOP_OP;
             }
 
-            let current_ticket_batch_mapper = self.ticket_batch(first_ticket_id_in_batch);
-            let ticket_batch: TicketBatch<Self::Api> = current_ticket_batch_mapper.get();
-            let address = &ticket_batch.address;
+            let ticket_batch: TicketBatch<Self::Api> = self
+                .ticket_batch(sale_round_id, first_ticket_id_in_batch)
+                .get();
             let nr_tickets_in_batch = ticket_batch.nr_tickets;
-
-            let nr_confirmed_tickets = self.nr_confirmed_tickets(address).get();
-            if nr_confirmed_tickets == 0 {
-                self.ticket_range_for_address(address).clear();
-                current_ticket_batch_mapper.clear();
-            } else if nr_removed > 0 || nr_confirmed_tickets < nr_tickets_in_batch {
-                let new_first_id = first_ticket_id_in_batch - nr_removed;
-                let new_last_id = new_first_id + nr_confirmed_tickets - 1;
-
-                current_ticket_batch_mapper.clear();
-
-                self.ticket_range_for_address(address).set(TicketRange {
-                    first_id: new_first_id,
-                    last_id: new_last_id,
-                });
-                self.ticket_batch(new_first_id).set(&TicketBatch {
-                    address: ticket_batch.address,
-                    nr_tickets: nr_confirmed_tickets,
-                });
-            }
-
-            nr_removed += nr_tickets_in_batch - nr_confirmed_tickets;
+            let nr_kept_tickets = self.filter_ticket_batch(
+                sale_round_id,
+                ticket_batch,
+                first_ticket_id_in_batch,
+                nr_removed,
+            );
+
+            nr_removed += nr_tickets_in_batch - nr_kept_tickets;
             first_ticket_id_in_batch += nr_tickets_in_batch;
 
             CONTINUE_OP
@@ -73,24 +66,25 @@ pub trait WinnerSelectionModule:
 
         match run_result {
             OperationCompletionStatus::InterruptedBeforeOutOfGas => {
-                self.save_progress(&OngoingOperationType::FilterTickets {
+                self.save_progress(&OngoingOperationType::filter_tickets(
+                    sale_round_id,
                     first_ticket_id_in_batch,
                     nr_removed,
-                });
+                ));
             }
             OperationCompletionStatus::Completed => {
                 // this only happens when a lot of tickets have been eliminated,
                 // and we end up with less total tickets than winning
                 let new_last_ticket_id = last_ticket_id - nr_removed;
-                let nr_winning_tickets = self.nr_winning_tickets().get();
-                if nr_winning_tickets > new_last_ticket_id {
-                    self.nr_winning_tickets().set(new_last_ticket_id);
+                let nr_winning_tickets_mapper = self.nr_winning_tickets(sale_round_id);
+                if nr_winning_tickets_mapper.get() > new_last_ticket_id {
+                    nr_winning_tickets_mapper.set(new_last_ticket_id);
                 }
 
-                self.last_ticket_id().set(new_last_ticket_id);
+                self.last_ticket_id(sale_round_id).set(new_last_ticket_id);
                 flags.were_tickets_filtered = true;
 
-                self.emit_filter_tickets_completed_event(new_last_ticket_id);
+                self.emit_filter_tickets_completed_event(sale_round_id, new_last_ticket_id);
             }
         };
 
@@ -99,28 +93,111 @@ pub trait WinnerSelectionModule:
         run_result
     }
 
+    /// The confirmed tickets of an address fill its batches in order.
+    /// Batches no longer listed for the address, i.e. taken out of its allowance, are dropped.
+    /// Returns the number of tickets kept.
+    fn filter_ticket_batch(
+        &self,
+        sale_round_id: SaleRoundId,
+        ticket_batch: TicketBatch<Self::Api>,
+        first_ticket_id_in_batch: usize,
+        nr_removed: usize,
+    ) -> usize {
+        let current_ticket_batch_mapper =
+            self.ticket_batch(sale_round_id, first_ticket_id_in_batch);
+        let ticket_ranges_mapper =
+            self.ticket_ranges_for_address(sale_round_id, &ticket_batch.address);
+        let mut ticket_ranges = ticket_ranges_mapper.get();
+        let opt_range_index = ticket_ranges
+            .iter()
+            .position(|ticket_range| ticket_range.first_id == first_ticket_id_in_batch);
+        let range_index = match opt_range_index {
+            Some(range_index) => range_index,
+            None => {
+                current_ticket_batch_mapper.clear();
+
+                return 0;
+            }
+        };
+
+        let nr_kept_in_previous_batches: usize = ticket_ranges
+            .iter()
+            .take(range_index)
+            .map(|ticket_range| ticket_range.nr_tickets())
+            .sum();
+        let nr_confirmed_tickets = self
+            .nr_confirmed_tickets(sale_round_id, &ticket_batch.address)
+            .get();
+        let nr_kept_tickets = core::cmp::min(
+            ticket_batch.nr_tickets,
+            nr_confirmed_tickets.saturating_sub(nr_kept_in_previous_batches),
+        );
+
+        if nr_kept_tickets == 0 {
+            current_ticket_batch_mapper.clear();
+            ticket_ranges.remove(range_index);
+        } else if nr_removed > 0 || nr_kept_tickets < ticket_batch.nr_tickets {
+            let new_first_id = first_ticket_id_in_batch - nr_removed;
+            let new_last_id = new_first_id + nr_kept_tickets - 1;
+
+            current_ticket_batch_mapper.clear();
+
+            let _ = ticket_ranges.set(
+                range_index,
+                TicketRange {
+                    first_id: new_first_id,
+                    last_id: new_last_id,
+                },
+            );
+            self.ticket_batch(sale_round_id, new_first_id)
+                .set(&TicketBatch {
+                    address: ticket_batch.address.clone(),
+                    nr_tickets: nr_kept_tickets,
+                });
+        } else {
+            return nr_kept_tickets;
+        }
+
+        if ticket_ranges.is_empty() {
+            ticket_ranges_mapper.clear();
+        } else {
+            ticket_ranges_mapper.set(&ticket_ranges);
+        }
+
+        nr_kept_tickets
+    }
+
     #[endpoint(selectWinners)]
-    fn select_winners(&self) -> OperationCompletionStatus {
+    fn select_winners(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> OperationCompletionStatus {
         self.require_not_paused();
-        self.require_winner_selection_period();
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.require_winner_selection_period(sale_round_id);
 
         self.check_caller_owner_or_user();
 
-        let flags_mapper = self.flags();
+        let flags_mapper = self.flags(sale_round_id);
         let mut flags: Flags = flags_mapper.get();
         require!(flags.were_tickets_filtered, "Must filter tickets first");
         require!(!flags.were_winners_selected, "Winners already selected");
 
-        let nr_winning_tickets = self.nr_winning_tickets().get();
-        let last_ticket_position = self.get_total_tickets();
+        let nr_winning_tickets = self.nr_winning_tickets(sale_round_id).get();
+        let last_ticket_position = self.get_total_tickets(sale_round_id);
 
-        let (mut rng, mut ticket_position) = self.load_select_winners_operation();
+        let (mut rng, mut ticket_position) = self.load_select_winners_operation(sale_round_id);
         let run_result = self.run_while_it_has_gas(|| {
             if nr_winning_tickets == 0 {
                 return STOP_OP;
             }
 
-            self.shuffle_single_ticket(&mut rng, ticket_position, last_ticket_position);
+            self.shuffle_single_ticket(
+                sale_round_id,
+                &mut rng,
+                ticket_position,
+                last_ticket_position,
+            );
 
             if ticket_position == nr_winning_tickets {
                 return STOP_OP;
@@ -133,20 +210,21 @@ pub trait WinnerSelectionModule:
 
         match run_result {
             OperationCompletionStatus::InterruptedBeforeOutOfGas => {
-                self.save_progress(&OngoingOperationType::SelectWinners {
+                self.save_progress(&OngoingOperationType::select_winners(
+                    sale_round_id,
                     rng,
                     ticket_position,
-                });
+                ));
             }
             OperationCompletionStatus::Completed => {
                 flags.were_winners_selected = true;
 
-                let ticket_price = self.ticket_price().get();
-                let claimable_ticket_payment = ticket_price.amount * (nr_winning_tickets as u32);
+                let claimable_ticket_payment =
+                    self.get_winning_tickets_payment(sale_round_id, nr_winning_tickets);
                 self.claimable_ticket_payment()
-                    .set(&claimable_ticket_payment);
+                    .update(|total_claimable| *total_claimable += claimable_ticket_payment);
 
-                self.emit_select_winners_completed_event(nr_winning_tickets);
+                self.emit_select_winners_completed_event(sale_round_id, nr_winning_tickets);
             }
         };
 
@@ -155,45 +233,172 @@ pub trait WinnerSelectionModule:
         run_result
     }
 
+    /// Rolls back a partially executed winner selection to the state right after filtering.
+    /// Not available once the additional selection step was started.
+    #[only_owner]
+    #[endpoint(abortWinnerSelection)]
+    fn abort_winner_selection(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> OperationCompletionStatus {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.require_winner_selection_period(sale_round_id);
+
+        let flags_mapper = self.flags(sale_round_id);
+        let mut flags: Flags = flags_mapper.get();
+        require!(flags.were_tickets_filtered, "Must filter tickets first");
+
+        let mut ticket_position = match self.current_ongoing_operation().get().with_sale_round() {
+            OngoingOperationType::SelectWinnersForSaleRound {
+                sale_round_id: op_sale_round_id,
+                rng,
+                ticket_position,
+            } if op_sale_round_id == sale_round_id => {
+                self.emit_abort_winner_selection_event(
+                    sale_round_id,
+                    rng.seed,
+                    ticket_position - FIRST_TICKET_ID,
+                );
+
+                FIRST_TICKET_ID
+            }
+            OngoingOperationType::None => {
+                require!(
+                    flags.were_winners_selected && !flags.was_additional_step_completed,
+                    "No winner selection to abort"
+                );
+
+                let nr_winning_tickets = self.nr_winning_tickets(sale_round_id).get();
+                self.emit_abort_winner_selection_event(
+                    sale_round_id,
+                    ManagedBuffer::new(),
+                    nr_winning_tickets,
+                );
+
+                FIRST_TICKET_ID
+            }
+            OngoingOperationType::AbortWinnerSelection {
+                sale_round_id: op_sale_round_id,
+                ticket_position,
+            } if op_sale_round_id == sale_round_id => ticket_position,
+            _ => sc_panic!("Cannot abort the current operation"),
+        };
+
+        let last_ticket_id = self.last_ticket_id(sale_round_id).get();
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_position > last_ticket_id {
+                return STOP_OP;
+            }
+
+            self.ticket_status(sale_round_id, ticket_position).clear();
+            self.ticket_pos_to_id(sale_round_id, ticket_position)
+                .clear();
+            ticket_position += 1;
+
+            CONTINUE_OP
+        });
+
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_progress(&OngoingOperationType::AbortWinnerSelection {
+                    sale_round_id,
+                    ticket_position,
+                });
+            }
+            OperationCompletionStatus::Completed => {
+                if flags.were_winners_selected {
+                    let nr_winning_tickets = self.nr_winning_tickets(sale_round_id).get();
+                    let claimable_ticket_payment =
+                        self.get_winning_tickets_payment(sale_round_id, nr_winning_tickets);
+                    self.claimable_ticket_payment()
+                        .update(|total_claimable| *total_claimable -= claimable_ticket_payment);
+                }
+
+                flags.were_winners_selected = false;
+                flags_mapper.set(&flags);
+            }
+        };
+
+        run_result
+    }
+
     /// Fisher-Yates algorithm,
     /// each position i is swapped with a random one in range [i, n]
     fn shuffle_single_ticket(
         &self,
+        sale_round_id: SaleRoundId,
         rng: &mut Random<Self::Api>,
         current_ticket_position: usize,
         last_ticket_position: usize,
     ) {
         let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
 
-        let winning_ticket_id = self.get_ticket_id_from_pos(rand_pos);
-        self.ticket_status(winning_ticket_id).set(WINNING_TICKET);
+        let winning_ticket_id = self.get_ticket_id_from_pos(sale_round_id, rand_pos);
+        self.ticket_status(sale_round_id, winning_ticket_id)
+            .set(WINNING_TICKET);
+
+        let current_ticket_id = self.get_ticket_id_from_pos(sale_round_id, current_ticket_position);
+        self.ticket_pos_to_id(sale_round_id, rand_pos)
+            .set(current_ticket_id);
+    }
+
+    fn get_winning_tickets_payment(
+        &self,
+        sale_round_id: SaleRoundId,
+        nr_winning_tickets: usize,
+    ) -> BigUint {
+        let ticket_price = self.ticket_price(sale_round_id).get();
 
-        let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
-        self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
+        ticket_price.amount * (nr_winning_tickets as u32)
     }
 
     #[view(getNumberOfWinningTicketsForAddress)]
-    fn get_number_of_winning_tickets_for_address(&self, address: ManagedAddress) -> usize {
-        self.get_winning_ticket_ids_for_address(address).len()
+    fn get_number_of_winning_tickets_for_address_view(
+        &self,
+        address: ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_number_of_winning_tickets_for_address(sale_round_id, address)
     }
 
     #[view(getWinningTicketIdsForAddress)]
+    fn get_winning_ticket_ids_for_address_view(
+        &self,
+        address: ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> MultiValueEncoded<usize> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_winning_ticket_ids_for_address(sale_round_id, address)
+    }
+
+    fn get_number_of_winning_tickets_for_address(
+        &self,
+        sale_round_id: SaleRoundId,
+        address: ManagedAddress,
+    ) -> usize {
+        self.get_winning_ticket_ids_for_address(sale_round_id, address)
+            .len()
+    }
+
     fn get_winning_ticket_ids_for_address(
         &self,
+        sale_round_id: SaleRoundId,
         address: ManagedAddress,
     ) -> MultiValueEncoded<usize> {
-        let flags: Flags = self.flags().get();
-        let ticket_range_mapper = self.ticket_range_for_address(&address);
+        let flags: Flags = self.flags(sale_round_id).get();
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(sale_round_id, &address);
         let mut ticket_ids = MultiValueEncoded::new();
-        if !flags.were_winners_selected || ticket_range_mapper.is_empty() {
+        if !flags.were_winners_selected || ticket_ranges_mapper.is_empty() {
             return ticket_ids;
         }
 
-        let ticket_range: TicketRange = ticket_range_mapper.get();
-        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let actual_ticket_status = self.ticket_status(ticket_id).get();
-            if actual_ticket_status == WINNING_TICKET {
-                ticket_ids.push(ticket_id);
+        for ticket_range in ticket_ranges_mapper.get().iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                let actual_ticket_status = self.ticket_status(sale_round_id, ticket_id).get();
+                if actual_ticket_status == WINNING_TICKET {
+                    ticket_ids.push(ticket_id);
+                }
             }
         }
 
@@ -0,0 +1,87 @@
+//! The contract lists every add-on module under a fixed path in this module.
+//! If the add-on's cargo feature is enabled, the path leads to the actual module.
+//! Otherwise it leads to an empty module, or, for the modules of this crate,
+//! to a disabled version providing the same hooks as no-ops.
+//! This way, the same contract declaration compiles for every combination of features.
+
+#[allow(unused_macros)]
+macro_rules! disabled_modules {
+    ($($module:ident::$module_trait:ident),+ $(,)?) => {
+        $(
+            pub mod $module {
+                multiversx_sc::imports!();
+
+                #[multiversx_sc::module]
+                pub trait $module_trait {}
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "guaranteed-tickets")]
+pub mod guaranteed_tickets;
+#[cfg(not(feature = "guaranteed-tickets"))]
+#[path = "add_ons/disabled/guaranteed_tickets.rs"]
+pub mod guaranteed_tickets;
+#[cfg(feature = "guaranteed-tickets")]
+pub use launchpad_guaranteed_tickets::{guaranteed_ticket_winners, guaranteed_tickets_init};
+#[cfg(not(feature = "guaranteed-tickets"))]
+disabled_modules!(
+    guaranteed_tickets_init::GuaranteedTicketsInitModule,
+    guaranteed_ticket_winners::GuaranteedTicketWinnersModule,
+);
+
+#[cfg(feature = "migration-allowances")]
+pub mod migration_allowances;
+#[cfg(not(feature = "migration-allowances"))]
+disabled_modules!(migration_allowances::MigrationAllowancesModule);
+
+#[cfg(feature = "nft-draw")]
+pub mod nft_draw;
+#[cfg(not(feature = "nft-draw"))]
+#[path = "add_ons/disabled/nft_draw.rs"]
+pub mod nft_draw;
+#[cfg(feature = "nft-draw")]
+pub use launchpad_with_nft::{
+    claim_nft, confirm_nft, events as nft_events, mystery_sft, nft_blacklist, nft_config,
+    nft_winners_selection,
+};
+#[cfg(feature = "nft-draw")]
+pub use multiversx_sc_modules::default_issue_callbacks;
+#[cfg(not(feature = "nft-draw"))]
+disabled_modules!(
+    claim_nft::ClaimNftModule,
+    confirm_nft::ConfirmNftModule,
+    nft_events::EventsModule,
+    mystery_sft::MysterySftModule,
+    nft_blacklist::NftBlacklistModule,
+    nft_config::NftConfigModule,
+    nft_winners_selection::NftWinnersSelectionModule,
+    default_issue_callbacks::DefaultIssueCallbacksModule,
+);
+
+#[cfg(feature = "locked-tokens")]
+pub mod locked_tokens;
+#[cfg(not(feature = "locked-tokens"))]
+#[path = "add_ons/disabled/locked_tokens.rs"]
+pub mod locked_tokens;
+#[cfg(feature = "locked-tokens")]
+pub use launchpad_locked_tokens::{events as locked_tokens_events, locked_launchpad_token_send};
+#[cfg(not(feature = "locked-tokens"))]
+disabled_modules!(
+    locked_tokens_events::EventsModule,
+    locked_launchpad_token_send::LockedLaunchpadTokenSend,
+);
+
+#[cfg(feature = "vesting")]
+pub mod vesting;
+#[cfg(not(feature = "vesting"))]
+#[path = "add_ons/disabled/vesting.rs"]
+pub mod vesting;
+#[cfg(feature = "vesting")]
+pub use launchpad_guaranteed_tickets_v2::{events as vesting_events, token_release};
+#[cfg(not(feature = "vesting"))]
+disabled_modules!(
+    vesting_events::EventsModule,
+    token_release::TokenReleaseModule,
+);
@@ -0,0 +1,50 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, user_info::UserInfo};
+
+/// (address, number of tickets)
+pub type AddTicketsEntry<M> = MultiValue2<ManagedAddress<M>, usize>;
+
+/// Without guaranteed tickets, all the winning tickets go through the base selection
+#[multiversx_sc::module]
+pub trait GuaranteedTicketsAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+{
+    #[inline]
+    fn add_tickets_entries(
+        &self,
+        address_number_pairs: MultiValueEncoded<AddTicketsEntry<Self::Api>>,
+    ) {
+        self.add_tickets(FIRST_SALE_ROUND_ID, address_number_pairs);
+    }
+
+    #[inline]
+    fn set_ticket_allowances_entries(
+        &self,
+        address_number_pairs: MultiValueEncoded<AddTicketsEntry<Self::Api>>,
+    ) {
+        self.set_ticket_allowances(FIRST_SALE_ROUND_ID, address_number_pairs);
+    }
+
+    #[inline]
+    fn nr_guaranteed_winning_tickets(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn clear_guaranteed_tickets_after_blacklist(&self, _users: &ManagedVec<ManagedAddress>) {}
+
+    #[inline]
+    fn add_guaranteed_tickets_user_info(
+        &self,
+        _address: &ManagedAddress,
+        _user_info: &mut UserInfo<Self::Api>,
+    ) {
+    }
+
+    #[inline]
+    fn clear_guaranteed_tickets_user_storage(&self, _address: &ManagedAddress) {}
+}
@@ -0,0 +1,27 @@
+multiversx_sc::imports!();
+
+/// Without locked tokens, the launchpad tokens are sent directly to the users
+#[multiversx_sc::module]
+pub trait LockedTokensAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::token_send::TokenSendModule
+{
+    #[inline]
+    fn require_locked_tokens_configured(&self) {}
+
+    #[inline]
+    fn require_liquid_launchpad_tokens(&self) {}
+
+    #[inline]
+    fn send_claimed_launchpad_tokens(
+        &self,
+        address: &ManagedAddress,
+        payment: &EsdtTokenPayment<Self::Api>,
+    ) {
+        self.default_send_launchpad_tokens_fn(address, payment);
+    }
+}
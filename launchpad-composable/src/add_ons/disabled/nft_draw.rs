@@ -0,0 +1,30 @@
+multiversx_sc::imports!();
+
+use launchpad_common::user_info::UserInfo;
+
+/// Without the NFT draw, the users only take part in the launchpad tokens sale
+#[multiversx_sc::module]
+pub trait NftDrawAddOnModule {
+    #[inline]
+    fn init_nft_draw(&self) {}
+
+    #[inline]
+    fn require_nft_draw_configured(&self) {}
+
+    #[inline]
+    fn refund_nft_draw_after_blacklist(&self, _users: &ManagedVec<ManagedAddress>) {}
+
+    #[inline]
+    fn claim_nft_draw_prize(&self, _is_first_claim: bool) {}
+
+    #[inline]
+    fn claim_nft_draw_payment(&self) {}
+
+    #[inline]
+    fn add_nft_draw_user_info(
+        &self,
+        _address: &ManagedAddress,
+        _user_info: &mut UserInfo<Self::Api>,
+    ) {
+    }
+}
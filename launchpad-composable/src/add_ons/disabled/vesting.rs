@@ -0,0 +1,50 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, user_info::UserInfo};
+
+/// Without vesting, all the won launchpad tokens are sent on the first claim
+#[multiversx_sc::module]
+pub trait VestingAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[inline]
+    fn release_launchpad_tokens<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
+    >(
+        &self,
+        send_fn: SendLaunchpadTokensFn,
+    ) {
+        self.claim_launchpad_tokens(FIRST_SALE_ROUND_ID, send_fn);
+    }
+
+    #[inline]
+    fn get_unreleased_launchpad_tokens(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    #[inline]
+    fn get_vesting_last_release_round(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn add_vesting_release_user_info(
+        &self,
+        _address: &ManagedAddress,
+        _user_info: &mut UserInfo<Self::Api>,
+    ) {
+    }
+
+    #[inline]
+    fn clear_vesting_user_storage(&self, _address: &ManagedAddress) {}
+}
@@ -0,0 +1,87 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, user_info::UserInfo};
+
+/// (address, staking tickets, energy tickets, has migrated tokens)
+pub type AddTicketsEntry<M> = MultiValue4<ManagedAddress<M>, usize, usize, bool>;
+
+#[multiversx_sc::module]
+pub trait GuaranteedTicketsAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + launchpad_guaranteed_tickets::guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+{
+    #[only_owner]
+    #[label("guaranteed-tickets")]
+    #[endpoint(setMinConfirmedForGuaranteedTicket)]
+    fn set_min_confirmed_for_guaranteed_ticket(&self, min_confirmed_for_guaranteed_ticket: usize) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(
+            min_confirmed_for_guaranteed_ticket > 0,
+            "Invalid minimum tickets confirmed for guaranteed winning ticket"
+        );
+
+        self.min_confirmed_for_guaranteed_ticket()
+            .set(min_confirmed_for_guaranteed_ticket);
+    }
+
+    fn add_tickets_entries(
+        &self,
+        address_number_pairs: MultiValueEncoded<AddTicketsEntry<Self::Api>>,
+    ) {
+        self.require_valid_add_tickets_entries(&address_number_pairs);
+        self.add_tickets_with_guaranteed_winners(address_number_pairs);
+    }
+
+    fn set_ticket_allowances_entries(
+        &self,
+        address_number_pairs: MultiValueEncoded<AddTicketsEntry<Self::Api>>,
+    ) {
+        self.require_valid_add_tickets_entries(&address_number_pairs);
+        self.set_tickets_with_guaranteed_winners(address_number_pairs);
+    }
+
+    fn require_valid_add_tickets_entries(
+        &self,
+        address_number_pairs: &MultiValueEncoded<AddTicketsEntry<Self::Api>>,
+    ) {
+        require!(
+            !self.min_confirmed_for_guaranteed_ticket().is_empty(),
+            "Minimum tickets confirmed for guaranteed winning ticket not set"
+        );
+
+        if !cfg!(feature = "migration-allowances") {
+            for entry in address_number_pairs.clone() {
+                let (_, _, _, has_migrated_tokens) = entry.into_tuple();
+                require!(!has_migrated_tokens, "Migration allowances not enabled");
+            }
+        }
+    }
+
+    #[inline]
+    fn nr_guaranteed_winning_tickets(&self) -> usize {
+        self.total_guaranteed_tickets().get()
+    }
+
+    #[inline]
+    fn clear_guaranteed_tickets_after_blacklist(&self, users: &ManagedVec<ManagedAddress>) {
+        self.clear_users_with_guaranteed_ticket_after_blacklist(users);
+    }
+
+    fn add_guaranteed_tickets_user_info(
+        &self,
+        address: &ManagedAddress,
+        user_info: &mut UserInfo<Self::Api>,
+    ) {
+        user_info.guaranteed_tickets = self.get_user_guaranteed_tickets(address);
+    }
+
+    #[inline]
+    fn clear_guaranteed_tickets_user_storage(&self, address: &ManagedAddress) {
+        self.clear_user_ticket_status(address);
+    }
+}
@@ -0,0 +1,46 @@
+multiversx_sc::imports!();
+
+use launchpad_common::config::FIRST_SALE_ROUND_ID;
+
+#[multiversx_sc::module]
+pub trait LockedTokensAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_locked_tokens::events::EventsModule
+    + launchpad_locked_tokens::locked_launchpad_token_send::LockedLaunchpadTokenSend
+{
+    #[only_owner]
+    #[label("locked-tokens")]
+    #[endpoint(setLockedTokensConfig)]
+    fn set_locked_tokens_config(
+        &self,
+        simple_lock_sc_address: ManagedAddress,
+        launchpad_tokens_lock_tranches: MultiValueEncoded<MultiValue2<u32, u64>>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+
+        self.try_set_simple_lock_sc_address(simple_lock_sc_address);
+        self.try_set_launchpad_tokens_lock_tranches(launchpad_tokens_lock_tranches);
+    }
+
+    fn require_locked_tokens_configured(&self) {
+        require!(
+            !self.simple_lock_sc_address().is_empty(),
+            "Locked tokens config not set"
+        );
+    }
+
+    /// Staking the claimed tokens would skip their lock
+    fn require_liquid_launchpad_tokens(&self) {
+        sc_panic!("Locked launchpad tokens cannot be staked");
+    }
+
+    #[inline]
+    fn send_claimed_launchpad_tokens(
+        &self,
+        address: &ManagedAddress,
+        payment: &EsdtTokenPayment<Self::Api>,
+    ) {
+        self.send_locked_launchpad_tokens(address, payment);
+    }
+}
@@ -0,0 +1,53 @@
+multiversx_sc::imports!();
+
+use launchpad_common::config::FIRST_SALE_ROUND_ID;
+
+/// (staking tickets, energy tickets, confirmed tickets, staking guaranteed tickets, migration guaranteed tickets)
+pub type UserTicketsStatus = MultiValue5<usize, usize, usize, usize, usize>;
+
+/// Allows granting guaranteed tickets to the users who migrated their tokens,
+/// next to the ones granted for staking
+#[multiversx_sc::module]
+pub trait MigrationAllowancesModule:
+    launchpad_common::permissions::PermissionsModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule
+{
+    #[label("migration-allowances")]
+    #[endpoint(removeGuaranteedUsersFromBlacklist)]
+    fn remove_guaranteed_users_from_blacklist_endpoint(
+        &self,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let users_vec = users_list.to_vec();
+        self.remove_users_from_blacklist(users_list);
+        self.remove_guaranteed_tickets_from_blacklist(&users_vec);
+    }
+
+    #[label("migration-allowances")]
+    #[view(getUserTicketsStatus)]
+    fn user_tickets_status(&self, address: ManagedAddress) -> UserTicketsStatus {
+        let user_ticket_status_mapper = self.user_ticket_status(&address);
+        require!(!user_ticket_status_mapper.is_empty(), "User not found");
+        let user_ticket_status = user_ticket_status_mapper.get();
+        let user_confirmed_tickets_no = self
+            .nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &address)
+            .get();
+
+        (
+            user_ticket_status.staking_tickets_allowance,
+            user_ticket_status.energy_tickets_allowance,
+            user_confirmed_tickets_no,
+            user_ticket_status.staking_guaranteed_tickets,
+            user_ticket_status.migration_guaranteed_tickets,
+        )
+            .into()
+    }
+}
@@ -0,0 +1,77 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, user_info::UserInfo};
+use launchpad_with_nft::mystery_sft::SftSetupSteps;
+
+#[multiversx_sc::module]
+pub trait NftDrawAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + multiversx_sc_modules::pause::PauseModule
+    + launchpad_with_nft::events::EventsModule
+    + launchpad_with_nft::nft_config::NftConfigModule
+    + launchpad_with_nft::mystery_sft::MysterySftModule
+    + launchpad_with_nft::confirm_nft::ConfirmNftModule
+    + launchpad_with_nft::nft_blacklist::NftBlacklistModule
+    + launchpad_with_nft::nft_winners_selection::NftWinnersSelectionModule
+    + launchpad_with_nft::claim_nft::ClaimNftModule
+{
+    #[only_owner]
+    #[label("nft-draw")]
+    #[endpoint(setTotalAvailableNfts)]
+    fn set_total_available_nfts(&self, total_available_nfts: usize) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(total_available_nfts > 0, "Invalid total_available_nfts");
+
+        self.total_available_nfts().set(total_available_nfts);
+    }
+
+    #[inline]
+    fn init_nft_draw(&self) {
+        self.sft_setup_steps()
+            .set_if_empty(SftSetupSteps::default());
+    }
+
+    fn require_nft_draw_configured(&self) {
+        require!(!self.nft_cost().is_empty(), "NFT cost not set");
+        require!(
+            !self.total_available_nfts().is_empty(),
+            "Total available NFTs not set"
+        );
+    }
+
+    #[inline]
+    fn refund_nft_draw_after_blacklist(&self, users: &ManagedVec<ManagedAddress>) {
+        self.refund_nft_cost_after_blacklist(users);
+    }
+
+    /// The mystery SFT is sent along with the first launchpad tokens claim
+    fn claim_nft_draw_prize(&self, is_first_claim: bool) {
+        if is_first_claim {
+            self.claim_nft();
+        }
+    }
+
+    #[inline]
+    fn claim_nft_draw_payment(&self) {
+        self.claim_nft_payment();
+    }
+
+    fn add_nft_draw_user_info(
+        &self,
+        address: &ManagedAddress,
+        user_info: &mut UserInfo<Self::Api>,
+    ) {
+        user_info.nft_status = self.get_user_nft_status(address);
+    }
+}
@@ -0,0 +1,94 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, user_info::UserInfo};
+
+#[multiversx_sc::module]
+pub trait VestingAddOnModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + multiversx_sc_modules::pause::PauseModule
+    + launchpad_guaranteed_tickets_v2::events::EventsModule
+    + launchpad_guaranteed_tickets_v2::token_release::TokenReleaseModule
+{
+    /// On the first call, the user's results are computed and the won tokens are put on vesting.
+    /// Every call then sends the tokens released so far, according to the unlock schedule.
+    fn release_launchpad_tokens<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
+    >(
+        &self,
+        send_fn: SendLaunchpadTokensFn,
+    ) {
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
+        self.require_before_claim_deadline();
+
+        let caller = self.blockchain().get_caller();
+        if !self.has_user_claimed(FIRST_SALE_ROUND_ID, &caller) {
+            let nr_redeemable_tickets =
+                self.compute_user_launchpad_results(FIRST_SALE_ROUND_ID, &caller);
+            if nr_redeemable_tickets > 0 {
+                let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
+                let launchpad_tokens_amount_won =
+                    BigUint::from(nr_redeemable_tickets as u32) * tokens_per_winning_ticket;
+                self.total_unreleased_launchpad_tokens()
+                    .update(|unreleased| *unreleased += &launchpad_tokens_amount_won);
+                self.user_total_claimable_balance(&caller)
+                    .set(launchpad_tokens_amount_won);
+            }
+        }
+
+        let claimable_tokens = self.compute_claimable_tokens(&caller);
+        if claimable_tokens == 0 {
+            return;
+        }
+
+        self.total_unreleased_launchpad_tokens()
+            .update(|unreleased| *unreleased -= &claimable_tokens);
+        self.user_claimed_balance(&caller)
+            .update(|balance| *balance += &claimable_tokens);
+        self.record_launchpad_tokens_claim(&claimable_tokens);
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let payment = EsdtTokenPayment::new(launchpad_token_id, 0, claimable_tokens);
+        send_fn(self, &caller, &payment);
+
+        self.emit_claim_launchpad_tokens_event(FIRST_SALE_ROUND_ID, payment);
+    }
+
+    #[inline]
+    fn get_unreleased_launchpad_tokens(&self) -> BigUint {
+        self.total_unreleased_launchpad_tokens().get()
+    }
+
+    #[inline]
+    fn get_vesting_last_release_round(&self) -> u64 {
+        self.get_last_release_round()
+    }
+
+    #[inline]
+    fn add_vesting_release_user_info(
+        &self,
+        address: &ManagedAddress,
+        user_info: &mut UserInfo<Self::Api>,
+    ) {
+        self.add_vesting_user_info(address, user_info);
+    }
+
+    fn clear_vesting_user_storage(&self, address: &ManagedAddress) {
+        self.user_total_claimable_balance(address).clear();
+        self.user_claimed_balance(address).clear();
+    }
+
+    /// Launchpad tokens won by the users who claimed at least once, which were not yet released
+    #[view(getTotalUnreleasedLaunchpadTokens)]
+    #[label("vesting")]
+    #[storage_mapper("totalUnreleasedLaunchpadTokens")]
+    fn total_unreleased_launchpad_tokens(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,215 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID, launch_stage::Flags, operation_progress::OperationProgress,
+    user_info::UserInfo,
+};
+use secondary_selection::HAS_SECONDARY_SELECTION;
+
+pub mod add_ons;
+pub mod secondary_selection;
+
+/// A launchpad built from the add-ons enabled through cargo features.
+/// The add-on parameters are set through their setters, during the add tickets period.
+#[multiversx_sc::contract]
+pub trait LaunchpadComposable:
+    launchpad_common::LaunchpadMain
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::setup::SetupModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::operation_progress::OperationProgressModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::user_info::UserInfoModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::claim_and_stake::ClaimAndStakeModule
+    + multiversx_sc_modules::pause::PauseModule
+    + add_ons::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + add_ons::guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+    + add_ons::guaranteed_tickets::GuaranteedTicketsAddOnModule
+    + add_ons::migration_allowances::MigrationAllowancesModule
+    + add_ons::default_issue_callbacks::DefaultIssueCallbacksModule
+    + add_ons::nft_events::EventsModule
+    + add_ons::nft_config::NftConfigModule
+    + add_ons::nft_blacklist::NftBlacklistModule
+    + add_ons::mystery_sft::MysterySftModule
+    + add_ons::confirm_nft::ConfirmNftModule
+    + add_ons::nft_winners_selection::NftWinnersSelectionModule
+    + add_ons::claim_nft::ClaimNftModule
+    + add_ons::nft_draw::NftDrawAddOnModule
+    + add_ons::locked_tokens_events::EventsModule
+    + add_ons::locked_launchpad_token_send::LockedLaunchpadTokenSend
+    + add_ons::locked_tokens::LockedTokensAddOnModule
+    + add_ons::vesting_events::EventsModule
+    + add_ons::token_release::TokenReleaseModule
+    + add_ons::vesting::VestingAddOnModule
+    + launchpad_common::selection_pipeline::SelectionPipelineModule
+    + secondary_selection::SecondarySelectionModule
+{
+    #[allow(clippy::too_many_arguments)]
+    #[init]
+    fn init(
+        &self,
+        launchpad_token_id: TokenIdentifier,
+        launchpad_tokens_per_winning_ticket: BigUint,
+        ticket_payment_token: EgldOrEsdtTokenIdentifier,
+        ticket_price: BigUint,
+        nr_winning_tickets: usize,
+        confirmation_period_start_round: u64,
+        winner_selection_start_round: u64,
+        claim_start_round: u64,
+    ) {
+        let flags = Flags {
+            has_winner_selection_process_started: false,
+            were_tickets_filtered: false,
+            were_winners_selected: false,
+            was_additional_step_completed: !HAS_SECONDARY_SELECTION,
+        };
+        self.init_base(
+            launchpad_token_id,
+            launchpad_tokens_per_winning_ticket,
+            ticket_payment_token,
+            ticket_price,
+            nr_winning_tickets,
+            confirmation_period_start_round,
+            winner_selection_start_round,
+            claim_start_round,
+            flags,
+        );
+
+        self.init_nft_draw();
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {
+        self.upgrade_base();
+    }
+
+    #[only_owner]
+    #[endpoint(addTickets)]
+    fn add_tickets_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<
+            add_ons::guaranteed_tickets::AddTicketsEntry<Self::Api>,
+        >,
+    ) {
+        self.add_tickets_entries(address_number_pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<
+            add_ons::guaranteed_tickets::AddTicketsEntry<Self::Api>,
+        >,
+    ) {
+        self.set_ticket_allowances_entries(address_number_pairs);
+    }
+
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositLaunchpadTokens)]
+    fn deposit_launchpad_tokens_endpoint(&self) {
+        self.require_nft_draw_configured();
+        self.require_locked_tokens_configured();
+
+        let base_selection_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let reserved_tickets = self.nr_guaranteed_winning_tickets();
+        let total_tickets = base_selection_winning_tickets + reserved_tickets;
+
+        self.deposit_launchpad_tokens(total_tickets);
+    }
+
+    #[endpoint(addUsersToBlacklist)]
+    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+        let users_vec = users_list.to_vec();
+        self.add_users_to_blacklist(&users_vec);
+        self.clear_guaranteed_tickets_after_blacklist(&users_vec);
+        self.refund_nft_draw_after_blacklist(&users_vec);
+    }
+
+    #[endpoint(claimLaunchpadTokens)]
+    fn claim_launchpad_tokens_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let is_first_claim = !self.has_user_claimed(FIRST_SALE_ROUND_ID, &caller);
+
+        self.release_launchpad_tokens(Self::send_claimed_launchpad_tokens);
+        self.claim_nft_draw_prize(is_first_claim);
+    }
+
+    /// Sends the claimed launchpad tokens to the staking endpoint set by the owner,
+    /// and the resulting position to the caller
+    #[endpoint(claimLaunchpadTokensAndStake)]
+    fn claim_launchpad_tokens_and_stake_endpoint(&self) {
+        self.require_claim_staking_endpoint_set();
+        self.require_liquid_launchpad_tokens();
+
+        let caller = self.blockchain().get_caller();
+        let is_first_claim = !self.has_user_claimed(FIRST_SALE_ROUND_ID, &caller);
+
+        self.release_launchpad_tokens(Self::stake_launchpad_tokens_fn);
+        self.claim_nft_draw_prize(is_first_claim);
+    }
+
+    #[only_owner]
+    #[endpoint(claimTicketPayment)]
+    fn claim_ticket_payment_endpoint(&self) {
+        let unreleased_launchpad_tokens = self.get_unreleased_launchpad_tokens();
+        self.claim_ticket_payment_with_reserved_tokens(&unreleased_launchpad_tokens);
+        self.claim_nft_draw_payment();
+    }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::get_vesting_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.cleanup_storage(
+            Self::cleanup_user_storage,
+            Self::no_reserved_launchpad_tokens,
+        )
+    }
+
+    fn cleanup_user_storage(&self, address: &ManagedAddress) {
+        self.clear_guaranteed_tickets_user_storage(address);
+        self.clear_vesting_user_storage(address);
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(&self, address: ManagedAddress) -> UserInfo<Self::Api> {
+        self.get_user_info_base(FIRST_SALE_ROUND_ID, &address, Self::add_user_info)
+    }
+
+    fn add_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        self.add_guaranteed_tickets_user_info(address, user_info);
+        self.add_nft_draw_user_info(address, user_info);
+        self.add_vesting_release_user_info(address, user_info);
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(&self) -> OperationProgress {
+        self.get_operation_progress(FIRST_SALE_ROUND_ID, Self::get_secondary_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(&self, gas_limit: u64) -> usize {
+        let progress = self.get_ongoing_operation_progress();
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
+}
@@ -0,0 +1,58 @@
+multiversx_sc::imports!();
+
+#[cfg(feature = "guaranteed-tickets")]
+use crate::add_ons::guaranteed_ticket_winners::GuaranteedTicketsStep;
+#[cfg(feature = "nft-draw")]
+use crate::add_ons::nft_winners_selection::NftSelectionStep;
+use launchpad_common::{operation_progress::OperationProgress, selection_pipeline::SelectionSteps};
+
+/// If no enabled add-on has a secondary selection step, the step is marked as completed on init
+pub const HAS_SECONDARY_SELECTION: bool =
+    cfg!(any(feature = "guaranteed-tickets", feature = "nft-draw"));
+
+#[multiversx_sc::module]
+pub trait SecondarySelectionModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::selection_pipeline::SelectionPipelineModule
+    + multiversx_sc_modules::pause::PauseModule
+    + crate::add_ons::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::add_ons::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::add_ons::guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+    + crate::add_ons::nft_events::EventsModule
+    + crate::add_ons::nft_config::NftConfigModule
+    + crate::add_ons::mystery_sft::MysterySftModule
+    + crate::add_ons::confirm_nft::ConfirmNftModule
+    + crate::add_ons::nft_blacklist::NftBlacklistModule
+    + crate::add_ons::nft_winners_selection::NftWinnersSelectionModule
+    + crate::add_ons::claim_nft::ClaimNftModule
+{
+    #[endpoint(secondarySelectionStep)]
+    fn secondary_selection_step(&self) -> OperationCompletionStatus {
+        self.run_selection_pipeline(self.secondary_selection_steps())
+    }
+
+    fn get_secondary_selection_progress(&self, encoded_data: ManagedBuffer) -> OperationProgress {
+        self.get_selection_pipeline_progress(self.secondary_selection_steps(), encoded_data)
+    }
+
+    /// The steps of the enabled add-ons. Guaranteed tickets go first,
+    /// so the NFT draw is not affected by the leftover tickets distribution.
+    fn secondary_selection_steps(&self) -> SelectionSteps<Self> {
+        &[
+            #[cfg(feature = "guaranteed-tickets")]
+            &GuaranteedTicketsStep,
+            #[cfg(feature = "nft-draw")]
+            &NftSelectionStep,
+        ]
+    }
+}
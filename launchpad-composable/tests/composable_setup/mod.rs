@@ -1,13 +1,22 @@
 use launchpad_common::{
     user_interactions::UserInteractionsModule, winner_selection::WinnerSelectionModule,
 };
-use launchpad_nft_and_guaranteed_tickets::{
-    combined_selection::CombinedSelectionModule, Launchpad,
+use launchpad_composable::{
+    add_ons::{
+        guaranteed_tickets::GuaranteedTicketsAddOnModule,
+        locked_launchpad_token_send::MAX_PERCENTAGE, locked_tokens::LockedTokensAddOnModule,
+        nft_draw::NftDrawAddOnModule,
+    },
+    secondary_selection::SecondarySelectionModule,
+    LaunchpadComposable,
 };
+use launchpad_guaranteed_tickets_v2::token_release::TokenReleaseModule;
 use launchpad_with_nft::{
     confirm_nft::ConfirmNftModule,
     mystery_sft::{MysterySftModule, SftSetupSteps},
+    nft_config::NftConfigModule,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::{
     storage::mappers::StorageTokenWrapper,
     types::{
@@ -21,6 +30,10 @@ use multiversx_sc_scenario::{
     DebugApi,
 };
 
+use self::simple_lock_mock::SimpleLockMock;
+
+pub mod simple_lock_mock;
+
 pub const NR_LAUNCHPAD_PARTICIPANTS: usize = 3;
 pub const BASE_TICKET_COST: u64 = 10;
 pub const NFT_TICKET_COST: u64 = 100;
@@ -37,25 +50,31 @@ pub const CLAIM_START_ROUND: u64 = 15;
 
 pub static SFT_TOKEN_ID: &[u8] = b"MYSTERY-123456";
 
+pub static LOCK_FN_NAME: &str = "lockTokens";
+pub static LOCKED_TOKEN_ID: &[u8] = b"LKTOK-123456";
+pub const LOCK_PERCENTAGE: u32 = 5_000; // 50%
+pub const UNLOCK_EPOCH: u64 = 10;
+
+pub const INITIAL_RELEASE_PERCENTAGE: u64 = 5_000; // 50%
+pub const VESTING_RELEASE_PERCENTAGE: u64 = 5_000; // 50%
+pub const VESTING_RELEASE_PERIOD: u64 = 10;
+
 pub struct LaunchpadSetup<LaunchpadBuilder>
 where
-    LaunchpadBuilder:
-        'static + Copy + Fn() -> launchpad_nft_and_guaranteed_tickets::ContractObj<DebugApi>,
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad_composable::ContractObj<DebugApi>,
 {
     pub b_mock: BlockchainStateWrapper,
     pub owner_address: Address,
     pub participants: Vec<Address>,
-    pub lp_wrapper: ContractObjWrapper<
-        launchpad_nft_and_guaranteed_tickets::ContractObj<DebugApi>,
-        LaunchpadBuilder,
-    >,
+    pub lp_wrapper:
+        ContractObjWrapper<launchpad_composable::ContractObj<DebugApi>, LaunchpadBuilder>,
 }
 
 impl<LaunchpadBuilder> LaunchpadSetup<LaunchpadBuilder>
 where
-    LaunchpadBuilder:
-        'static + Copy + Fn() -> launchpad_nft_and_guaranteed_tickets::ContractObj<DebugApi>,
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad_composable::ContractObj<DebugApi>,
 {
+    /// Sets up a launchpad with all the add-ons, up to and including the launchpad tokens deposit
     pub fn new(lp_builder: LaunchpadBuilder) -> Self {
         let rust_zero = rust_biguint!(0u64);
         let user_balance =
@@ -74,14 +93,22 @@ where
 
         b_mock.set_esdt_balance(&owner_address, LAUNCHPAD_TOKEN_ID, &total_launchpad_tokens);
 
+        let simple_lock_sc =
+            b_mock.create_sc_account(&rust_zero, None, SimpleLockMock::new, "simple lock wasm");
+        b_mock.set_esdt_local_roles(
+            simple_lock_sc.address_ref(),
+            LOCKED_TOKEN_ID,
+            &[EsdtLocalRole::NftCreate],
+        );
+
         let lp_wrapper = b_mock.create_sc_account(
             &rust_zero,
             Some(&owner_address),
             lp_builder,
-            "launchpad_with_nft.wasm",
+            "launchpad_composable.wasm",
         );
 
-        // init launchpad
+        // init launchpad and configure the add-ons
         b_mock
             .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
                 sc.init(
@@ -93,12 +120,32 @@ where
                     CONFIRM_START_ROUND,
                     WINNER_SELECTION_START_ROUND,
                     CLAIM_START_ROUND,
+                );
+
+                sc.set_min_confirmed_for_guaranteed_ticket(MAX_TIER_TICKETS);
+                sc.set_nft_cost(
                     EgldOrEsdtTokenIdentifier::egld(),
                     0,
                     managed_biguint!(NFT_TICKET_COST),
-                    TOTAL_NFTS,
-                    MAX_TIER_TICKETS,
                 );
+                sc.set_total_available_nfts(TOTAL_NFTS);
+                let mut lock_tranches = MultiValueEncoded::new();
+                lock_tranches.push((MAX_PERCENTAGE - LOCK_PERCENTAGE, 0).into());
+                lock_tranches.push((LOCK_PERCENTAGE, UNLOCK_EPOCH).into());
+                sc.set_locked_tokens_config(
+                    managed_address!(simple_lock_sc.address_ref()),
+                    lock_tranches,
+                );
+                let mut unlock_milestones = MultiValueEncoded::new();
+                unlock_milestones.push((CLAIM_START_ROUND, INITIAL_RELEASE_PERCENTAGE).into());
+                unlock_milestones.push(
+                    (
+                        CLAIM_START_ROUND + VESTING_RELEASE_PERIOD,
+                        VESTING_RELEASE_PERCENTAGE,
+                    )
+                        .into(),
+                );
+                sc.set_unlock_schedule(unlock_milestones);
             })
             .assert_ok();
 
@@ -127,6 +174,7 @@ where
             .assert_ok();
 
         // add tickets
+        // first user - 1 ticket, second user - 2 tickets, 3rd user - 3 tickets
         b_mock
             .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
                 let mut args = MultiValueEncoded::new();
@@ -154,30 +202,6 @@ where
 
         b_mock.set_block_round(CONFIRM_START_ROUND);
 
-        // confirm base launchpad tickets
-        for (i, p) in participants.iter().enumerate() {
-            let nr_tickets = i + 1;
-            b_mock
-                .execute_tx(
-                    p,
-                    &lp_wrapper,
-                    &rust_biguint!(nr_tickets as u64 * BASE_TICKET_COST),
-                    |sc| {
-                        sc.confirm_tickets(nr_tickets);
-                    },
-                )
-                .assert_ok();
-        }
-
-        // confirm nft
-        for p in &participants[0..participants.len() - 1] {
-            b_mock
-                .execute_tx(p, &lp_wrapper, &rust_biguint!(NFT_TICKET_COST), |sc| {
-                    sc.confirm_nft();
-                })
-                .assert_ok();
-        }
-
         Self {
             b_mock,
             owner_address,
@@ -186,13 +210,29 @@ where
         }
     }
 
+    pub fn confirm(&mut self, caller: &Address, nr_tickets: usize) -> TxResult {
+        self.b_mock.execute_tx(
+            caller,
+            &self.lp_wrapper,
+            &rust_biguint!(BASE_TICKET_COST * nr_tickets as u64),
+            |sc| {
+                sc.confirm_tickets(
+                    nr_tickets,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
     pub fn confirm_nft(&mut self, caller: &Address) -> TxResult {
         self.b_mock.execute_tx(
             caller,
             &self.lp_wrapper,
             &rust_biguint!(NFT_TICKET_COST),
             |sc| {
-                sc.confirm_nft();
+                sc.confirm_nft(OptionalValue::None);
             },
         )
     }
@@ -204,7 +244,7 @@ where
                 &self.lp_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let result = sc.filter_tickets();
+                    let result = sc.filter_tickets(OptionalValue::None);
                     assert!(matches!(result, OperationCompletionStatus::Completed));
                 },
             )
@@ -215,7 +255,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let result = sc.select_winners();
+                let result = sc.select_winners(OptionalValue::None);
                 assert!(matches!(result, OperationCompletionStatus::Completed));
             },
         )
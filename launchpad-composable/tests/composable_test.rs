@@ -0,0 +1,359 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use composable_setup::{
+    simple_lock_mock::LockedTokenAttributes, LaunchpadSetup, BASE_TICKET_COST, CLAIM_START_ROUND,
+    LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID, LOCKED_TOKEN_ID, MAX_TIER_TICKETS,
+    NFT_TICKET_COST, NR_WINNING_TICKETS, SFT_TOKEN_ID, TOTAL_NFTS, UNLOCK_EPOCH,
+    VESTING_RELEASE_PERIOD, WINNER_SELECTION_START_ROUND,
+};
+use launchpad_common::{
+    claim_and_stake::ClaimAndStakeModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
+    launch_stage::LaunchStageModule,
+    tickets::{TicketsModule, WINNING_TICKET},
+};
+use launchpad_composable::{
+    add_ons::{nft_draw::NftDrawAddOnModule, vesting::VestingAddOnModule},
+    secondary_selection::SecondarySelectionModule,
+    LaunchpadComposable,
+};
+use launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule;
+use launchpad_with_nft::{
+    confirm_nft::ConfirmNftModule, mystery_sft::MysterySftTypes,
+    nft_winners_selection::NftWinnersSelectionModule,
+};
+use multiversx_sc::{codec::Empty, types::EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, managed_token_id_wrapped,
+    rust_biguint, DebugApi,
+};
+
+mod composable_setup;
+
+#[test]
+fn setup_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_composable::contract_obj);
+    let part = lp_setup.participants.clone();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.secondary_selection_steps().len(), 2);
+            assert_eq!(
+                sc.flags(FIRST_SALE_ROUND_ID)
+                    .get()
+                    .was_additional_step_completed,
+                false
+            );
+
+            // 1 ticket for the max tier gets removed
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
+            assert_eq!(
+                sc.min_confirmed_for_guaranteed_ticket().get(),
+                MAX_TIER_TICKETS
+            );
+            assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
+            assert!(sc
+                .users_with_guaranteed_ticket()
+                .contains(&managed_address!(part.last().unwrap())));
+
+            assert_eq!(sc.total_available_nfts().get(), TOTAL_NFTS);
+            assert_eq!(
+                sc.total_launchpad_tokens_deposited().get(),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn add_on_setters_only_before_confirmation_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_composable::contract_obj);
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_total_available_nfts(TOTAL_NFTS + 1);
+            },
+        )
+        .assert_user_error("Add tickets period has passed");
+}
+
+#[test]
+fn deposit_requires_add_ons_configured_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_composable::contract_obj);
+    let owner = lp_setup.owner_address.clone();
+
+    // a second launchpad, where only the base parameters are set
+    let lp_wrapper = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        launchpad_composable::contract_obj,
+        "launchpad_composable.wasm",
+    );
+    lp_setup.b_mock.set_block_round(0);
+    lp_setup
+        .b_mock
+        .execute_tx(&owner, &lp_wrapper, &rust_biguint!(0), |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(BASE_TICKET_COST),
+                NR_WINNING_TICKETS,
+                composable_setup::CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_esdt_balance(
+        &owner,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64),
+    );
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &lp_wrapper,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64),
+            |sc| {
+                sc.deposit_launchpad_tokens_endpoint();
+            },
+        )
+        .assert_user_error("NFT cost not set");
+}
+
+#[test]
+fn full_launchpad_with_all_add_ons_test() {
+    DebugApi::dummy();
+    let mut lp_setup = LaunchpadSetup::new(launchpad_composable::contract_obj);
+    let part = lp_setup.participants.clone();
+
+    // all users confirm all their tickets, the first two also confirm the NFT
+    for (i, p) in part.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+    for p in &part[0..part.len() - 1] {
+        lp_setup.confirm_nft(p).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.select_base_launchpad_winners().assert_ok();
+
+    // both steps in a single call: guaranteed tickets, then the NFT draw
+    lp_setup.secondary_selection_step_single_call().assert_ok();
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
+
+            assert_eq!(sc.nft_selection_winners().len(), 1);
+            assert!(sc
+                .nft_selection_winners()
+                .contains(&managed_address!(&part[1])));
+
+            assert_eq!(
+                sc.claimable_ticket_payment().get(),
+                managed_biguint!(BASE_TICKET_COST * NR_WINNING_TICKETS as u64)
+            );
+            assert_eq!(
+                sc.claimable_nft_payment().get(),
+                managed_biguint!(NFT_TICKET_COST * TOTAL_NFTS as u64)
+            );
+            assert!(
+                sc.flags(FIRST_SALE_ROUND_ID)
+                    .get()
+                    .was_additional_step_completed
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.secondary_selection_step();
+            },
+        )
+        .assert_user_error("Already performed this step");
+
+    // first claim - half of the won tokens are released, and half of those are locked
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in &part {
+        lp_setup.claim(p).assert_ok();
+    }
+
+    let released_per_claim = LAUNCHPAD_TOKENS_PER_TICKET / 2;
+    let locked_per_claim = released_per_claim / 2;
+    for p in [&part[0], &part[2]] {
+        lp_setup.b_mock.check_esdt_balance(
+            p,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(released_per_claim - locked_per_claim),
+        );
+    }
+    lp_setup.b_mock.check_nft_balance(
+        &part[0],
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(locked_per_claim),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LAUNCHPAD_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&part[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+
+    // the mystery SFTs are sent on the first claim only
+    lp_setup.b_mock.check_nft_balance(
+        &part[0],
+        SFT_TOKEN_ID,
+        MysterySftTypes::ConfirmedLost.as_nonce(),
+        &rust_biguint!(1),
+        Some(&Empty),
+    );
+    lp_setup.b_mock.check_nft_balance(
+        &part[1],
+        SFT_TOKEN_ID,
+        MysterySftTypes::ConfirmedWon.as_nonce(),
+        &rust_biguint!(1),
+        Some(&Empty),
+    );
+    lp_setup.b_mock.check_nft_balance(
+        &part[2],
+        SFT_TOKEN_ID,
+        MysterySftTypes::NotConfirmed.as_nonce(),
+        &rust_biguint!(1),
+        Some(&Empty),
+    );
+
+    // the owner can't take the tokens that are still vesting
+    lp_setup.owner_claim().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(120));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_unreleased_launchpad_tokens(),
+                managed_biguint!(2 * released_per_claim)
+            );
+        })
+        .assert_ok();
+
+    // second claim, after the vesting period - the rest of the tokens
+    lp_setup
+        .b_mock
+        .set_block_round(CLAIM_START_ROUND + VESTING_RELEASE_PERIOD);
+    lp_setup.claim(&part[0]).assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &part[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(2 * (released_per_claim - locked_per_claim)),
+    );
+    lp_setup.b_mock.check_nft_balance(
+        &part[1],
+        SFT_TOKEN_ID,
+        MysterySftTypes::ConfirmedWon.as_nonce(),
+        &rust_biguint!(1),
+        Some(&Empty),
+    );
+    lp_setup
+        .claim(&part[0])
+        .assert_user_error("Already claimed all tokens");
+
+    lp_setup.claim(&part[2]).assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+
+    // user[0] won 1 ticket and lost the NFT, user[1] won the NFT, user[2] won 1 ticket (guaranteed)
+    lp_setup
+        .b_mock
+        .check_egld_balance(&part[0], &rust_biguint!(120));
+    lp_setup
+        .b_mock
+        .check_egld_balance(&part[1], &rust_biguint!(30));
+    lp_setup
+        .b_mock
+        .check_egld_balance(&part[2], &rust_biguint!(120));
+}
+
+#[test]
+fn claim_and_stake_with_locked_tokens_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_composable::contract_obj);
+    let owner = lp_setup.owner_address.clone();
+    let participant = lp_setup.participants[0].clone();
+    let staking_sc = lp_setup.lp_wrapper.address_ref().clone();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participant,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_launchpad_tokens_and_stake_endpoint();
+            },
+        )
+        .assert_user_error("Staking endpoint not set");
+
+    lp_setup
+        .b_mock
+        .execute_tx(&owner, &lp_setup.lp_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_claim_staking_endpoint(managed_address!(&staking_sc), managed_buffer!(b"stake"));
+        })
+        .assert_ok();
+
+    // the locked tranches would be sent to the staking contract unlocked
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participant,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_launchpad_tokens_and_stake_endpoint();
+            },
+        )
+        .assert_user_error("Locked launchpad tokens cannot be staked");
+}
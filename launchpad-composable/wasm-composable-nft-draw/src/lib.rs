@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           42
+// Upgrade:                              1
+// Endpoints:                           70
 // Async Callback:                       1
-// Total number of exported functions:  44
+// Total number of exported functions:  73
 
 #![no_std]
 
@@ -15,42 +16,70 @@ multiversx_sc_wasm_adapter::allocator!();
 multiversx_sc_wasm_adapter::panic_handler!();
 
 multiversx_sc_wasm_adapter::endpoints! {
-    launchpad_nft_and_guaranteed_tickets
+    launchpad_composable
     (
         init => init
+        upgrade => upgrade
         addTickets => add_tickets_endpoint
+        setTicketAllowances => set_ticket_allowances_endpoint
         depositLaunchpadTokens => deposit_launchpad_tokens_endpoint
         addUsersToBlacklist => add_users_to_blacklist_endpoint
         claimLaunchpadTokens => claim_launchpad_tokens_endpoint
+        claimLaunchpadTokensAndStake => claim_launchpad_tokens_and_stake_endpoint
         claimTicketPayment => claim_ticket_payment_endpoint
-        getLaunchStageFlags => flags
-        getConfiguration => configuration
+        setClaimDeadlineRound => set_claim_deadline_round_endpoint
+        cleanupStorage => cleanup_storage_endpoint
+        getUserInfo => get_user_info
+        getOngoingOperationProgress => get_ongoing_operation_progress
+        getEstimatedOperationCalls => get_estimated_operation_calls
+        getLaunchStageFlags => get_launch_stage_flags
+        getNumberOfSaleRounds => nr_sale_rounds
+        getConfiguration => get_configuration
+        getTicketPrice => get_ticket_price
+        getNumberOfWinningTickets => get_number_of_winning_tickets
         getLaunchpadTokenId => launchpad_token_id
         getLaunchpadTokensPerWinningTicket => launchpad_tokens_per_winning_ticket
-        getTicketPrice => ticket_price
-        getNumberOfWinningTickets => nr_winning_tickets
         getTotalLaunchpadTokensDeposited => total_launchpad_tokens_deposited
+        getClaimDeadlineRound => claim_deadline_round
+        getTermsOfSale => terms_of_sale
+        getAcceptedTermsOfSale => accepted_terms_of_sale
         setTicketPrice => set_ticket_price
         setLaunchpadTokensPerWinningTicket => set_launchpad_tokens_per_winning_ticket
         setConfirmationPeriodStartRound => set_confirmation_period_start_round
         setWinnerSelectionStartRound => set_winner_selection_start_round
         setClaimStartRound => set_claim_start_round
+        setTermsOfSale => set_terms_of_sale
+        isStorageCleanupCompleted => storage_cleanup_completed
         getTicketRangeForAddress => get_ticket_range_for_address
-        getTotalNumberOfTicketsForAddress => get_total_number_of_tickets_for_address
-        getTotalNumberOfTickets => last_ticket_id
-        getNumberOfConfirmedTicketsForAddress => nr_confirmed_tickets
+        getTotalNumberOfTicketsForAddress => get_total_number_of_tickets_for_address_view
+        getTicketBatches => get_ticket_batches
+        getTotalNumberOfTickets => get_total_number_of_tickets
+        getNumberOfConfirmedTicketsForAddress => get_number_of_confirmed_tickets_for_address
         filterTickets => filter_tickets
         selectWinners => select_winners
-        getNumberOfWinningTicketsForAddress => get_number_of_winning_tickets_for_address
-        getWinningTicketIdsForAddress => get_winning_ticket_ids_for_address
+        abortWinnerSelection => abort_winner_selection
+        getNumberOfWinningTicketsForAddress => get_number_of_winning_tickets_for_address_view
+        getWinningTicketIdsForAddress => get_winning_ticket_ids_for_address_view
         setSupportAddress => add_support_address
         getSupportAddress => support_address
+        backfillBlacklistedUsers => backfill_blacklisted_users
         isUserBlacklisted => is_user_blacklisted
+        getBlacklistedUsers => get_blacklisted_users
+        getLaunchpadStats => get_launchpad_stats
         confirmTickets => confirm_tickets
-        hasUserClaimedTokens => has_user_claimed
+        hasUserClaimedTokens => has_user_claimed_view
+        backfillClaimedUsers => backfill_claimed_users
+        getClaimedUsers => get_claimed_users
+        setKycSigner => set_kyc_signer
+        removeKycSigner => remove_kyc_signer
+        getKycSigner => get_kyc_signer
+        setClaimStakingEndpoint => set_claim_staking_endpoint
+        removeClaimStakingEndpoint => remove_claim_staking_endpoint
+        getClaimStakingEndpoint => claim_staking_endpoint
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        secondarySelectionStep => secondary_selection_step
         setNftCost => set_nft_cost
         getNftCost => nft_cost
         issueMysterySft => issue_mystery_sft
@@ -59,8 +88,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         confirmNft => confirm_nft
         hasUserConfirmedNft => has_user_confirmed_nft
         hasUserWonNft => has_user_won_nft
-        secondarySelectionStep => secondary_selection_step
+        getNftWinners => get_nft_winners
+        setTotalAvailableNfts => set_total_available_nfts
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback! { launchpad_nft_and_guaranteed_tickets }
+multiversx_sc_wasm_adapter::async_callback! { launchpad_composable }
@@ -0,0 +1,129 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+use crate::launchpad_config::{LaunchpadInfo, LaunchpadType};
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetTemplateAddressEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    launchpad_type: LaunchpadType,
+    template_address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetSupportAddressEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    support_address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct DeployLaunchpadEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    launchpad_address: ManagedAddress<M>,
+    launchpad_info: LaunchpadInfo<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_set_template_address_event(
+        &self,
+        launchpad_type: LaunchpadType,
+        template_address: ManagedAddress,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_template_address_event(
+            user.clone(),
+            round,
+            epoch,
+            SetTemplateAddressEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                launchpad_type,
+                template_address,
+            },
+        )
+    }
+
+    fn emit_set_support_address_event(&self, support_address: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_support_address_event(
+            user.clone(),
+            round,
+            epoch,
+            SetSupportAddressEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                support_address,
+            },
+        )
+    }
+
+    fn emit_deploy_launchpad_event(
+        &self,
+        launchpad_address: ManagedAddress,
+        launchpad_info: LaunchpadInfo<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.deploy_launchpad_event(
+            user.clone(),
+            round,
+            epoch,
+            DeployLaunchpadEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                launchpad_address,
+                launchpad_info,
+            },
+        )
+    }
+
+    #[event("setTemplateAddress")]
+    fn set_template_address_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_template_address_event: SetTemplateAddressEvent<Self::Api>,
+    );
+
+    #[event("setSupportAddress")]
+    fn set_support_address_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_support_address_event: SetSupportAddressEvent<Self::Api>,
+    );
+
+    #[event("deployLaunchpad")]
+    fn deploy_launchpad_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        deploy_launchpad_event: DeployLaunchpadEvent<Self::Api>,
+    );
+}
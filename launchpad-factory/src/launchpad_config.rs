@@ -0,0 +1,166 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::config::TimelineConfig;
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum LaunchpadType {
+    Basic,
+    LockedTokens,
+    WithNft,
+    GuaranteedTickets,
+    GuaranteedTicketsV2,
+    MigrationGuaranteedTickets,
+    LockedTokensAndGuaranteedTickets,
+    NftAndGuaranteedTickets,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct LockTrancheConfig {
+    pub percentage: u32,
+    pub unlock_epoch: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LockedTokensConfig<M: ManagedTypeApi> {
+    pub simple_lock_sc_address: ManagedAddress<M>,
+    pub lock_tranches: ManagedVec<M, LockTrancheConfig>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct NftConfig<M: ManagedTypeApi> {
+    pub nft_cost: EgldOrEsdtTokenPayment<M>,
+    pub total_available_nfts: usize,
+}
+
+/// The settings specific to each launchpad type, i.e. the `init` arguments
+/// that follow the common ones.
+/// The guaranteed tickets with locked tokens or NFT types are composable launchpads,
+/// which only take the common `init` arguments and are configured through their setters.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum LaunchpadKind<M: ManagedTypeApi> {
+    Basic,
+    LockedTokens {
+        locked_tokens: LockedTokensConfig<M>,
+    },
+    WithNft {
+        nft: NftConfig<M>,
+    },
+    GuaranteedTickets {
+        min_confirmed_for_guaranteed_ticket: usize,
+    },
+    GuaranteedTicketsV2,
+    MigrationGuaranteedTickets {
+        min_confirmed_for_guaranteed_ticket: usize,
+    },
+    LockedTokensAndGuaranteedTickets {
+        min_confirmed_for_guaranteed_ticket: usize,
+        locked_tokens: LockedTokensConfig<M>,
+    },
+    NftAndGuaranteedTickets {
+        nft: NftConfig<M>,
+        min_confirmed_for_guaranteed_ticket: usize,
+    },
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LaunchpadConfig<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub launchpad_token_id: TokenIdentifier<M>,
+    pub launchpad_tokens_per_winning_ticket: BigUint<M>,
+    pub ticket_payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub ticket_price: BigUint<M>,
+    pub nr_winning_tickets: usize,
+    pub timeline: TimelineConfig,
+    pub kind: LaunchpadKind<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LaunchpadInfo<M: ManagedTypeApi> {
+    pub launchpad_type: LaunchpadType,
+    pub owner: ManagedAddress<M>,
+    pub launchpad_token_id: TokenIdentifier<M>,
+    pub timeline: TimelineConfig,
+}
+
+impl<M: ManagedTypeApi> LaunchpadKind<M> {
+    pub fn launchpad_type(&self) -> LaunchpadType {
+        match self {
+            LaunchpadKind::Basic => LaunchpadType::Basic,
+            LaunchpadKind::LockedTokens { .. } => LaunchpadType::LockedTokens,
+            LaunchpadKind::WithNft { .. } => LaunchpadType::WithNft,
+            LaunchpadKind::GuaranteedTickets { .. } => LaunchpadType::GuaranteedTickets,
+            LaunchpadKind::GuaranteedTicketsV2 => LaunchpadType::GuaranteedTicketsV2,
+            LaunchpadKind::MigrationGuaranteedTickets { .. } => {
+                LaunchpadType::MigrationGuaranteedTickets
+            }
+            LaunchpadKind::LockedTokensAndGuaranteedTickets { .. } => {
+                LaunchpadType::LockedTokensAndGuaranteedTickets
+            }
+            LaunchpadKind::NftAndGuaranteedTickets { .. } => LaunchpadType::NftAndGuaranteedTickets,
+        }
+    }
+
+    /// Appends the arguments in the order expected by the `init` function of the launchpad type
+    fn push_init_args(&self, args: &mut ManagedArgBuffer<M>) {
+        match self {
+            LaunchpadKind::Basic
+            | LaunchpadKind::GuaranteedTicketsV2
+            | LaunchpadKind::LockedTokensAndGuaranteedTickets { .. }
+            | LaunchpadKind::NftAndGuaranteedTickets { .. } => {}
+            LaunchpadKind::LockedTokens { locked_tokens } => {
+                locked_tokens.push_init_args(args);
+            }
+            LaunchpadKind::WithNft { nft } => {
+                nft.push_init_args(args);
+            }
+            LaunchpadKind::GuaranteedTickets {
+                min_confirmed_for_guaranteed_ticket,
+            }
+            | LaunchpadKind::MigrationGuaranteedTickets {
+                min_confirmed_for_guaranteed_ticket,
+            } => {
+                args.push_arg(min_confirmed_for_guaranteed_ticket);
+            }
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> LockedTokensConfig<M> {
+    /// Also the arguments of the composable `setLockedTokensConfig`
+    pub fn push_init_args(&self, args: &mut ManagedArgBuffer<M>) {
+        args.push_arg(&self.simple_lock_sc_address);
+        for lock_tranche in self.lock_tranches.iter() {
+            args.push_arg(lock_tranche.percentage);
+            args.push_arg(lock_tranche.unlock_epoch);
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> NftConfig<M> {
+    fn push_init_args(&self, args: &mut ManagedArgBuffer<M>) {
+        args.push_arg(&self.nft_cost.token_identifier);
+        args.push_arg(self.nft_cost.token_nonce);
+        args.push_arg(&self.nft_cost.amount);
+        args.push_arg(self.total_available_nfts);
+    }
+}
+
+impl<M: ManagedTypeApi> LaunchpadConfig<M> {
+    pub fn to_init_args(&self) -> ManagedArgBuffer<M> {
+        let mut args = ManagedArgBuffer::new();
+        args.push_arg(&self.launchpad_token_id);
+        args.push_arg(&self.launchpad_tokens_per_winning_ticket);
+        args.push_arg(&self.ticket_payment_token);
+        args.push_arg(&self.ticket_price);
+        args.push_arg(self.nr_winning_tickets);
+        args.push_arg(self.timeline.confirmation_period_start_round);
+        args.push_arg(self.timeline.winner_selection_start_round);
+        args.push_arg(self.timeline.claim_start_round);
+        self.kind.push_init_args(&mut args);
+
+        args
+    }
+}
@@ -0,0 +1,250 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod launchpad_config;
+
+use launchpad_common::{config::TimelineConfig, pagination::get_page};
+use launchpad_config::{LaunchpadConfig, LaunchpadInfo, LaunchpadKind, LaunchpadType};
+
+#[multiversx_sc::contract]
+pub trait LaunchpadFactory: events::EventsModule {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[only_owner]
+    #[endpoint(setTemplateAddress)]
+    fn set_template_address(
+        &self,
+        launchpad_type: LaunchpadType,
+        template_address: ManagedAddress,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&template_address),
+            "Invalid template address"
+        );
+
+        self.template_address(launchpad_type).set(&template_address);
+
+        self.emit_set_template_address_event(launchpad_type, template_address);
+    }
+
+    /// The support address set on the deployed launchpads, the project team if not set
+    #[only_owner]
+    #[endpoint(setSupportAddress)]
+    fn set_support_address(&self, support_address: ManagedAddress) {
+        self.support_address().set(&support_address);
+
+        self.emit_set_support_address_event(support_address);
+    }
+
+    /// Deploys a copy of the template contract for the given launchpad type
+    /// and transfers its ownership to the project team, i.e. `config.owner`.
+    /// The launchpad `init` sets the factory as support address, so it is replaced before the transfer.
+    #[only_owner]
+    #[endpoint(deployLaunchpad)]
+    fn deploy_launchpad(&self, config: LaunchpadConfig<Self::Api>) -> ManagedAddress {
+        let launchpad_type = config.kind.launchpad_type();
+        let template_mapper = self.template_address(launchpad_type);
+        require!(
+            !template_mapper.is_empty(),
+            "No template set for this launchpad type"
+        );
+
+        let launchpad_address = self
+            .tx()
+            .raw_deploy()
+            .from_source(template_mapper.get())
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .arguments_raw(config.to_init_args())
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
+        self.configure_add_ons(&launchpad_address, &config.kind);
+
+        let support_address = if self.support_address().is_empty() {
+            config.owner.clone()
+        } else {
+            self.support_address().get()
+        };
+        self.tx()
+            .to(&launchpad_address)
+            .raw_call("setSupportAddress")
+            .argument(&support_address)
+            .sync_call();
+
+        self.send()
+            .change_owner_address(launchpad_address.clone(), &config.owner)
+            .sync_call();
+
+        let launchpad_info = LaunchpadInfo {
+            launchpad_type,
+            owner: config.owner,
+            launchpad_token_id: config.launchpad_token_id,
+            timeline: config.timeline,
+        };
+        let _ = self.launchpads().insert(launchpad_address.clone());
+        self.launchpad_info(&launchpad_address).set(&launchpad_info);
+
+        self.emit_deploy_launchpad_event(launchpad_address.clone(), launchpad_info);
+
+        launchpad_address
+    }
+
+    /// The composable launchpads are configured while the factory is still their owner
+    fn configure_add_ons(
+        &self,
+        launchpad_address: &ManagedAddress,
+        kind: &LaunchpadKind<Self::Api>,
+    ) {
+        match kind {
+            LaunchpadKind::LockedTokensAndGuaranteedTickets {
+                min_confirmed_for_guaranteed_ticket,
+                locked_tokens,
+            } => {
+                self.set_min_confirmed_for_guaranteed_ticket(
+                    launchpad_address,
+                    *min_confirmed_for_guaranteed_ticket,
+                );
+
+                let mut locked_tokens_args = ManagedArgBuffer::new();
+                locked_tokens.push_init_args(&mut locked_tokens_args);
+                self.tx()
+                    .to(launchpad_address)
+                    .raw_call("setLockedTokensConfig")
+                    .arguments_raw(locked_tokens_args)
+                    .sync_call();
+            }
+            LaunchpadKind::NftAndGuaranteedTickets {
+                nft,
+                min_confirmed_for_guaranteed_ticket,
+            } => {
+                self.set_min_confirmed_for_guaranteed_ticket(
+                    launchpad_address,
+                    *min_confirmed_for_guaranteed_ticket,
+                );
+
+                self.tx()
+                    .to(launchpad_address)
+                    .raw_call("setNftCost")
+                    .argument(&nft.nft_cost.token_identifier)
+                    .argument(&nft.nft_cost.token_nonce)
+                    .argument(&nft.nft_cost.amount)
+                    .sync_call();
+                self.tx()
+                    .to(launchpad_address)
+                    .raw_call("setTotalAvailableNfts")
+                    .argument(&nft.total_available_nfts)
+                    .sync_call();
+            }
+            _ => {}
+        }
+    }
+
+    fn set_min_confirmed_for_guaranteed_ticket(
+        &self,
+        launchpad_address: &ManagedAddress,
+        min_confirmed_for_guaranteed_ticket: usize,
+    ) {
+        self.tx()
+            .to(launchpad_address)
+            .raw_call("setMinConfirmedForGuaranteedTicket")
+            .argument(&min_confirmed_for_guaranteed_ticket)
+            .sync_call();
+    }
+
+    #[view(getLaunchpads)]
+    fn get_launchpads(&self, offset: usize, limit: usize) -> MultiValueEncoded<ManagedAddress> {
+        get_page(&self.launchpads(), offset, limit)
+    }
+
+    /// The timeline is read from the launchpad itself, as it may have changed since deploy.
+    #[view(getLaunchpadInfo)]
+    fn get_launchpad_info(&self, launchpad_address: ManagedAddress) -> LaunchpadInfo<Self::Api> {
+        require!(
+            self.launchpads().contains(&launchpad_address),
+            "Unknown launchpad"
+        );
+
+        let mut launchpad_info = self.launchpad_info(&launchpad_address).get();
+        launchpad_info.timeline = self.current_timeline(launchpad_address);
+
+        launchpad_info
+    }
+
+    /// Launchpads whose claim period has not started yet, among the page of `getLaunchpads`
+    #[view(getActiveLaunchpads)]
+    fn get_active_launchpads(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.filter_launchpads(offset, limit, |timeline, current_round| {
+            current_round < timeline.claim_start_round
+        })
+    }
+
+    /// Launchpads that have reached their claim period, among the page of `getLaunchpads`
+    #[view(getPastLaunchpads)]
+    fn get_past_launchpads(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.filter_launchpads(offset, limit, |timeline, current_round| {
+            current_round >= timeline.claim_start_round
+        })
+    }
+
+    /// The page is filtered after being read, so the same `offset` and `limit`
+    /// walk all the launchpads, even though fewer of them may be returned.
+    fn filter_launchpads<FilterFn: Fn(&TimelineConfig, u64) -> bool>(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter_fn: FilterFn,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let current_round = self.blockchain().get_block_round();
+        let mut result = MultiValueEncoded::new();
+        for launchpad_address in get_page(&self.launchpads(), offset, limit) {
+            let timeline = self.current_timeline(launchpad_address.clone());
+            if filter_fn(&timeline, current_round) {
+                result.push(launchpad_address);
+            }
+        }
+
+        result
+    }
+
+    /// Deployed launchpads always live in the factory's shard, so their storage can be read directly.
+    /// The timeline is the one passed at deploy, i.e. of the first sale round, kept under the legacy key.
+    fn current_timeline(&self, launchpad_address: ManagedAddress) -> TimelineConfig {
+        self.launchpad_configuration(launchpad_address).get()
+    }
+
+    #[view(getTemplateAddress)]
+    #[storage_mapper("templateAddress")]
+    fn template_address(&self, launchpad_type: LaunchpadType) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getSupportAddress)]
+    #[storage_mapper("supportAddress")]
+    fn support_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("launchpads")]
+    fn launchpads(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("launchpadInfo")]
+    fn launchpad_info(
+        &self,
+        launchpad_address: &ManagedAddress,
+    ) -> SingleValueMapper<LaunchpadInfo<Self::Api>>;
+
+    #[storage_mapper_from_address("configuration")]
+    fn launchpad_configuration(
+        &self,
+        launchpad_address: ManagedAddress,
+    ) -> SingleValueMapper<TimelineConfig, ManagedAddress>;
+}
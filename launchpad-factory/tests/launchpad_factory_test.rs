@@ -0,0 +1,318 @@
+use launchpad_common::{
+    config::{ConfigModule, TimelineConfig, FIRST_SALE_ROUND_ID},
+    permissions::PermissionsModule,
+    setup::SetupModule,
+};
+use launchpad_factory::{
+    launchpad_config::{LaunchpadConfig, LaunchpadKind, LaunchpadType, NftConfig},
+    LaunchpadFactory,
+};
+use launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule;
+use launchpad_with_nft::{confirm_nft::ConfirmNftModule, nft_config::NftConfigModule};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    contract_base::ContractBase,
+    types::{Address, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::BlockchainStateWrapper, DebugApi,
+};
+
+static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100;
+const TICKET_PRICE: u64 = 10;
+const NR_WINNING_TICKETS: usize = 2;
+const CONFIRM_START_ROUND: u64 = 5;
+const WINNER_SELECTION_START_ROUND: u64 = 10;
+const CLAIM_START_ROUND: u64 = 15;
+
+fn launchpad_config(owner: &Address, kind: LaunchpadKind<DebugApi>) -> LaunchpadConfig<DebugApi> {
+    LaunchpadConfig {
+        owner: managed_address!(owner),
+        launchpad_token_id: managed_token_id!(LAUNCHPAD_TOKEN_ID),
+        launchpad_tokens_per_winning_ticket: managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        ticket_payment_token: EgldOrEsdtTokenIdentifier::egld(),
+        ticket_price: managed_biguint!(TICKET_PRICE),
+        nr_winning_tickets: NR_WINNING_TICKETS,
+        timeline: TimelineConfig {
+            confirmation_period_start_round: CONFIRM_START_ROUND,
+            winner_selection_start_round: WINNER_SELECTION_START_ROUND,
+            claim_start_round: CLAIM_START_ROUND,
+        },
+        kind,
+    }
+}
+
+#[test]
+fn deploy_launchpad_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let factory_owner = b_mock.create_user_account(&rust_zero);
+    let project_owner = b_mock.create_user_account(&rust_zero);
+
+    let template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad::contract_obj,
+        "launchpad.wasm",
+    );
+    let factory_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad_factory::contract_obj,
+        "launchpad_factory.wasm",
+    );
+
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            sc.init();
+            sc.set_template_address(
+                LaunchpadType::Basic,
+                managed_address!(template_wrapper.address_ref()),
+            );
+        })
+        .assert_ok();
+
+    // no template for this type
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_launchpad(launchpad_config(
+                &project_owner,
+                LaunchpadKind::GuaranteedTicketsV2,
+            ));
+        })
+        .assert_user_error("No template set for this launchpad type");
+
+    let lp_wrapper =
+        b_mock.prepare_deploy_from_sc(factory_wrapper.address_ref(), launchpad::contract_obj);
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            let launchpad_address =
+                sc.deploy_launchpad(launchpad_config(&project_owner, LaunchpadKind::Basic));
+            assert_eq!(
+                launchpad_address,
+                managed_address!(lp_wrapper.address_ref())
+            );
+        })
+        .assert_ok();
+
+    // deployed with the given config and owned by the project team,
+    // which is also the support address when the factory has none
+    b_mock
+        .execute_query(&lp_wrapper, |sc| {
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(&project_owner)
+            );
+            assert_eq!(sc.support_address().get(), managed_address!(&project_owner));
+            assert_eq!(
+                sc.launchpad_token_id().get(),
+                managed_token_id!(LAUNCHPAD_TOKEN_ID)
+            );
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
+            assert_eq!(
+                sc.configuration(FIRST_SALE_ROUND_ID)
+                    .get()
+                    .claim_start_round,
+                CLAIM_START_ROUND
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&factory_wrapper, |sc| {
+            let launchpads = sc.get_launchpads(0, 10).to_vec();
+            assert_eq!(launchpads.len(), 1);
+            assert_eq!(
+                launchpads.get(0).clone_value(),
+                managed_address!(lp_wrapper.address_ref())
+            );
+
+            let launchpad_info = sc.get_launchpad_info(managed_address!(lp_wrapper.address_ref()));
+            assert_eq!(launchpad_info.launchpad_type, LaunchpadType::Basic);
+            assert_eq!(launchpad_info.owner, managed_address!(&project_owner));
+            assert_eq!(
+                launchpad_info.launchpad_token_id,
+                managed_token_id!(LAUNCHPAD_TOKEN_ID)
+            );
+
+            assert_eq!(sc.get_active_launchpads(0, 10).len(), 1);
+            assert_eq!(sc.get_past_launchpads(0, 10).len(), 0);
+        })
+        .assert_ok();
+
+    // the project team moves the claim period, which is picked up by the factory
+    b_mock
+        .execute_tx(&project_owner, &lp_wrapper, &rust_zero, |sc| {
+            sc.set_claim_start_round(CLAIM_START_ROUND + 10, OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CLAIM_START_ROUND);
+    b_mock
+        .execute_query(&factory_wrapper, |sc| {
+            let launchpad_info = sc.get_launchpad_info(managed_address!(lp_wrapper.address_ref()));
+            assert_eq!(
+                launchpad_info.timeline.claim_start_round,
+                CLAIM_START_ROUND + 10
+            );
+
+            assert_eq!(sc.get_active_launchpads(0, 10).len(), 1);
+            assert_eq!(sc.get_past_launchpads(0, 10).len(), 0);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CLAIM_START_ROUND + 10);
+    b_mock
+        .execute_query(&factory_wrapper, |sc| {
+            assert_eq!(sc.get_active_launchpads(0, 10).len(), 0);
+            assert_eq!(sc.get_past_launchpads(0, 10).len(), 1);
+            assert_eq!(sc.get_past_launchpads(1, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn deploy_launchpad_with_support_address_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let factory_owner = b_mock.create_user_account(&rust_zero);
+    let project_owner = b_mock.create_user_account(&rust_zero);
+    let support = b_mock.create_user_account(&rust_zero);
+
+    let template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad::contract_obj,
+        "launchpad.wasm",
+    );
+    let factory_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad_factory::contract_obj,
+        "launchpad_factory.wasm",
+    );
+
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            sc.init();
+            sc.set_template_address(
+                LaunchpadType::Basic,
+                managed_address!(template_wrapper.address_ref()),
+            );
+            sc.set_support_address(managed_address!(&support));
+        })
+        .assert_ok();
+
+    let mut lp_wrappers = Vec::new();
+    for _ in 0..3 {
+        let lp_wrapper =
+            b_mock.prepare_deploy_from_sc(factory_wrapper.address_ref(), launchpad::contract_obj);
+        b_mock
+            .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+                let _ = sc.deploy_launchpad(launchpad_config(&project_owner, LaunchpadKind::Basic));
+            })
+            .assert_ok();
+        lp_wrappers.push(lp_wrapper);
+    }
+
+    for lp_wrapper in &lp_wrappers {
+        b_mock
+            .execute_query(lp_wrapper, |sc| {
+                assert_eq!(sc.support_address().get(), managed_address!(&support));
+            })
+            .assert_ok();
+    }
+
+    // the project team moves the claim period of the second launchpad
+    b_mock
+        .execute_tx(&project_owner, &lp_wrappers[1], &rust_zero, |sc| {
+            sc.set_claim_start_round(CLAIM_START_ROUND + 10, OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CLAIM_START_ROUND);
+    b_mock
+        .execute_query(&factory_wrapper, |sc| {
+            assert_eq!(sc.get_active_launchpads(0, 2).len(), 1);
+            assert_eq!(sc.get_active_launchpads(2, 2).len(), 0);
+            assert_eq!(sc.get_past_launchpads(0, 2).len(), 1);
+            assert_eq!(sc.get_past_launchpads(2, 2).len(), 1);
+            assert_eq!(sc.get_past_launchpads(3, 2).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn deploy_composable_launchpad_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let factory_owner = b_mock.create_user_account(&rust_zero);
+    let project_owner = b_mock.create_user_account(&rust_zero);
+
+    let template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad_composable::contract_obj,
+        "launchpad_composable.wasm",
+    );
+    let factory_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&factory_owner),
+        launchpad_factory::contract_obj,
+        "launchpad_factory.wasm",
+    );
+
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            sc.init();
+            sc.set_template_address(
+                LaunchpadType::NftAndGuaranteedTickets,
+                managed_address!(template_wrapper.address_ref()),
+            );
+        })
+        .assert_ok();
+
+    let lp_wrapper = b_mock.prepare_deploy_from_sc(
+        factory_wrapper.address_ref(),
+        launchpad_composable::contract_obj,
+    );
+    b_mock
+        .execute_tx(&factory_owner, &factory_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_launchpad(launchpad_config(
+                &project_owner,
+                LaunchpadKind::NftAndGuaranteedTickets {
+                    nft: NftConfig {
+                        nft_cost: EgldOrEsdtTokenPayment::new(
+                            EgldOrEsdtTokenIdentifier::egld(),
+                            0,
+                            managed_biguint!(TICKET_PRICE * 10),
+                        ),
+                        total_available_nfts: 5,
+                    },
+                    min_confirmed_for_guaranteed_ticket: 3,
+                },
+            ));
+        })
+        .assert_ok();
+
+    // the add-ons are configured through the setters before the ownership transfer
+    b_mock
+        .execute_query(&lp_wrapper, |sc| {
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(&project_owner)
+            );
+            assert_eq!(sc.min_confirmed_for_guaranteed_ticket().get(), 3);
+            assert_eq!(
+                sc.nft_cost().get().amount,
+                managed_biguint!(TICKET_PRICE * 10)
+            );
+            assert_eq!(sc.total_available_nfts().get(), 5);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    launchpad_factory
+    (
+        init => init
+        upgrade => upgrade
+        setTemplateAddress => set_template_address
+        setSupportAddress => set_support_address
+        deployLaunchpad => deploy_launchpad
+        getLaunchpads => get_launchpads
+        getLaunchpadInfo => get_launchpad_info
+        getActiveLaunchpads => get_active_launchpads
+        getPastLaunchpads => get_past_launchpads
+        getTemplateAddress => template_address
+        getSupportAddress => support_address
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
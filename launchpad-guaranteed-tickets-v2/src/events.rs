@@ -1,227 +1,517 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::token_release::UnlockMilestone;
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+use crate::{
+    token_release::{UnlockMilestone, UnlockScheduleId, UnlockSegment},
+    vesting_position::VestingPositionAttributes,
+};
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetUnlockScheduleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    milestones: ManagedVec<M, UnlockMilestone>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetUnlockScheduleSegmentsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    segments: ManagedVec<M, UnlockSegment>,
+}
 
 #[derive(TypeAbi, TopEncode)]
-pub struct ClaimLaunchpadTokensEvent<M: ManagedTypeApi> {
+pub struct RegisterUnlockScheduleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
+    schedule_id: UnlockScheduleId,
+    segments: ManagedVec<M, UnlockSegment>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetGuaranteedTicketsUnlockScheduleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    schedule_id: UnlockScheduleId,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct IssueVestingPositionTokenEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    token_display_name: ManagedBuffer<M>,
+    token_ticker: ManagedBuffer<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct MintVestingPositionEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    position: EsdtTokenPayment<M>,
+    attributes: VestingPositionAttributes<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct StorageMigrationCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    nr_users_migrated: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct PushReleaseEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    address: ManagedAddress<M>,
     token_payment: EsdtTokenPayment<M>,
 }
 
 #[derive(TypeAbi, TopEncode)]
-pub struct AddUsersToBlacklistEvent<M: ManagedTypeApi> {
-    admin: ManagedAddress<M>,
+pub struct SetProceedsEscrowEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    users: ManagedVec<M, ManagedAddress<M>>,
+    approver: ManagedAddress<M>,
+    approval_quorum: u64,
+    milestones: ManagedVec<M, u64>,
 }
 
 #[derive(TypeAbi, TopEncode)]
-pub struct RemoveGuaranteedUsersFromBlacklistEvent<M: ManagedTypeApi> {
-    admin: ManagedAddress<M>,
+pub struct VoteEscrowMilestoneEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    users: ManagedVec<M, ManagedAddress<M>>,
+    milestone_index: usize,
+    nr_votes: usize,
 }
 
 #[derive(TypeAbi, TopEncode)]
-pub struct SetUnlockScheduleEvent<M: ManagedTypeApi> {
-    admin: ManagedAddress<M>,
+pub struct ApproveEscrowMilestoneEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    milestones: ManagedVec<M, UnlockMilestone>,
+    milestone_index: usize,
 }
 
 #[derive(TypeAbi, TopEncode)]
-pub struct AddTicketsEvent<M: ManagedTypeApi> {
-    admin: ManagedAddress<M>,
+pub struct DeclareProjectFailedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    users_count: usize,
-    total_tickets_added: usize,
-    total_guaranteed_tickets_added: usize,
+    refundable_amount: BigUint<M>,
 }
 
 #[derive(TypeAbi, TopEncode)]
-pub struct DistributeGuaranteedTicketsCompletedEvent<M: ManagedTypeApi> {
-    admin: ManagedAddress<M>,
+pub struct ClaimProceedsRefundEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    total_additional_winning_tickets: usize,
+    token_payment: EgldOrEsdtTokenPayment<M>,
 }
 
 #[multiversx_sc::module]
 pub trait EventsModule {
-    fn emit_claim_launchpad_tokens_event(&self, token_payment: EsdtTokenPayment) {
+    fn emit_set_unlock_schedule_event(&self, milestones: ManagedVec<UnlockMilestone>) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.claim_launchpad_tokens_event(
+        self.set_unlock_schedule_event(
             user.clone(),
             round,
             epoch,
-            ClaimLaunchpadTokensEvent {
+            SetUnlockScheduleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
                 user,
                 round,
                 epoch,
-                token_payment,
+                milestones,
             },
         )
     }
 
-    fn emit_add_users_to_blacklist_event(&self, users: ManagedVec<ManagedAddress>) {
-        let admin = self.blockchain().get_caller();
+    #[event("setUnlockSchedule")]
+    fn set_unlock_schedule_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_unlock_schedule_event: SetUnlockScheduleEvent<Self::Api>,
+    );
+
+    fn emit_set_unlock_schedule_segments_event(&self, segments: ManagedVec<UnlockSegment>) {
+        let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.add_users_to_blacklist_event(
-            admin.clone(),
+        self.set_unlock_schedule_segments_event(
+            user.clone(),
             round,
             epoch,
-            AddUsersToBlacklistEvent {
-                admin,
+            SetUnlockScheduleSegmentsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
                 round,
                 epoch,
-                users,
+                segments,
             },
         )
     }
 
-    fn emit_remove_guaranteed_users_from_blacklist_event(&self, users: ManagedVec<ManagedAddress>) {
-        let admin = self.blockchain().get_caller();
+    #[event("setUnlockScheduleSegments")]
+    fn set_unlock_schedule_segments_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_unlock_schedule_segments_event: SetUnlockScheduleSegmentsEvent<Self::Api>,
+    );
+
+    fn emit_register_unlock_schedule_event(
+        &self,
+        schedule_id: UnlockScheduleId,
+        segments: ManagedVec<UnlockSegment>,
+    ) {
+        let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.remove_guaranteed_users_from_blacklist_event(
-            admin.clone(),
+        self.register_unlock_schedule_event(
+            user.clone(),
             round,
             epoch,
-            RemoveGuaranteedUsersFromBlacklistEvent {
-                admin,
+            RegisterUnlockScheduleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
                 round,
                 epoch,
-                users,
+                schedule_id,
+                segments,
             },
         )
     }
 
-    fn emit_set_unlock_schedule_event(&self, milestones: ManagedVec<UnlockMilestone>) {
-        let admin = self.blockchain().get_caller();
+    #[event("registerUnlockSchedule")]
+    fn register_unlock_schedule_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        register_unlock_schedule_event: RegisterUnlockScheduleEvent<Self::Api>,
+    );
+
+    fn emit_set_guaranteed_tickets_unlock_schedule_event(&self, schedule_id: UnlockScheduleId) {
+        let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.set_unlock_schedule_event(
-            admin.clone(),
+        self.set_guaranteed_tickets_unlock_schedule_event(
+            user.clone(),
             round,
             epoch,
-            SetUnlockScheduleEvent {
-                admin,
+            SetGuaranteedTicketsUnlockScheduleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
                 round,
                 epoch,
-                milestones,
+                schedule_id,
             },
         )
     }
 
-    fn emit_add_tickets_event(
+    #[event("setGuaranteedTicketsUnlockSchedule")]
+    fn set_guaranteed_tickets_unlock_schedule_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_guaranteed_tickets_unlock_schedule_event: SetGuaranteedTicketsUnlockScheduleEvent<
+            Self::Api,
+        >,
+    );
+
+    fn emit_issue_vesting_position_token_event(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.issue_vesting_position_token_event(
+            user.clone(),
+            round,
+            epoch,
+            IssueVestingPositionTokenEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                token_display_name,
+                token_ticker,
+            },
+        )
+    }
+
+    #[event("issueVestingPositionToken")]
+    fn issue_vesting_position_token_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        issue_vesting_position_token_event: IssueVestingPositionTokenEvent<Self::Api>,
+    );
+
+    fn emit_mint_vesting_position_event(
         &self,
-        users_count: usize,
-        total_tickets_added: usize,
-        total_guaranteed_tickets_added: usize,
+        user: ManagedAddress,
+        position: EsdtTokenPayment,
+        attributes: VestingPositionAttributes<Self::Api>,
     ) {
-        let admin = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.add_tickets_event(
-            admin.clone(),
+        self.mint_vesting_position_event(
+            user.clone(),
+            round,
+            epoch,
+            MintVestingPositionEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                position,
+                attributes,
+            },
+        )
+    }
+
+    #[event("mintVestingPosition")]
+    fn mint_vesting_position_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        mint_vesting_position_event: MintVestingPositionEvent<Self::Api>,
+    );
+
+    fn emit_storage_migration_completed_event(&self, nr_users_migrated: usize) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.storage_migration_completed_event(
+            user.clone(),
             round,
             epoch,
-            AddTicketsEvent {
-                admin,
+            StorageMigrationCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
                 round,
                 epoch,
-                users_count,
-                total_tickets_added,
-                total_guaranteed_tickets_added,
+                nr_users_migrated,
             },
         )
     }
 
-    fn emit_distribute_guaranteed_tickets_completed_event(
+    #[event("storageMigrationCompleted")]
+    fn storage_migration_completed_event(
         &self,
-        total_additional_winning_tickets: usize,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        storage_migration_completed_event: StorageMigrationCompletedEvent<Self::Api>,
+    );
+
+    fn emit_push_release_event(
+        &self,
+        address: ManagedAddress,
+        token_payment: EsdtTokenPayment<Self::Api>,
     ) {
-        let admin = self.blockchain().get_caller();
+        let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
-        self.distribute_guaranteed_tickets_completed_event(
-            admin.clone(),
+        self.push_release_event(
+            user.clone(),
             round,
             epoch,
-            DistributeGuaranteedTicketsCompletedEvent {
-                admin,
+            PushReleaseEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
                 round,
                 epoch,
-                total_additional_winning_tickets,
+                address,
+                token_payment,
             },
         )
     }
 
-    #[event("claimLaunchpadTokens")]
-    fn claim_launchpad_tokens_event(
+    #[event("pushRelease")]
+    fn push_release_event(
         &self,
         #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        claim_launchpad_tokens_event: ClaimLaunchpadTokensEvent<Self::Api>,
+        push_release_event: PushReleaseEvent<Self::Api>,
     );
 
-    #[event("addUsersToBlacklist")]
-    fn add_users_to_blacklist_event(
+    fn emit_set_proceeds_escrow_event(
         &self,
-        #[indexed] admin: ManagedAddress,
+        approver: ManagedAddress,
+        approval_quorum: u64,
+        milestones: ManagedVec<u64>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_proceeds_escrow_event(
+            user.clone(),
+            round,
+            epoch,
+            SetProceedsEscrowEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                approver,
+                approval_quorum,
+                milestones,
+            },
+        )
+    }
+
+    #[event("setProceedsEscrow")]
+    fn set_proceeds_escrow_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        add_users_to_blacklist_event: AddUsersToBlacklistEvent<Self::Api>,
+        set_proceeds_escrow_event: SetProceedsEscrowEvent<Self::Api>,
     );
 
-    #[event("removeGuaranteedUsersFromBlacklist")]
-    fn remove_guaranteed_users_from_blacklist_event(
+    fn emit_vote_escrow_milestone_event(&self, milestone_index: usize, nr_votes: usize) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.vote_escrow_milestone_event(
+            user.clone(),
+            round,
+            epoch,
+            VoteEscrowMilestoneEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                milestone_index,
+                nr_votes,
+            },
+        )
+    }
+
+    #[event("voteEscrowMilestone")]
+    fn vote_escrow_milestone_event(
         &self,
-        #[indexed] admin: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        remove_guaranteed_users_from_blacklist_event: RemoveGuaranteedUsersFromBlacklistEvent<
-            Self::Api,
-        >,
+        vote_escrow_milestone_event: VoteEscrowMilestoneEvent<Self::Api>,
     );
 
-    #[event("setUnlockSchedule")]
-    fn set_unlock_schedule_event(
+    fn emit_approve_escrow_milestone_event(&self, milestone_index: usize) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.approve_escrow_milestone_event(
+            user.clone(),
+            round,
+            epoch,
+            ApproveEscrowMilestoneEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                milestone_index,
+            },
+        )
+    }
+
+    #[event("approveEscrowMilestone")]
+    fn approve_escrow_milestone_event(
         &self,
-        #[indexed] admin: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        set_unlock_schedule_event: SetUnlockScheduleEvent<Self::Api>,
+        approve_escrow_milestone_event: ApproveEscrowMilestoneEvent<Self::Api>,
     );
 
-    #[event("addTickets")]
-    fn add_tickets_event(
+    fn emit_declare_project_failed_event(&self, refundable_amount: BigUint) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.declare_project_failed_event(
+            user.clone(),
+            round,
+            epoch,
+            DeclareProjectFailedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                refundable_amount,
+            },
+        )
+    }
+
+    #[event("declareProjectFailed")]
+    fn declare_project_failed_event(
         &self,
-        #[indexed] admin: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        add_tickets_event: AddTicketsEvent<Self::Api>,
+        declare_project_failed_event: DeclareProjectFailedEvent<Self::Api>,
     );
 
-    #[event("distributeGuaranteedTicketsCompleted")]
-    fn distribute_guaranteed_tickets_completed_event(
+    fn emit_claim_proceeds_refund_event(&self, token_payment: EgldOrEsdtTokenPayment<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_proceeds_refund_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimProceedsRefundEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                token_payment,
+            },
+        )
+    }
+
+    #[event("claimProceedsRefund")]
+    fn claim_proceeds_refund_event(
         &self,
-        #[indexed] admin: ManagedAddress,
+        #[indexed] caller: ManagedAddress,
         #[indexed] round: u64,
         #[indexed] epoch: u64,
-        distribute_guaranteed_tickets_completed_event: DistributeGuaranteedTicketsCompletedEvent<
-            Self::Api,
-        >,
+        claim_proceeds_refund_event: ClaimProceedsRefundEvent<Self::Api>,
     );
 }
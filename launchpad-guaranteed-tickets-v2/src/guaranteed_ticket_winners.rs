@@ -2,9 +2,12 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID,
     ongoing_operation::{CONTINUE_OP, STOP_OP},
+    operation_progress::{OperationPhase, OperationProgress},
     random::Random,
-    tickets::{TicketRange, WINNING_TICKET},
+    selection_pipeline::AdditionalSelectionStep,
+    tickets::{TicketRanges, WINNING_TICKET},
 };
 use multiversx_sc::api::CryptoApi;
 
@@ -48,7 +51,10 @@ pub trait GuaranteedTicketWinnersModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
     + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::token_release::TokenReleaseModule
+    + crate::events::EventsModule
 {
     fn select_guaranteed_tickets(
         &self,
@@ -56,6 +62,9 @@ pub trait GuaranteedTicketWinnersModule:
     ) -> OperationCompletionStatus {
         let mut users_whitelist = self.users_with_guaranteed_ticket();
         let mut users_left = users_whitelist.len();
+        if self.current_ongoing_operation().is_empty() {
+            self.guaranteed_users_selection_total().set(users_left);
+        }
 
         self.run_while_it_has_gas(|| {
             if users_left == 0 {
@@ -70,7 +79,9 @@ pub trait GuaranteedTicketWinnersModule:
             if user_ticket_status_mapper.is_empty() {
                 return CONTINUE_OP;
             }
-            let user_confirmed_tickets = self.nr_confirmed_tickets(&current_user).get();
+            let user_confirmed_tickets = self
+                .nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &current_user)
+                .get();
             let user_ticket_status = user_ticket_status_mapper.get();
 
             let result =
@@ -120,18 +131,18 @@ pub trait GuaranteedTicketWinnersModule:
         guaranteed_tickets: usize,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
     ) {
-        let ticket_range_mapper = self.ticket_range_for_address(user);
-        if ticket_range_mapper.is_empty() {
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(FIRST_SALE_ROUND_ID, user);
+        if ticket_ranges_mapper.is_empty() {
             op.leftover_tickets += guaranteed_tickets;
             return;
         }
-        let ticket_range = ticket_range_mapper.get();
+        let ticket_ranges = ticket_ranges_mapper.get();
 
-        let user_winning_tickets = self.winning_tickets_in_range(&ticket_range);
+        let user_winning_tickets = self.winning_tickets_in_ranges(&ticket_ranges);
 
         if guaranteed_tickets > user_winning_tickets {
             let tickets_to_win = guaranteed_tickets - user_winning_tickets;
-            self.select_additional_winning_tickets(ticket_range, tickets_to_win, op);
+            self.select_additional_winning_tickets(ticket_ranges, tickets_to_win, op);
             op.leftover_tickets += user_winning_tickets;
         } else {
             op.leftover_tickets += guaranteed_tickets;
@@ -140,21 +151,25 @@ pub trait GuaranteedTicketWinnersModule:
 
     fn select_additional_winning_tickets(
         &self,
-        ticket_range: TicketRange,
+        ticket_ranges: TicketRanges<Self::Api>,
         tickets_to_win: usize,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
     ) {
         let mut remaining_tickets = tickets_to_win;
-        let mut current_ticket = ticket_range.first_id;
-
-        while remaining_tickets > 0 && current_ticket <= ticket_range.last_id {
-            let is_winning_ticket = self.ticket_status(current_ticket).get();
-            if !is_winning_ticket {
-                self.ticket_status(current_ticket).set(WINNING_TICKET);
-                op.total_additional_winning_tickets += 1;
-                remaining_tickets -= 1;
+        for ticket_range in ticket_ranges.iter() {
+            let mut current_ticket = ticket_range.first_id;
+            while remaining_tickets > 0 && current_ticket <= ticket_range.last_id {
+                let is_winning_ticket = self
+                    .ticket_status(FIRST_SALE_ROUND_ID, current_ticket)
+                    .get();
+                if !is_winning_ticket {
+                    self.ticket_status(FIRST_SALE_ROUND_ID, current_ticket)
+                        .set(WINNING_TICKET);
+                    op.total_additional_winning_tickets += 1;
+                    remaining_tickets -= 1;
+                }
+                current_ticket += 1;
             }
-            current_ticket += 1;
         }
 
         op.leftover_tickets += remaining_tickets;
@@ -164,8 +179,8 @@ pub trait GuaranteedTicketWinnersModule:
         &self,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
     ) -> OperationCompletionStatus {
-        let nr_original_winning_tickets = self.nr_winning_tickets().get();
-        let last_ticket_pos = self.get_total_tickets();
+        let nr_original_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let last_ticket_pos = self.get_total_tickets(FIRST_SALE_ROUND_ID);
 
         self.run_while_it_has_gas(|| {
             if self.are_all_tickets_distributed(nr_original_winning_tickets, op, last_ticket_pos) {
@@ -223,12 +238,14 @@ pub trait GuaranteedTicketWinnersModule:
         CONTINUE_OP
     }
 
-    fn winning_tickets_in_range(&self, ticket_range: &TicketRange) -> usize {
+    fn winning_tickets_in_ranges(&self, ticket_ranges: &TicketRanges<Self::Api>) -> usize {
         let mut winning_tickets_no = 0;
-        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
-            if ticket_status == WINNING_TICKET {
-                winning_tickets_no += 1;
+        for ticket_range in ticket_ranges.iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                let ticket_status = self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get();
+                if ticket_status == WINNING_TICKET {
+                    winning_tickets_no += 1;
+                }
             }
         }
 
@@ -241,30 +258,102 @@ pub trait GuaranteedTicketWinnersModule:
         current_ticket_position: usize,
         last_ticket_position: usize,
     ) -> AdditionalSelectionTryResult {
-        let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
+        let current_ticket_id =
+            self.get_ticket_id_from_pos(FIRST_SALE_ROUND_ID, current_ticket_position);
         if self.is_already_winning_ticket(current_ticket_id) {
             return AdditionalSelectionTryResult::CurrentAlreadyWinning;
         }
 
         let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
-        let selected_ticket_id = self.get_ticket_id_from_pos(rand_pos);
+        let selected_ticket_id = self.get_ticket_id_from_pos(FIRST_SALE_ROUND_ID, rand_pos);
         if self.is_already_winning_ticket(selected_ticket_id) {
             // Swap tickets positions so that the current position still has a chance in future selections
-            self.ticket_pos_to_id(current_ticket_position)
+            self.ticket_pos_to_id(FIRST_SALE_ROUND_ID, current_ticket_position)
                 .set(selected_ticket_id);
-            self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
+            self.ticket_pos_to_id(FIRST_SALE_ROUND_ID, rand_pos)
+                .set(current_ticket_id);
 
             return AdditionalSelectionTryResult::NewlySelectedAlreadyWinning;
         }
 
-        self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
-        self.ticket_status(selected_ticket_id).set(WINNING_TICKET);
+        self.ticket_pos_to_id(FIRST_SALE_ROUND_ID, rand_pos)
+            .set(current_ticket_id);
+        self.ticket_status(FIRST_SALE_ROUND_ID, selected_ticket_id)
+            .set(WINNING_TICKET);
 
         AdditionalSelectionTryResult::Ok
     }
 
     #[inline]
     fn is_already_winning_ticket(&self, ticket_id: usize) -> bool {
-        self.ticket_status(ticket_id).get() == WINNING_TICKET
+        self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get() == WINNING_TICKET
+    }
+
+    fn guaranteed_tickets_selection_progress(
+        &self,
+        op: &GuaranteedTicketsSelectionOperation<Self::Api>,
+    ) -> OperationProgress {
+        let users_left = self.users_with_guaranteed_ticket().len();
+        if users_left > 0 {
+            let total_users_mapper = self.guaranteed_users_selection_total();
+            let users_processed = if self.current_ongoing_operation().is_empty() {
+                0
+            } else {
+                total_users_mapper.get() - users_left
+            };
+
+            return OperationProgress::new(
+                OperationPhase::GuaranteedTicketsSelection,
+                users_processed,
+                users_left,
+            );
+        }
+
+        let nr_original_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let last_ticket_pos = self.get_total_tickets(FIRST_SALE_ROUND_ID);
+        let positions_left = last_ticket_pos
+            .saturating_sub(nr_original_winning_tickets + op.total_additional_winning_tickets);
+
+        OperationProgress::new(
+            OperationPhase::LeftoverTicketsDistribution,
+            op.leftover_ticket_pos_offset - 1,
+            core::cmp::min(op.leftover_tickets, positions_left),
+        )
+    }
+
+    #[storage_mapper("guaranteedUsersSelectionTotal")]
+    fn guaranteed_users_selection_total(&self) -> SingleValueMapper<usize>;
+}
+
+/// Selects the guaranteed tickets, then redistributes the ones that could not be awarded
+pub struct GuaranteedTicketsStep;
+
+impl<Sc: GuaranteedTicketWinnersModule> AdditionalSelectionStep<Sc> for GuaranteedTicketsStep {
+    type Operation = GuaranteedTicketsSelectionOperation<Sc::Api>;
+
+    fn run(&self, sc: &Sc, op: &mut Self::Operation) -> OperationCompletionStatus {
+        let run_result = sc.select_guaranteed_tickets(op);
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            return run_result;
+        }
+
+        sc.distribute_leftover_tickets(op)
+    }
+
+    fn on_complete(&self, sc: &Sc, op: &Self::Operation) {
+        let ticket_price = sc.ticket_price(FIRST_SALE_ROUND_ID).get();
+        let claimable_ticket_payment =
+            ticket_price.amount * (op.total_additional_winning_tickets as u32);
+        sc.claimable_ticket_payment()
+            .update(|claim_amt| *claim_amt += claimable_ticket_payment);
+
+        sc.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .update(|nr_winning| *nr_winning += op.total_additional_winning_tickets);
+
+        sc.emit_distribute_guaranteed_tickets_completed_event(op.total_additional_winning_tickets);
+    }
+
+    fn progress(&self, sc: &Sc, op: &Self::Operation) -> OperationProgress {
+        sc.guaranteed_tickets_selection_progress(op)
     }
 }
@@ -1,6 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, pagination::get_page};
+
+use crate::token_release::UnlockScheduleId;
+
 pub const MAX_TICKETS_ALLOWANCE: usize = 255;
 pub const MAX_GUARANTEED_TICKETS_ENTRIES: usize = 10;
 
@@ -23,12 +27,13 @@ impl<M: ManagedTypeApi> UserTicketsStatus<M> {
             guaranteed_tickets_info: ManagedVec::new(),
         }
     }
-}
 
-pub struct AddTicketsResult {
-    pub total_users_count: usize,
-    pub total_tickets_added: usize,
-    pub total_guaranteed_tickets_added: usize,
+    pub fn guaranteed_tickets(&self) -> usize {
+        self.guaranteed_tickets_info
+            .iter()
+            .map(|info| info.guaranteed_tickets)
+            .sum()
+    }
 }
 
 #[multiversx_sc::module]
@@ -37,17 +42,25 @@ pub trait GuaranteedTicketsInitModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::token_release::TokenReleaseModule
+    + crate::events::EventsModule
 {
+    /// Users are assigned the given unlock schedule, if any,
+    /// or the guaranteed tickets one if they get guaranteed tickets
     fn add_tickets_with_guaranteed_winners(
         &self,
         address_number_pairs: MultiValueEncoded<
             MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
         >,
-    ) -> AddTicketsResult {
-        self.require_add_tickets_period();
+        opt_unlock_schedule_id: Option<UnlockScheduleId>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        if let Some(schedule_id) = opt_unlock_schedule_id {
+            self.require_registered_unlock_schedule(schedule_id);
+        }
 
-        let mut guaranteed_ticket_whitelist = self.users_with_guaranteed_ticket();
-        let mut total_winning_tickets = self.nr_winning_tickets().get();
+        let mut total_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
 
         let mut total_users_count = 0;
@@ -68,59 +81,182 @@ pub trait GuaranteedTicketsInitModule:
                 total_tickets_allowance <= MAX_TICKETS_ALLOWANCE,
                 "Total number of tickets exceeds maximum allowed"
             );
-            require!(
-                guaranteed_ticket_raw.len() <= MAX_GUARANTEED_TICKETS_ENTRIES,
-                "Number of guaranteed tickets entries exceeds maximum allowed"
+            let first_ticket_id = self.try_create_tickets(
+                FIRST_SALE_ROUND_ID,
+                buyer.clone(),
+                total_tickets_allowance,
             );
 
-            self.try_create_tickets(buyer.clone(), total_tickets_allowance);
+            let user_ticket_status = self.grant_guaranteed_tickets(
+                &buyer,
+                total_tickets_allowance,
+                guaranteed_ticket_raw,
+                &mut total_winning_tickets,
+                &mut total_guaranteed_tickets,
+            );
+            let user_guaranteed_tickets = user_ticket_status.guaranteed_tickets();
+            total_guaranteed_tickets_added += user_guaranteed_tickets;
+            total_tickets_added += total_tickets_allowance;
 
-            let mut user_ticket_status = UserTicketsStatus::new(total_tickets_allowance);
+            total_users_count += 1;
+            self.user_ticket_status(&buyer).set(user_ticket_status);
+            self.assign_user_unlock_schedule(
+                &buyer,
+                opt_unlock_schedule_id,
+                user_guaranteed_tickets > 0,
+            );
 
-            let mut user_guaranteed_tickets = 0;
+            self.emit_add_user_tickets_event(
+                FIRST_SALE_ROUND_ID,
+                buyer,
+                first_ticket_id,
+                total_tickets_allowance,
+                user_guaranteed_tickets,
+            );
+        }
 
-            let mut guaranteed_ticket_infos = ManagedVec::new();
-            for info in guaranteed_ticket_raw.into_iter() {
-                let (guaranteed_tickets, min_confirmed_tickets) = info.into_tuple();
-                require!(
-                    guaranteed_tickets <= min_confirmed_tickets,
-                    "Invalid guaranteed ticket min confirmed tickets"
-                );
-                user_guaranteed_tickets += guaranteed_tickets;
+        self.total_guaranteed_tickets()
+            .set(total_guaranteed_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(total_winning_tickets);
+
+        self.emit_add_tickets_event(
+            FIRST_SALE_ROUND_ID,
+            total_users_count,
+            total_tickets_added,
+            total_guaranteed_tickets_added,
+        );
+    }
+
+    /// Sets the ticket allowances and guaranteed entries of users who were already added,
+    /// or adds new users. Users set to 0 tickets are removed from the sale.
+    /// The unlock schedules are assigned again, as for `add_tickets_with_guaranteed_winners`.
+    fn set_tickets_with_guaranteed_winners(
+        &self,
+        address_number_pairs: MultiValueEncoded<
+            MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
+        >,
+        opt_unlock_schedule_id: Option<UnlockScheduleId>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        if let Some(schedule_id) = opt_unlock_schedule_id {
+            self.require_registered_unlock_schedule(schedule_id);
+        }
+
+        let mut total_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
+        for multi_arg in address_number_pairs.into_iter() {
+            let (address, total_tickets_allowance, guaranteed_ticket_raw) = multi_arg.into_tuple();
+            require!(
+                self.blacklist_user_ticket_status(&address).is_empty(),
+                "Cannot change the allowance of a blacklisted user"
+            );
+            require!(
+                total_tickets_allowance <= MAX_TICKETS_ALLOWANCE,
+                "Total number of tickets exceeds maximum allowed"
+            );
 
-                let guaranteed_ticket_info = GuaranteedTicketInfo {
-                    guaranteed_tickets,
-                    min_confirmed_tickets,
-                };
-                guaranteed_ticket_infos.push(guaranteed_ticket_info);
+            let user_ticket_status_mapper = self.user_ticket_status(&address);
+            if !user_ticket_status_mapper.is_empty() {
+                let previous_guaranteed_tickets =
+                    user_ticket_status_mapper.take().guaranteed_tickets();
+                if previous_guaranteed_tickets > 0 {
+                    let _ = self.users_with_guaranteed_ticket().swap_remove(&address);
+                    total_winning_tickets += previous_guaranteed_tickets;
+                    total_guaranteed_tickets -= previous_guaranteed_tickets;
+                }
             }
 
-            if user_guaranteed_tickets > 0 {
+            let previous_nr_tickets = self.try_set_ticket_allowance(
+                FIRST_SALE_ROUND_ID,
+                &address,
+                total_tickets_allowance,
+            );
+
+            let mut user_guaranteed_tickets = 0;
+            if total_tickets_allowance > 0 {
                 require!(
-                    total_winning_tickets >= user_guaranteed_tickets,
-                    "Not enough winning tickets for guaranteed allocation"
+                    !self.blockchain().is_smart_contract(&address),
+                    "Only user accounts can participate"
+                );
+
+                let user_ticket_status = self.grant_guaranteed_tickets(
+                    &address,
+                    total_tickets_allowance,
+                    guaranteed_ticket_raw,
+                    &mut total_winning_tickets,
+                    &mut total_guaranteed_tickets,
                 );
-                let _ = guaranteed_ticket_whitelist.insert(buyer.clone());
-                total_winning_tickets -= user_guaranteed_tickets;
-                total_guaranteed_tickets += user_guaranteed_tickets;
-                user_ticket_status.guaranteed_tickets_info = guaranteed_ticket_infos;
-                total_guaranteed_tickets_added += user_guaranteed_tickets;
+                user_guaranteed_tickets = user_ticket_status.guaranteed_tickets();
+                user_ticket_status_mapper.set(user_ticket_status);
+                self.assign_user_unlock_schedule(
+                    &address,
+                    opt_unlock_schedule_id,
+                    user_guaranteed_tickets > 0,
+                );
+            } else {
+                self.user_unlock_schedule_id(&address).clear();
             }
-            total_tickets_added += total_tickets_allowance;
 
-            total_users_count += 1;
-            self.user_ticket_status(&buyer).set(user_ticket_status);
+            self.emit_set_user_ticket_allowance_event(
+                FIRST_SALE_ROUND_ID,
+                address,
+                previous_nr_tickets,
+                total_tickets_allowance,
+                user_guaranteed_tickets,
+            );
         }
 
         self.total_guaranteed_tickets()
             .set(total_guaranteed_tickets);
-        self.nr_winning_tickets().set(total_winning_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(total_winning_tickets);
+    }
 
-        AddTicketsResult {
-            total_users_count,
-            total_tickets_added,
-            total_guaranteed_tickets_added,
+    /// Reserves the user's guaranteed tickets out of the winning tickets
+    fn grant_guaranteed_tickets(
+        &self,
+        user: &ManagedAddress,
+        total_tickets_allowance: usize,
+        guaranteed_ticket_raw: MultiValueEncodedCounted<MultiValue2<usize, usize>>,
+        total_winning_tickets: &mut usize,
+        total_guaranteed_tickets: &mut usize,
+    ) -> UserTicketsStatus<Self::Api> {
+        require!(
+            guaranteed_ticket_raw.len() <= MAX_GUARANTEED_TICKETS_ENTRIES,
+            "Number of guaranteed tickets entries exceeds maximum allowed"
+        );
+
+        let mut user_ticket_status = UserTicketsStatus::new(total_tickets_allowance);
+        let mut user_guaranteed_tickets = 0;
+        let mut guaranteed_ticket_infos = ManagedVec::new();
+        for info in guaranteed_ticket_raw.into_iter() {
+            let (guaranteed_tickets, min_confirmed_tickets) = info.into_tuple();
+            require!(
+                guaranteed_tickets <= min_confirmed_tickets,
+                "Invalid guaranteed ticket min confirmed tickets"
+            );
+            user_guaranteed_tickets += guaranteed_tickets;
+
+            let guaranteed_ticket_info = GuaranteedTicketInfo {
+                guaranteed_tickets,
+                min_confirmed_tickets,
+            };
+            guaranteed_ticket_infos.push(guaranteed_ticket_info);
         }
+
+        if user_guaranteed_tickets > 0 {
+            require!(
+                *total_winning_tickets >= user_guaranteed_tickets,
+                "Not enough winning tickets for guaranteed allocation"
+            );
+            let _ = self.users_with_guaranteed_ticket().insert(user.clone());
+            *total_winning_tickets -= user_guaranteed_tickets;
+            *total_guaranteed_tickets += user_guaranteed_tickets;
+            user_ticket_status.guaranteed_tickets_info = guaranteed_ticket_infos;
+        }
+
+        user_ticket_status
     }
 
     fn clear_users_with_guaranteed_ticket_after_blacklist(
@@ -128,7 +264,7 @@ pub trait GuaranteedTicketsInitModule:
         users: &ManagedVec<ManagedAddress>,
     ) {
         let mut whitelist = self.users_with_guaranteed_ticket();
-        let mut nr_winning_tickets = self.nr_winning_tickets().get();
+        let mut nr_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
         for user in users {
             let _ = whitelist.swap_remove(&user);
@@ -144,17 +280,21 @@ pub trait GuaranteedTicketsInitModule:
                 .set(user_ticket_status);
         }
 
-        self.nr_winning_tickets().set(nr_winning_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(nr_winning_tickets);
         self.total_guaranteed_tickets()
             .set(total_guaranteed_tickets);
     }
 
     fn remove_guaranteed_tickets_from_blacklist(&self, users: &ManagedVec<ManagedAddress>) {
-        let mut nr_winning_tickets = self.nr_winning_tickets().get();
+        let mut nr_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
         let mut whitelist = self.users_with_guaranteed_ticket();
         for user in users {
-            if self.ticket_range_for_address(&user).is_empty() {
+            if self
+                .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &user)
+                .is_empty()
+            {
                 continue;
             }
 
@@ -178,11 +318,40 @@ pub trait GuaranteedTicketsInitModule:
                 .set(blacklist_user_ticket_status);
         }
 
-        self.nr_winning_tickets().set(nr_winning_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(nr_winning_tickets);
         self.total_guaranteed_tickets()
             .set(total_guaranteed_tickets);
     }
 
+    fn get_user_guaranteed_tickets(&self, user: &ManagedAddress) -> usize {
+        let user_ticket_status_mapper = self.user_ticket_status(user);
+        if user_ticket_status_mapper.is_empty() {
+            return 0;
+        }
+
+        let user_ticket_status = user_ticket_status_mapper.get();
+        user_ticket_status
+            .guaranteed_tickets_info
+            .iter()
+            .map(|info| info.guaranteed_tickets)
+            .sum()
+    }
+
+    fn clear_user_ticket_status(&self, user: &ManagedAddress) {
+        self.user_ticket_status(user).clear();
+        self.blacklist_user_ticket_status(user).clear();
+    }
+
+    #[view(getGuaranteedUsers)]
+    fn get_guaranteed_users(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        get_page(&self.users_with_guaranteed_ticket(), offset, limit)
+    }
+
     #[storage_mapper("usersWithGuaranteedTicket")]
     fn users_with_guaranteed_ticket(&self) -> UnorderedSetMapper<ManagedAddress>;
 
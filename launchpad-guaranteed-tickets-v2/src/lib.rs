@@ -3,14 +3,25 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{config::TokenAmountPair, launch_stage::Flags, tickets::WINNING_TICKET};
-
-use crate::guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation;
+use launchpad_common::{
+    config::{TokenAmountPair, FIRST_SALE_ROUND_ID},
+    launch_stage::Flags,
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    operation_progress::OperationProgress,
+    selection_pipeline::SelectionSteps,
+    tickets::WINNING_TICKET,
+    user_info::UserInfo,
+};
+
+use crate::{guaranteed_ticket_winners::GuaranteedTicketsStep, token_release::UnlockScheduleId};
 
 pub mod events;
 pub mod guaranteed_ticket_winners;
 pub mod guaranteed_tickets_init;
+pub mod proceeds_escrow;
+pub mod storage_migration;
 pub mod token_release;
+pub mod vesting_position;
 
 use crate::guaranteed_tickets_init::GuaranteedTicketInfo;
 
@@ -22,18 +33,28 @@ pub trait LaunchpadGuaranteedTickets:
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
     + launchpad_common::tickets::TicketsModule
     + launchpad_common::winner_selection::WinnerSelectionModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::operation_progress::OperationProgressModule
     + launchpad_common::permissions::PermissionsModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::user_info::UserInfoModule
     + guaranteed_tickets_init::GuaranteedTicketsInitModule
     + guaranteed_ticket_winners::GuaranteedTicketWinnersModule
     + token_release::TokenReleaseModule
+    + vesting_position::VestingPositionModule
+    + proceeds_escrow::ProceedsEscrowModule
     + events::EventsModule
     + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::selection_pipeline::SelectionPipelineModule
+    + storage_migration::StorageMigrationModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -63,7 +84,12 @@ pub trait LaunchpadGuaranteedTickets:
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.require_no_additional_selection_in_progress();
+        self.upgrade_base();
+        self.start_storage_migration();
+        self.migrate_unlock_milestones();
+    }
 
     /// The parameter `address_number_pairs` is a list of tuples, where each tuple contains:
     /// the address of the user, total number of tickets to be added, and a list of guaranteed tickets
@@ -75,20 +101,57 @@ pub trait LaunchpadGuaranteedTickets:
             MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
         >,
     ) {
-        let add_tickets_result = self.add_tickets_with_guaranteed_winners(address_number_pairs);
+        self.require_storage_migrated();
+        self.add_tickets_with_guaranteed_winners(address_number_pairs, None);
+    }
 
-        self.emit_add_tickets_event(
-            add_tickets_result.total_users_count,
-            add_tickets_result.total_tickets_added,
-            add_tickets_result.total_guaranteed_tickets_added,
-        );
+    /// Same entries as `addTickets`, all the users vesting on the given unlock schedule
+    #[only_owner]
+    #[endpoint(addTicketsWithUnlockSchedule)]
+    fn add_tickets_with_unlock_schedule_endpoint(
+        &self,
+        unlock_schedule_id: UnlockScheduleId,
+        address_number_pairs: MultiValueEncoded<
+            MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
+        >,
+    ) {
+        self.require_storage_migrated();
+        self.add_tickets_with_guaranteed_winners(address_number_pairs, Some(unlock_schedule_id));
+    }
+
+    /// Same entries as `addTickets`, setting the absolute allowance and guaranteed tickets
+    /// of users who were already added. Setting an allowance to 0 removes the user from the sale.
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<
+            MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
+        >,
+    ) {
+        self.require_storage_migrated();
+        self.set_tickets_with_guaranteed_winners(address_number_pairs, None);
+    }
+
+    /// Same entries as `setTicketAllowances`, all the users vesting on the given unlock schedule
+    #[only_owner]
+    #[endpoint(setTicketAllowancesWithUnlockSchedule)]
+    fn set_ticket_allowances_with_unlock_schedule_endpoint(
+        &self,
+        unlock_schedule_id: UnlockScheduleId,
+        address_number_pairs: MultiValueEncoded<
+            MultiValue3<ManagedAddress, usize, MultiValueEncodedCounted<MultiValue2<usize, usize>>>,
+        >,
+    ) {
+        self.require_storage_migrated();
+        self.set_tickets_with_guaranteed_winners(address_number_pairs, Some(unlock_schedule_id));
     }
 
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
     fn deposit_launchpad_tokens_endpoint(&self) {
-        let base_selection_winning_tickets = self.nr_winning_tickets().get();
+        let base_selection_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let reserved_tickets = self.total_guaranteed_tickets().get();
         let total_tickets = base_selection_winning_tickets + reserved_tickets;
 
@@ -97,6 +160,7 @@ pub trait LaunchpadGuaranteedTickets:
 
     #[endpoint(refundUserTickets)]
     fn refund_user_tickets(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+        self.require_storage_migrated();
         let users_vec = users_list.to_vec();
         self.add_users_to_blacklist(&users_vec);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
@@ -104,11 +168,10 @@ pub trait LaunchpadGuaranteedTickets:
 
     #[endpoint(addUsersToBlacklist)]
     fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+        self.require_storage_migrated();
         let users_vec = users_list.to_vec();
         self.add_users_to_blacklist(&users_vec);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
-
-        self.emit_add_users_to_blacklist_event(users_vec);
     }
 
     #[endpoint(removeGuaranteedUsersFromBlacklist)]
@@ -116,172 +179,272 @@ pub trait LaunchpadGuaranteedTickets:
         &self,
         users_list: MultiValueEncoded<ManagedAddress>,
     ) {
+        self.require_storage_migrated();
         let users_vec = users_list.to_vec();
         self.remove_users_from_blacklist(users_list);
         self.remove_guaranteed_tickets_from_blacklist(&users_vec);
-
-        self.emit_remove_guaranteed_users_from_blacklist_event(users_vec);
     }
 
     #[endpoint(distributeGuaranteedTickets)]
     fn distribute_guaranteed_tickets_endpoint(&self) -> OperationCompletionStatus {
+        self.require_storage_migrated();
         self.require_not_paused();
-        self.require_winner_selection_period();
-
         self.check_caller_owner_or_user();
 
-        let flags_mapper = self.flags();
-        let mut flags = flags_mapper.get();
-        require!(
-            flags.were_winners_selected,
-            "Must select winners for base launchpad first"
-        );
-        require!(
-            !flags.was_additional_step_completed,
-            "Already distributed tickets"
-        );
-
-        let mut current_operation: GuaranteedTicketsSelectionOperation<Self::Api> =
-            self.load_additional_selection_operation();
-        let first_op_run_result = self.select_guaranteed_tickets(&mut current_operation);
-        if first_op_run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
-            self.save_additional_selection_progress(&current_operation);
-
-            return first_op_run_result;
-        }
-
-        let second_op_run_result = self.distribute_leftover_tickets(&mut current_operation);
-        match second_op_run_result {
-            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
-                self.save_additional_selection_progress(&current_operation);
-            }
-            OperationCompletionStatus::Completed => {
-                flags.was_additional_step_completed = true;
-                flags_mapper.set(&flags);
-
-                let ticket_price = self.ticket_price().get();
-                let claimable_ticket_payment = ticket_price.amount
-                    * (current_operation.total_additional_winning_tickets as u32);
-                self.claimable_ticket_payment()
-                    .update(|claim_amt| *claim_amt += claimable_ticket_payment);
-
-                self.nr_winning_tickets().update(|nr_winning| {
-                    *nr_winning += current_operation.total_additional_winning_tickets
-                });
-
-                self.emit_distribute_guaranteed_tickets_completed_event(
-                    current_operation.total_additional_winning_tickets,
-                );
-            }
-        };
+        self.run_selection_pipeline(self.additional_selection_steps())
+    }
 
-        second_op_run_result
+    fn additional_selection_steps(&self) -> SelectionSteps<Self> {
+        &[&GuaranteedTicketsStep]
     }
 
     #[endpoint(claimLaunchpadTokens)]
     fn claim_launchpad_tokens_endpoint(&self) {
         self.require_not_paused();
+        self.require_before_claim_deadline();
+
         let caller = self.blockchain().get_caller();
-        let user_results_processed = self.claim_list().contains(&caller);
+        let user_results_processed = self.claim_list(FIRST_SALE_ROUND_ID).contains(&caller);
         if !user_results_processed {
             self.compute_launchpad_results(&caller);
+        } else {
+            // users who claimed before the storage migration are only in the claim list
+            self.claimed_users(FIRST_SALE_ROUND_ID)
+                .insert(caller.clone());
         };
 
         let claimable_tokens = self.compute_claimable_tokens(&caller);
         if claimable_tokens > 0 {
-            let launchpad_token_id = self.launchpad_token_id().get();
-            self.send()
-                .direct_esdt(&caller, &launchpad_token_id, 0, &claimable_tokens);
-            self.user_claimed_balance(&caller)
-                .update(|balance| *balance += &claimable_tokens);
-
-            self.emit_claim_launchpad_tokens_event(EsdtTokenPayment::new(
-                launchpad_token_id,
-                0,
-                claimable_tokens,
-            ));
+            let payment = self.send_claimable_tokens(&caller, claimable_tokens);
+            self.emit_claim_launchpad_tokens_event(FIRST_SALE_ROUND_ID, payment);
+        }
+
+        if !user_results_processed && self.are_vesting_positions_enabled() {
+            self.convert_into_vesting_position(&caller);
+        }
+    }
+
+    /// Sends every participant the tokens released since their last claim.
+    /// The results of participants who have not claimed yet are computed first,
+    /// the same as on their first claim, while the ones left without tickets,
+    /// e.g. who did not confirm, were filtered out or blacklisted, are skipped.
+    /// The claimed users are walked afterwards, to reach the ones who claimed in v1,
+    /// who are not among the participants. They are only claimed users after claiming again,
+    /// or once the owner lists them through `backfillClaimedUsers`.
+    #[endpoint(pushReleases)]
+    fn push_releases_endpoint(&self) -> OperationCompletionStatus {
+        self.require_extended_permissions();
+        self.require_not_paused();
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
+        self.require_before_claim_deadline();
+
+        let participants_mapper = self.participants();
+        let claimed_users_mapper = self.claimed_users(FIRST_SALE_ROUND_ID);
+        let nr_participants = participants_mapper.len();
+        let (mut user_index, nr_users) =
+            self.load_push_releases_operation(nr_participants + claimed_users_mapper.len());
+        let run_result = self.run_while_it_has_gas(|| {
+            if user_index > nr_users {
+                return STOP_OP;
+            }
+
+            if user_index <= nr_participants {
+                let user = participants_mapper.get_by_index(user_index);
+                self.push_participant_release(&user);
+            } else {
+                let user = claimed_users_mapper.get_by_index(user_index - nr_participants);
+                if !participants_mapper.contains(&user) {
+                    self.push_user_release(&user);
+                }
+            }
+            user_index += 1;
+
+            CONTINUE_OP
+        });
+
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperationType::PushReleases {
+                user_index,
+                nr_users,
+            });
         }
+
+        run_result
+    }
+
+    fn push_participant_release(&self, user: &ManagedAddress) {
+        let user_results_processed = self.claim_list(FIRST_SALE_ROUND_ID).contains(user);
+        if !user_results_processed {
+            if self
+                .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, user)
+                .is_empty()
+            {
+                return;
+            }
+
+            self.compute_launchpad_results(user);
+        }
+
+        self.push_user_release(user);
+
+        if !user_results_processed && self.are_vesting_positions_enabled() {
+            self.convert_into_vesting_position(user);
+        }
+    }
+
+    /// Same as a claim of the user, except that fully claimed users are skipped instead of failing
+    fn push_user_release(&self, user: &ManagedAddress) {
+        let user_total_claimable_balance = self.user_total_claimable_balance(user).get();
+        if user_total_claimable_balance == 0
+            || self.user_claimed_balance(user).get() >= user_total_claimable_balance
+        {
+            return;
+        }
+
+        let claimable_tokens = self.compute_claimable_tokens(user);
+        if claimable_tokens > 0 {
+            let payment = self.send_claimable_tokens(user, claimable_tokens);
+            self.emit_push_release_event(user.clone(), payment);
+        }
+    }
+
+    fn send_claimable_tokens(
+        &self,
+        user: &ManagedAddress,
+        claimable_tokens: BigUint,
+    ) -> EsdtTokenPayment {
+        let launchpad_token_id = self.launchpad_token_id().get();
+        self.send()
+            .direct_esdt(user, &launchpad_token_id, 0, &claimable_tokens);
+        self.record_launchpad_tokens_claim(&claimable_tokens);
+        self.user_claimed_balance(user)
+            .update(|balance| *balance += &claimable_tokens);
+
+        EsdtTokenPayment::new(launchpad_token_id, 0, claimable_tokens)
     }
 
     fn compute_launchpad_results(&self, caller: &ManagedAddress) {
-        self.require_claim_period();
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
 
-        let ticket_range = self.try_get_ticket_range(caller);
-        let nr_confirmed_tickets = self.nr_confirmed_tickets(caller).get();
+        let ticket_ranges = self.try_get_ticket_ranges(FIRST_SALE_ROUND_ID, caller);
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(FIRST_SALE_ROUND_ID, caller).get();
         let mut nr_redeemable_tickets = 0;
 
-        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
-            if ticket_status == WINNING_TICKET {
-                self.ticket_status(ticket_id).clear();
+        for ticket_range in ticket_ranges.iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                let ticket_status = self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get();
+                if ticket_status == WINNING_TICKET {
+                    self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).clear();
 
-                nr_redeemable_tickets += 1;
+                    nr_redeemable_tickets += 1;
+                }
+
+                self.ticket_pos_to_id(FIRST_SALE_ROUND_ID, ticket_id)
+                    .clear();
             }
 
-            self.ticket_pos_to_id(ticket_id).clear();
+            self.ticket_batch(FIRST_SALE_ROUND_ID, ticket_range.first_id)
+                .clear();
         }
 
-        self.nr_confirmed_tickets(caller).clear();
-        self.ticket_range_for_address(caller).clear();
-        self.ticket_batch(ticket_range.first_id).clear();
+        self.nr_confirmed_tickets(FIRST_SALE_ROUND_ID, caller)
+            .clear();
+        self.ticket_ranges_for_address(FIRST_SALE_ROUND_ID, caller)
+            .clear();
 
         if nr_redeemable_tickets > 0 {
-            self.nr_winning_tickets()
+            self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
                 .update(|nr_winning_tickets| *nr_winning_tickets -= nr_redeemable_tickets);
         }
 
-        self.claim_list().add(caller);
+        self.claim_list(FIRST_SALE_ROUND_ID).add(caller);
+        self.claimed_users(FIRST_SALE_ROUND_ID)
+            .insert(caller.clone());
+        self.record_user_claim();
+
+        let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
+        let launchpad_tokens_amount_won =
+            BigUint::from(nr_redeemable_tickets as u32) * tokens_per_winning_ticket;
+        self.emit_launchpad_results_computed_event(
+            FIRST_SALE_ROUND_ID,
+            nr_confirmed_tickets,
+            nr_redeemable_tickets,
+            launchpad_tokens_amount_won.clone(),
+        );
 
         let nr_tickets_to_refund = nr_confirmed_tickets - nr_redeemable_tickets;
-        self.refund_ticket_payment(caller, nr_tickets_to_refund);
+        self.refund_ticket_payment(FIRST_SALE_ROUND_ID, caller, nr_tickets_to_refund);
 
         if nr_redeemable_tickets > 0 {
-            let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
-            let launchpad_tokens_amount_won =
-                BigUint::from(nr_redeemable_tickets as u32) * tokens_per_winning_ticket;
-
             self.user_total_claimable_balance(caller)
                 .set(launchpad_tokens_amount_won);
         }
+        self.record_user_escrow_tickets(caller, nr_redeemable_tickets);
     }
 
     #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
-        self.require_claim_period();
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
 
         let owner = self.blockchain().get_caller();
 
-        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
         let ticket_payment_mapper = self.claimable_ticket_payment();
         let claimable_ticket_payment = ticket_payment_mapper.get();
-        if claimable_ticket_payment > 0 {
+        let payment_amount = if self.is_proceeds_escrow_enabled() {
+            self.take_released_proceeds(&claimable_ticket_payment)
+        } else {
             ticket_payment_mapper.clear();
-
+            claimable_ticket_payment.clone()
+        };
+        if payment_amount > 0 {
             self.send()
-                .direct(&owner, &ticket_price.token_id, 0, &claimable_ticket_payment);
+                .direct(&owner, &ticket_price.token_id, 0, &payment_amount);
         }
 
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let extra_launchpad_tokens = self.claim_extra_launchpad_tokens(
+            &owner,
+            &launchpad_token_id,
+            &claimable_ticket_payment,
+        );
+
+        self.emit_claim_ticket_payment_event(
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, payment_amount),
+            EsdtTokenPayment::new(launchpad_token_id, 0, extra_launchpad_tokens),
+        );
+    }
+
+    /// Sends back the deposited launchpad tokens that were not won by anyone
+    fn claim_extra_launchpad_tokens(
+        &self,
+        owner: &ManagedAddress,
+        launchpad_token_id: &TokenIdentifier,
+        claimable_ticket_payment: &BigUint,
+    ) -> BigUint {
         let deposited_tokens_mapper = self.total_launchpad_tokens_deposited();
         let total_launchpad_tokens_deposited = deposited_tokens_mapper.take();
         if total_launchpad_tokens_deposited == 0 {
-            return;
+            return BigUint::zero();
         }
 
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
         let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
-        let total_nr_winning_tickets = claimable_ticket_payment / ticket_price.amount;
+        let total_nr_winning_tickets = claimable_ticket_payment / &ticket_price.amount;
 
         let total_launchpad_tokens_won = total_nr_winning_tickets * amount_per_ticket;
         if total_launchpad_tokens_won >= total_launchpad_tokens_deposited {
-            return;
+            return BigUint::zero();
         }
 
-        let launchpad_token_id = self.launchpad_token_id().get();
         let extra_launchpad_tokens = total_launchpad_tokens_deposited - total_launchpad_tokens_won;
         if extra_launchpad_tokens > 0 {
             self.send()
-                .direct_esdt(&owner, &launchpad_token_id, 0, &extra_launchpad_tokens);
+                .direct_esdt(owner, launchpad_token_id, 0, &extra_launchpad_tokens);
         }
+
+        extra_launchpad_tokens
     }
 
     #[view(getUserTicketsStatus)]
@@ -296,4 +459,58 @@ pub trait LaunchpadGuaranteedTickets:
         )
             .into()
     }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::get_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.require_proceeds_escrow_settled();
+
+        self.cleanup_storage(
+            Self::cleanup_user_storage,
+            Self::get_vesting_positions_balance,
+        )
+    }
+
+    fn cleanup_user_storage(&self, address: &ManagedAddress) {
+        self.clear_user_ticket_status(address);
+        self.user_total_claimable_balance(address).clear();
+        self.user_claimed_balance(address).clear();
+        self.user_unlock_schedule_id(address).clear();
+        self.user_escrow_tickets(address).clear();
+        self.user_proceeds_refunded(address).clear();
+        for milestone_index in 0..self.escrow_milestones().get().len() {
+            self.user_escrow_vote(milestone_index, address).clear();
+        }
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(&self, address: ManagedAddress) -> UserInfo<Self::Api> {
+        self.get_user_info_base(FIRST_SALE_ROUND_ID, &address, Self::add_user_info)
+    }
+
+    fn add_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        user_info.guaranteed_tickets = self.get_user_guaranteed_tickets(address);
+        self.add_vesting_user_info(address, user_info);
+    }
+
+    fn get_additional_selection_progress(&self, encoded_data: ManagedBuffer) -> OperationProgress {
+        self.get_selection_pipeline_progress(self.additional_selection_steps(), encoded_data)
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(&self) -> OperationProgress {
+        self.get_operation_progress(FIRST_SALE_ROUND_ID, Self::get_additional_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(&self, gas_limit: u64) -> usize {
+        let progress = self.get_ongoing_operation_progress();
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
 }
@@ -0,0 +1,310 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{
+    config::{TokenAmountPair, FIRST_SALE_ROUND_ID},
+    tickets::WINNING_TICKET,
+};
+
+use crate::token_release::MAX_PERCENTAGE;
+
+pub const MAX_ESCROW_MILESTONES: usize = 20;
+
+#[multiversx_sc::module]
+pub trait ProceedsEscrowModule:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Once set, the ticket payments of the winners are released to the owner
+    /// milestone by milestone, each one taking the given percentage of the proceeds.
+    /// A milestone is approved by the `approver`, or by the winners holding
+    /// at least `approval_quorum` of the winning tickets.
+    /// The approver may not be the owner, and cannot be changed once set.
+    #[only_owner]
+    #[endpoint(setProceedsEscrow)]
+    fn set_proceeds_escrow(
+        &self,
+        approver: ManagedAddress,
+        approval_quorum: u64,
+        milestones: MultiValueEncoded<u64>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(
+            approver != self.blockchain().get_owner_address(),
+            "Escrow approver must differ from the owner"
+        );
+        let approver_mapper = self.escrow_approver();
+        require!(
+            approver_mapper.is_empty() || approver_mapper.get() == approver,
+            "Escrow approver cannot be changed"
+        );
+        require!(
+            approval_quorum > 0 && approval_quorum <= MAX_PERCENTAGE,
+            "Invalid approval quorum"
+        );
+        require!(
+            !milestones.is_empty() && milestones.len() <= MAX_ESCROW_MILESTONES,
+            "Invalid number of escrow milestones"
+        );
+
+        let mut total_percentage = 0;
+        let mut percentages = ManagedVec::new();
+        for percentage in milestones {
+            require!(percentage > 0, "Invalid escrow milestone percentage");
+
+            total_percentage += percentage;
+            percentages.push(percentage);
+        }
+        require!(
+            total_percentage == MAX_PERCENTAGE,
+            "Escrow milestones must add up to 100%"
+        );
+
+        approver_mapper.set(&approver);
+        self.escrow_approval_quorum().set(approval_quorum);
+        self.escrow_milestones().set(&percentages);
+
+        self.emit_set_proceeds_escrow_event(approver, approval_quorum, percentages);
+    }
+
+    #[endpoint(approveEscrowMilestone)]
+    fn approve_escrow_milestone(&self) {
+        self.require_escrow_approver();
+        let milestone_index = self.require_pending_escrow_milestone();
+
+        self.approve_milestone(milestone_index);
+    }
+
+    /// Each winning ticket of the caller counts as one vote for the next milestone,
+    /// whether or not they claimed already
+    #[endpoint(voteEscrowMilestone)]
+    fn vote_escrow_milestone(&self) {
+        self.require_not_paused();
+        let milestone_index = self.require_pending_escrow_milestone();
+
+        let caller = self.blockchain().get_caller();
+        let nr_winning_tickets = self.get_user_escrow_tickets(&caller);
+        require!(nr_winning_tickets > 0, "Only winners may vote");
+
+        let user_vote_mapper = self.user_escrow_vote(milestone_index, &caller);
+        require!(!user_vote_mapper.get(), "Already voted");
+        user_vote_mapper.set(true);
+
+        let nr_votes = self
+            .escrow_milestone_votes(milestone_index)
+            .update(|nr_votes| {
+                *nr_votes += nr_winning_tickets;
+                *nr_votes
+            });
+        self.emit_vote_escrow_milestone_event(milestone_index, nr_winning_tickets);
+
+        let nr_escrow_tickets = self.get_total_escrow_tickets();
+        let approval_quorum = self.escrow_approval_quorum().get();
+        if nr_votes as u64 * MAX_PERCENTAGE >= approval_quorum * nr_escrow_tickets as u64 {
+            self.approve_milestone(milestone_index);
+        }
+    }
+
+    /// The proceeds of the milestones not approved yet become refundable to the winners,
+    /// proportionally to their winning tickets
+    #[endpoint(declareProjectFailed)]
+    fn declare_project_failed(&self) {
+        self.require_escrow_approver();
+        let _ = self.require_pending_escrow_milestone();
+
+        self.project_failed().set(true);
+
+        let total_proceeds = self.claimable_ticket_payment().get();
+        let refundable_amount = &total_proceeds - &self.compute_released_proceeds(&total_proceeds);
+        self.emit_declare_project_failed_event(refundable_amount);
+    }
+
+    /// Refunds must be claimed before the claim deadline,
+    /// as the remaining balance is sent to the owner on storage cleanup
+    #[endpoint(claimProceedsRefund)]
+    fn claim_proceeds_refund(&self) {
+        self.require_not_paused();
+        self.require_before_claim_deadline();
+        require!(self.project_failed().get(), "Project not declared failed");
+
+        let caller = self.blockchain().get_caller();
+        let refunded_mapper = self.user_proceeds_refunded(&caller);
+        let nr_winning_tickets = self.get_user_escrow_tickets(&caller);
+        require!(
+            !refunded_mapper.get() && nr_winning_tickets > 0,
+            "Nothing to refund"
+        );
+        refunded_mapper.set(true);
+
+        let total_proceeds = self.claimable_ticket_payment().get();
+        let unreleased_proceeds =
+            &total_proceeds - &self.compute_released_proceeds(&total_proceeds);
+        let refund_amount = unreleased_proceeds * nr_winning_tickets as u32
+            / self.get_total_escrow_tickets() as u32;
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
+        if refund_amount > 0 {
+            self.send()
+                .direct(&caller, &ticket_price.token_id, 0, &refund_amount);
+        }
+
+        self.emit_claim_proceeds_refund_event(EgldOrEsdtTokenPayment::new(
+            ticket_price.token_id,
+            0,
+            refund_amount,
+        ));
+    }
+
+    fn approve_milestone(&self, milestone_index: usize) {
+        self.nr_approved_escrow_milestones()
+            .set(milestone_index + 1);
+
+        self.emit_approve_escrow_milestone_event(milestone_index);
+    }
+
+    fn require_escrow_approver(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.escrow_approver().get(), "Permission denied");
+    }
+
+    /// Returns the index of the next milestone to be approved
+    fn require_pending_escrow_milestone(&self) -> usize {
+        require!(self.is_proceeds_escrow_enabled(), "Proceeds escrow not set");
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
+        require!(!self.project_failed().get(), "Project declared failed");
+
+        let milestone_index = self.nr_approved_escrow_milestones().get();
+        require!(
+            milestone_index < self.escrow_milestones().get().len(),
+            "All escrow milestones approved"
+        );
+
+        milestone_index
+    }
+
+    /// The escrow is settled once all the milestones are approved, or the project failed
+    fn require_proceeds_escrow_settled(&self) {
+        if !self.is_proceeds_escrow_enabled() || self.project_failed().get() {
+            return;
+        }
+
+        require!(
+            self.nr_approved_escrow_milestones().get() == self.escrow_milestones().get().len(),
+            "Proceeds escrow not settled"
+        );
+    }
+
+    fn is_proceeds_escrow_enabled(&self) -> bool {
+        !self.escrow_milestones().is_empty()
+    }
+
+    /// Records the winning tickets of a user, which give their votes and refund share
+    fn record_user_escrow_tickets(&self, address: &ManagedAddress, nr_winning_tickets: usize) {
+        if nr_winning_tickets > 0 && self.is_proceeds_escrow_enabled() {
+            self.user_escrow_tickets(address).set(nr_winning_tickets);
+        }
+    }
+
+    /// The winning tickets recorded when the user's results were computed,
+    /// or the ones found in their ticket ranges until then
+    fn get_user_escrow_tickets(&self, address: &ManagedAddress) -> usize {
+        let user_escrow_tickets_mapper = self.user_escrow_tickets(address);
+        if !user_escrow_tickets_mapper.is_empty() {
+            return user_escrow_tickets_mapper.get();
+        }
+
+        let ticket_ranges_mapper = self.ticket_ranges_for_address(FIRST_SALE_ROUND_ID, address);
+        if ticket_ranges_mapper.is_empty() {
+            return 0;
+        }
+
+        let mut nr_winning_tickets = 0;
+        for ticket_range in ticket_ranges_mapper.get().iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                if self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get() == WINNING_TICKET {
+                    nr_winning_tickets += 1;
+                }
+            }
+        }
+
+        nr_winning_tickets
+    }
+
+    /// Moves the withdrawn amount up to the proceeds released so far, returning the difference
+    fn take_released_proceeds(&self, total_proceeds: &BigUint) -> BigUint {
+        let released_proceeds = self.compute_released_proceeds(total_proceeds);
+        let withdrawn_proceeds = self.withdrawn_proceeds().replace(released_proceeds.clone());
+
+        released_proceeds - withdrawn_proceeds
+    }
+
+    fn compute_released_proceeds(&self, total_proceeds: &BigUint) -> BigUint {
+        let nr_approved_milestones = self.nr_approved_escrow_milestones().get();
+        let mut released_percentage = 0;
+        for percentage in self
+            .escrow_milestones()
+            .get()
+            .iter()
+            .take(nr_approved_milestones)
+        {
+            released_percentage += percentage;
+        }
+
+        total_proceeds * released_percentage / MAX_PERCENTAGE
+    }
+
+    /// All the winning tickets, as paid into the escrow
+    fn get_total_escrow_tickets(&self) -> usize {
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price(FIRST_SALE_ROUND_ID).get();
+        let total_proceeds = self.claimable_ticket_payment().get();
+        let nr_tickets = total_proceeds / ticket_price.amount;
+
+        nr_tickets.to_u64().unwrap_or_default() as usize
+    }
+
+    #[view(getEscrowApprover)]
+    #[storage_mapper("escrowApprover")]
+    fn escrow_approver(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getEscrowMilestones)]
+    #[storage_mapper("escrowMilestones")]
+    fn escrow_milestones(&self) -> SingleValueMapper<ManagedVec<u64>>;
+
+    #[view(getEscrowApprovalQuorum)]
+    #[storage_mapper("escrowApprovalQuorum")]
+    fn escrow_approval_quorum(&self) -> SingleValueMapper<u64>;
+
+    #[view(getNrApprovedEscrowMilestones)]
+    #[storage_mapper("nrApprovedEscrowMilestones")]
+    fn nr_approved_escrow_milestones(&self) -> SingleValueMapper<usize>;
+
+    #[view(getEscrowMilestoneVotes)]
+    #[storage_mapper("escrowMilestoneVotes")]
+    fn escrow_milestone_votes(&self, milestone_index: usize) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("userEscrowVote")]
+    fn user_escrow_vote(
+        &self,
+        milestone_index: usize,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[view(getUserEscrowTickets)]
+    #[storage_mapper("userEscrowTickets")]
+    fn user_escrow_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("userProceedsRefunded")]
+    fn user_proceeds_refunded(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getWithdrawnProceeds)]
+    #[storage_mapper("withdrawnProceeds")]
+    fn withdrawn_proceeds(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(isProjectFailed)]
+    #[storage_mapper("projectFailed")]
+    fn project_failed(&self) -> SingleValueMapper<bool>;
+}
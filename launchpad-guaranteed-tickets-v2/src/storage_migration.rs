@@ -0,0 +1,297 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID,
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    tickets::{TicketBatch, TicketRange, TicketRanges},
+};
+
+use crate::{
+    guaranteed_tickets_init::{GuaranteedTicketInfo, UserTicketsStatus},
+    token_release::{UnlockMilestone, UnlockSchedule, MAX_PERCENTAGE},
+};
+
+/// `UserTicketsStatus` as stored by launchpad-guaranteed-tickets (v1)
+#[derive(TopEncode, TopDecode)]
+pub struct UserTicketsStatusV1 {
+    pub staking_tickets_allowance: usize,
+    pub energy_tickets_allowance: usize,
+    pub staking_guaranteed_tickets: usize,
+    pub migration_guaranteed_tickets: usize,
+}
+
+impl UserTicketsStatusV1 {
+    /// v1 guarantees the migration ticket if all the energy tickets are confirmed,
+    /// and the staking ticket if either all the tickets are confirmed along with the migration ticket,
+    /// or `min_confirmed_for_staking_ticket` are confirmed without it.
+    /// v2 entries are independent, so a user holding both requires all the tickets confirmed for the staking one.
+    pub fn into_v2<M: ManagedTypeApi>(
+        self,
+        min_confirmed_for_staking_ticket: usize,
+    ) -> UserTicketsStatus<M> {
+        let total_tickets_allowance =
+            self.staking_tickets_allowance + self.energy_tickets_allowance;
+        let mut guaranteed_tickets_info = ManagedVec::new();
+        if self.migration_guaranteed_tickets > 0 {
+            guaranteed_tickets_info.push(GuaranteedTicketInfo {
+                guaranteed_tickets: self.migration_guaranteed_tickets,
+                min_confirmed_tickets: core::cmp::max(
+                    self.energy_tickets_allowance,
+                    self.migration_guaranteed_tickets,
+                ),
+            });
+        }
+        if self.staking_guaranteed_tickets > 0 {
+            let min_confirmed_tickets = if self.migration_guaranteed_tickets > 0 {
+                total_tickets_allowance
+            } else {
+                min_confirmed_for_staking_ticket
+            };
+            guaranteed_tickets_info.push(GuaranteedTicketInfo {
+                guaranteed_tickets: self.staking_guaranteed_tickets,
+                min_confirmed_tickets: core::cmp::max(
+                    min_confirmed_tickets,
+                    self.staking_guaranteed_tickets,
+                ),
+            });
+        }
+
+        UserTicketsStatus {
+            total_tickets_allowance,
+            guaranteed_tickets_info,
+        }
+    }
+}
+
+/// `UnlockSchedule` as stored by launchpad-guaranteed-tickets (v1)
+#[derive(TopEncode, TopDecode)]
+pub struct UnlockScheduleV1 {
+    pub claim_start_round: u64,
+    pub initial_release_percentage: u64,
+    pub vesting_release_times: u64,
+    pub vesting_release_percentage: u64,
+    pub vesting_release_period: u64,
+}
+
+impl UnlockScheduleV1 {
+    /// The initial release, followed by one milestone for each vesting period
+    pub fn into_v2<M: ManagedTypeApi>(self) -> UnlockSchedule<M> {
+        let mut milestones = ManagedVec::new();
+        if self.initial_release_percentage == MAX_PERCENTAGE || self.vesting_release_period == 0 {
+            milestones.push(UnlockMilestone {
+                release_round: self.claim_start_round,
+                percentage: MAX_PERCENTAGE,
+            });
+
+            return UnlockSchedule::from_milestones(&milestones);
+        }
+
+        milestones.push(UnlockMilestone {
+            release_round: self.claim_start_round,
+            percentage: self.initial_release_percentage,
+        });
+        for i in 1..=self.vesting_release_times {
+            milestones.push(UnlockMilestone {
+                release_round: self.claim_start_round + i * self.vesting_release_period,
+                percentage: self.vesting_release_percentage,
+            });
+        }
+
+        UnlockSchedule::from_milestones(&milestones)
+    }
+}
+
+/// Upgrades a running v1 sale in place. The first sale round keeps the v1 keys, and the ticket batches,
+/// the claimable and claimed balances use the same encoding in both versions,
+/// so the results computed by v1 carry over as they are.
+#[multiversx_sc::module]
+pub trait StorageMigrationModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::token_release::TokenReleaseModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Called on upgrade. A v1 sale is recognised by the minimum confirmed tickets
+    /// for the staking guaranteed ticket, which v2 does not use.
+    /// The contract is paused until the owner unpauses it after `migrateStorage` completes.
+    fn start_storage_migration(&self) {
+        if self.legacy_min_confirmed_for_guaranteed_ticket().is_empty()
+            || self.storage_migration_pending().get()
+        {
+            return;
+        }
+
+        require!(
+            self.current_ongoing_operation().is_empty(),
+            "Cannot migrate storage during an ongoing operation"
+        );
+
+        let legacy_unlock_schedule_mapper = self.legacy_unlock_schedule();
+        if !legacy_unlock_schedule_mapper.is_empty() {
+            let unlock_schedule = legacy_unlock_schedule_mapper.take().into_v2();
+            self.unlock_schedule().set(unlock_schedule);
+        }
+
+        self.storage_migration_pending().set(true);
+        self.set_paused(true);
+    }
+
+    /// Walks the v1 ticket batches, then the users with a guaranteed ticket, and for each user found:
+    /// - converts the ticket status, including the blacklisted one, and the ticket range
+    /// - adds them to the participants, and to the blacklisted or claimed users if they are in the v1 lists
+    ///
+    /// The batches of the users filtered out or who already claimed were cleared by v1,
+    /// and their ticket status is not read anymore, so they are not listed as participants,
+    /// nor as blacklisted users. The users who claimed before the upgrade
+    /// are added to the claimed users on their next claim, or through `backfillClaimedUsers`.
+    #[only_owner]
+    #[endpoint(migrateStorage)]
+    fn migrate_storage(&self) -> OperationCompletionStatus {
+        require!(
+            self.storage_migration_pending().get(),
+            "No storage migration pending"
+        );
+
+        let min_confirmed_for_staking_ticket =
+            self.legacy_min_confirmed_for_guaranteed_ticket().get();
+        let last_ticket_id = self.last_ticket_id(FIRST_SALE_ROUND_ID).get();
+        let users_with_guaranteed_ticket_mapper = self.users_with_guaranteed_ticket();
+        let nr_users_with_guaranteed_ticket = users_with_guaranteed_ticket_mapper.len();
+        let (mut ticket_id, mut user_index) = self.load_storage_migration_operation();
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_id <= last_ticket_id {
+                let ticket_batch_mapper = self.ticket_batch(FIRST_SALE_ROUND_ID, ticket_id);
+                if ticket_batch_mapper.is_empty() {
+                    ticket_id += 1;
+                    return CONTINUE_OP;
+                }
+
+                let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.get();
+                self.migrate_user(&ticket_batch.address, min_confirmed_for_staking_ticket);
+                ticket_id += ticket_batch.nr_tickets;
+
+                return CONTINUE_OP;
+            }
+
+            if user_index > nr_users_with_guaranteed_ticket {
+                return STOP_OP;
+            }
+
+            let user = users_with_guaranteed_ticket_mapper.get_by_index(user_index);
+            self.migrate_user(&user, min_confirmed_for_staking_ticket);
+            user_index += 1;
+
+            CONTINUE_OP
+        });
+
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_progress(&OngoingOperationType::StorageMigration {
+                    ticket_id,
+                    user_index,
+                });
+            }
+            OperationCompletionStatus::Completed => {
+                self.legacy_min_confirmed_for_guaranteed_ticket().clear();
+                self.storage_migration_pending().clear();
+
+                self.emit_storage_migration_completed_event(self.participants().len());
+            }
+        };
+
+        run_result
+    }
+
+    /// Users already added to the participants were migrated before
+    fn migrate_user(&self, user: &ManagedAddress, min_confirmed_for_staking_ticket: usize) {
+        if !self.participants().insert(user.clone()) {
+            return;
+        }
+
+        self.migrate_user_ticket_status(user, min_confirmed_for_staking_ticket);
+
+        let legacy_ticket_range_mapper = self.legacy_ticket_range_for_address(user);
+        if !legacy_ticket_range_mapper.is_empty() {
+            let ticket_ranges = TicketRanges::from_single_item(legacy_ticket_range_mapper.get());
+            self.ticket_ranges_for_address(FIRST_SALE_ROUND_ID, user)
+                .set(ticket_ranges);
+        }
+
+        if self.blacklist().contains(user) {
+            self.blacklisted_users().insert(user.clone());
+        }
+        if self.claim_list(FIRST_SALE_ROUND_ID).contains(user) {
+            self.claimed_users(FIRST_SALE_ROUND_ID).insert(user.clone());
+        }
+    }
+
+    fn migrate_user_ticket_status(
+        &self,
+        user: &ManagedAddress,
+        min_confirmed_for_staking_ticket: usize,
+    ) {
+        let legacy_status_mapper = self.legacy_user_ticket_status(user);
+        if !legacy_status_mapper.is_empty() {
+            let user_ticket_status = legacy_status_mapper
+                .take()
+                .into_v2(min_confirmed_for_staking_ticket);
+            self.user_ticket_status(user).set(user_ticket_status);
+        }
+
+        let legacy_blacklist_status_mapper = self.legacy_blacklist_user_ticket_status(user);
+        if !legacy_blacklist_status_mapper.is_empty() {
+            let user_ticket_status = legacy_blacklist_status_mapper
+                .take()
+                .into_v2(min_confirmed_for_staking_ticket);
+            self.blacklist_user_ticket_status(user)
+                .set(user_ticket_status);
+        }
+    }
+
+    fn require_storage_migrated(&self) {
+        require!(
+            !self.storage_migration_pending().get(),
+            "Storage migration pending"
+        );
+    }
+
+    #[view(isStorageMigrationPending)]
+    #[storage_mapper("storageMigrationPending")]
+    fn storage_migration_pending(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("minConfirmedForGuaranteedTicket")]
+    fn legacy_min_confirmed_for_guaranteed_ticket(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("userTicketStatus")]
+    fn legacy_user_ticket_status(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<UserTicketsStatusV1>;
+
+    #[storage_mapper("blacklistUserTicketStatus")]
+    fn legacy_blacklist_user_ticket_status(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<UserTicketsStatusV1>;
+
+    #[storage_mapper("ticketRangeForAddress")]
+    fn legacy_ticket_range_for_address(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<TicketRange>;
+
+    #[storage_mapper("unlockSchedule")]
+    fn legacy_unlock_schedule(&self) -> SingleValueMapper<UnlockScheduleV1>;
+}
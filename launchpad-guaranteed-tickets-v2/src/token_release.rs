@@ -1,62 +1,197 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{config, launch_stage};
+use launchpad_common::{config, config::FIRST_SALE_ROUND_ID, launch_stage, user_info::UserInfo};
 
 pub const MAX_PERCENTAGE: u64 = 10_000;
 pub const MAX_UNLOCK_MILESTONES_ENTRIES: usize = 60;
 pub const MAX_RELEASE_ROUND_DIFF: u64 = 26_280_000; // 5 years in rounds at 6s/block
 
+pub type UnlockScheduleId = u32;
+
+/// Refers to the schedule set through `setUnlockSchedule` or `setUnlockScheduleSegments`
+pub const DEFAULT_UNLOCK_SCHEDULE_ID: UnlockScheduleId = 0;
+
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, ManagedVecItem)]
 pub struct UnlockMilestone {
     pub release_round: u64,
     pub percentage: u64,
 }
 
+/// - `Cliff` releases the whole percentage at `end_round`
+/// - `Step` releases the percentage in `nr_steps` equal parts, evenly spread up to `end_round`
+/// - `Linear` releases the percentage round by round, from `start_round` to `end_round`
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    ManagedVecItem,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug,
+)]
+pub enum UnlockCurve {
+    Cliff,
+    Step,
+    Linear,
+}
+
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct UnlockSegment {
+    pub curve: UnlockCurve,
+    pub start_round: u64,
+    pub end_round: u64,
+    pub nr_steps: u64,
+    pub percentage: u64,
+}
+
+impl UnlockSegment {
+    pub fn cliff(release_round: u64, percentage: u64) -> Self {
+        UnlockSegment {
+            curve: UnlockCurve::Cliff,
+            start_round: release_round,
+            end_round: release_round,
+            nr_steps: 0,
+            percentage,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self.curve {
+            UnlockCurve::Cliff => self.start_round <= self.end_round && self.nr_steps == 0,
+            UnlockCurve::Step => {
+                self.start_round < self.end_round
+                    && self.nr_steps > 0
+                    && self.nr_steps <= self.end_round - self.start_round
+            }
+            UnlockCurve::Linear => self.start_round < self.end_round && self.nr_steps == 0,
+        }
+    }
+
+    /// The released part of the segment's percentage, as `(numerator, denominator)`,
+    /// for a round between `start_round` and `end_round`
+    fn released_fraction(&self, current_round: u64) -> (u64, u64) {
+        let elapsed_rounds = current_round - self.start_round;
+        let total_rounds = self.end_round - self.start_round;
+        match self.curve {
+            UnlockCurve::Cliff => (0, 1),
+            UnlockCurve::Step => (elapsed_rounds * self.nr_steps / total_rounds, self.nr_steps),
+            UnlockCurve::Linear => (elapsed_rounds, total_rounds),
+        }
+    }
+}
+
 #[derive(TopEncode, TopDecode, TypeAbi, NestedEncode, NestedDecode)]
 pub struct UnlockSchedule<M: ManagedTypeApi> {
-    milestones: ManagedVec<M, UnlockMilestone>,
+    segments: ManagedVec<M, UnlockSegment>,
+}
+
+/// `UnlockSchedule` as stored before the unlock segments
+#[derive(TopEncode, TopDecode)]
+pub struct UnlockMilestonesSchedule<M: ManagedTypeApi> {
+    pub milestones: ManagedVec<M, UnlockMilestone>,
 }
 
 impl<M: ManagedTypeApi> Default for UnlockSchedule<M> {
     fn default() -> Self {
         Self {
-            milestones: ManagedVec::from_single_item(UnlockMilestone {
-                release_round: 0,
-                percentage: MAX_PERCENTAGE,
-            }),
+            segments: ManagedVec::from_single_item(UnlockSegment::cliff(0, MAX_PERCENTAGE)),
         }
     }
 }
 
 impl<M: ManagedTypeApi> UnlockSchedule<M> {
-    pub fn new(milestones: ManagedVec<M, UnlockMilestone>) -> Self {
-        UnlockSchedule { milestones }
+    pub fn new(segments: ManagedVec<M, UnlockSegment>) -> Self {
+        UnlockSchedule { segments }
+    }
+
+    pub fn from_milestones(milestones: &ManagedVec<M, UnlockMilestone>) -> Self {
+        let mut segments = ManagedVec::new();
+        for milestone in milestones.iter() {
+            segments.push(UnlockSegment::cliff(
+                milestone.release_round,
+                milestone.percentage,
+            ));
+        }
+
+        UnlockSchedule { segments }
+    }
+
+    /// Segments are sorted by round, so the last one ends last
+    pub fn last_release_round(&self) -> u64 {
+        match self.segments.iter().last() {
+            Some(segment) => segment.end_round,
+            None => 0,
+        }
     }
 
+    /// Segments may not overlap, and must be sorted by round
     fn validate(&self, current_round: u64) -> bool {
-        if self.milestones.is_empty() {
+        if self.segments.is_empty() {
             return false;
         }
 
         let mut total_percentage = 0u64;
         let mut last_round = 0u64;
 
-        for milestone in self.milestones.iter() {
-            if milestone.percentage > MAX_PERCENTAGE
-                || milestone.release_round < current_round
-                || milestone.release_round < last_round
-                || milestone.release_round > current_round + MAX_RELEASE_ROUND_DIFF
+        for segment in self.segments.iter() {
+            if !segment.is_valid()
+                || segment.percentage > MAX_PERCENTAGE
+                || segment.start_round < current_round
+                || segment.start_round < last_round
+                || segment.end_round > current_round + MAX_RELEASE_ROUND_DIFF
             {
                 return false;
             }
 
-            last_round = milestone.release_round;
-            total_percentage += milestone.percentage;
+            last_round = segment.end_round;
+            total_percentage += segment.percentage;
         }
 
         total_percentage == MAX_PERCENTAGE
     }
+
+    /// The released part of `total_amount`, rounded down only once,
+    /// so the amounts claimed over time always add up to what is released so far
+    pub fn compute_unlocked_amount(
+        &self,
+        total_amount: &BigUint<M>,
+        current_round: u64,
+    ) -> BigUint<M> {
+        let mut released_percentage = 0u64;
+        let mut ongoing_fraction = (0u64, 1u64);
+        for segment in self.segments.iter() {
+            if segment.end_round <= current_round {
+                released_percentage += segment.percentage;
+                continue;
+            }
+
+            // segments don't overlap, so none of the following ones started either
+            if segment.start_round < current_round {
+                let (numerator, denominator) = segment.released_fraction(current_round);
+                ongoing_fraction = (segment.percentage * numerator, denominator);
+            }
+            break;
+        }
+
+        let (ongoing_numerator, denominator) = ongoing_fraction;
+        let numerator = BigUint::from(released_percentage) * denominator + ongoing_numerator;
+
+        total_amount * &numerator / (BigUint::from(MAX_PERCENTAGE) * denominator)
+    }
 }
 
 #[multiversx_sc::module]
@@ -64,9 +199,10 @@ pub trait TokenReleaseModule:
     config::ConfigModule + launch_stage::LaunchStageModule + crate::events::EventsModule
 {
     #[only_owner]
+    #[label("vesting")]
     #[endpoint(setUnlockSchedule)]
     fn set_unlock_schedule(&self, unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>) {
-        self.require_add_tickets_period();
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
         require!(
             unlock_milestones.len() <= MAX_UNLOCK_MILESTONES_ENTRIES,
             "Maximum unlock milestones entries exceeded"
@@ -81,18 +217,194 @@ pub trait TokenReleaseModule:
             });
         }
 
+        self.try_set_unlock_schedule(UnlockSchedule::from_milestones(&milestones));
+
+        self.emit_set_unlock_schedule_event(milestones);
+    }
+
+    /// Segments are given as `(curve, start round, end round, number of steps, percentage)`,
+    /// the number of steps being used by the `Step` curve only, and 0 otherwise
+    #[only_owner]
+    #[label("vesting")]
+    #[endpoint(setUnlockScheduleSegments)]
+    fn set_unlock_schedule_segments(
+        &self,
+        unlock_segments: MultiValueEncoded<MultiValue5<UnlockCurve, u64, u64, u64, u64>>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(
+            unlock_segments.len() <= MAX_UNLOCK_MILESTONES_ENTRIES,
+            "Maximum unlock milestones entries exceeded"
+        );
+
+        let segments = self.build_unlock_segments(unlock_segments);
+        self.try_set_unlock_schedule(UnlockSchedule::new(segments.clone()));
+
+        self.emit_set_unlock_schedule_segments_event(segments);
+    }
+
+    /// Registers an additional schedule, with the same segments as `setUnlockScheduleSegments`.
+    /// Users vest on it if assigned through `addTicketsWithUnlockSchedule`,
+    /// or if it is the schedule of the guaranteed tickets winners.
+    #[only_owner]
+    #[label("vesting")]
+    #[endpoint(registerUnlockSchedule)]
+    fn register_unlock_schedule(
+        &self,
+        schedule_id: UnlockScheduleId,
+        unlock_segments: MultiValueEncoded<MultiValue5<UnlockCurve, u64, u64, u64, u64>>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(
+            schedule_id != DEFAULT_UNLOCK_SCHEDULE_ID,
+            "Invalid unlock schedule ID"
+        );
+        require!(
+            unlock_segments.len() <= MAX_UNLOCK_MILESTONES_ENTRIES,
+            "Maximum unlock milestones entries exceeded"
+        );
+
+        let segments = self.build_unlock_segments(unlock_segments);
+        let unlock_schedule = UnlockSchedule::new(segments.clone());
         let current_round = self.blockchain().get_block_round();
-        let unlock_schedule = UnlockSchedule::new(milestones.clone());
         require!(
             unlock_schedule.validate(current_round),
             "Invalid unlock schedule"
         );
 
+        self.require_release_before_claim_deadline(unlock_schedule.last_release_round());
+
+        self.unlock_schedule_by_id(schedule_id).set(unlock_schedule);
+        self.registered_unlock_schedule_ids().insert(schedule_id);
+
+        self.emit_register_unlock_schedule_event(schedule_id, segments);
+    }
+
+    /// Users added with guaranteed tickets from now on vest on the given schedule,
+    /// unless they are assigned another one explicitly
+    #[only_owner]
+    #[label("vesting")]
+    #[endpoint(setGuaranteedTicketsUnlockSchedule)]
+    fn set_guaranteed_tickets_unlock_schedule(&self, schedule_id: UnlockScheduleId) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        self.require_registered_unlock_schedule(schedule_id);
+
+        self.guaranteed_tickets_unlock_schedule_id()
+            .set(schedule_id);
+
+        self.emit_set_guaranteed_tickets_unlock_schedule_event(schedule_id);
+    }
+
+    /// Called on upgrade. Contracts deployed before the unlock segments
+    /// have their milestones converted into cliff segments.
+    fn migrate_unlock_milestones(&self) {
+        let legacy_unlock_schedule_mapper = self.legacy_unlock_milestones_schedule();
+        if legacy_unlock_schedule_mapper.is_empty() {
+            return;
+        }
+
+        let legacy_unlock_schedule = legacy_unlock_schedule_mapper.take();
+        self.unlock_schedule().set(UnlockSchedule::from_milestones(
+            &legacy_unlock_schedule.milestones,
+        ));
+    }
+
+    fn build_unlock_segments(
+        &self,
+        unlock_segments: MultiValueEncoded<MultiValue5<UnlockCurve, u64, u64, u64, u64>>,
+    ) -> ManagedVec<UnlockSegment> {
+        let mut segments = ManagedVec::new();
+        for unlock_segment in unlock_segments {
+            let (curve, start_round, end_round, nr_steps, percentage) = unlock_segment.into_tuple();
+            segments.push(UnlockSegment {
+                curve,
+                start_round,
+                end_round,
+                nr_steps,
+                percentage,
+            });
+        }
+
+        segments
+    }
+
+    fn try_set_unlock_schedule(&self, unlock_schedule: UnlockSchedule<Self::Api>) {
+        let current_round = self.blockchain().get_block_round();
+        require!(
+            unlock_schedule.validate(current_round),
+            "Invalid unlock schedule"
+        );
+        self.require_release_before_claim_deadline(unlock_schedule.last_release_round());
+
         self.unlock_schedule().set(unlock_schedule);
+    }
 
-        self.emit_set_unlock_schedule_event(milestones);
+    fn require_registered_unlock_schedule(&self, schedule_id: UnlockScheduleId) {
+        require!(
+            schedule_id == DEFAULT_UNLOCK_SCHEDULE_ID
+                || !self.unlock_schedule_by_id(schedule_id).is_empty(),
+            "Unknown unlock schedule"
+        );
+    }
+
+    /// Assigns the given schedule, or the guaranteed tickets schedule to users with guaranteed tickets
+    fn assign_user_unlock_schedule(
+        &self,
+        user: &ManagedAddress,
+        opt_schedule_id: Option<UnlockScheduleId>,
+        has_guaranteed_tickets: bool,
+    ) {
+        let schedule_id = match opt_schedule_id {
+            Some(schedule_id) => schedule_id,
+            None if has_guaranteed_tickets => self.guaranteed_tickets_unlock_schedule_id().get(),
+            None => DEFAULT_UNLOCK_SCHEDULE_ID,
+        };
+
+        let user_schedule_id_mapper = self.user_unlock_schedule_id(user);
+        if schedule_id == DEFAULT_UNLOCK_SCHEDULE_ID {
+            user_schedule_id_mapper.clear();
+        } else {
+            user_schedule_id_mapper.set(schedule_id);
+        }
+    }
+
+    fn get_user_unlock_schedule(&self, address: &ManagedAddress) -> UnlockSchedule<Self::Api> {
+        let schedule_id = self.user_unlock_schedule_id(address).get();
+        self.get_unlock_schedule(schedule_id)
+    }
+
+    fn get_unlock_schedule(&self, schedule_id: UnlockScheduleId) -> UnlockSchedule<Self::Api> {
+        if schedule_id != DEFAULT_UNLOCK_SCHEDULE_ID {
+            return self.unlock_schedule_by_id(schedule_id).get();
+        }
+
+        let unlock_schedule_mapper = self.unlock_schedule();
+        if unlock_schedule_mapper.is_empty() {
+            UnlockSchedule::default()
+        } else {
+            unlock_schedule_mapper.get()
+        }
+    }
+
+    /// The round from which the tokens of every schedule are fully released
+    fn get_last_release_round(&self) -> u64 {
+        let mut last_release_round = self
+            .get_unlock_schedule(DEFAULT_UNLOCK_SCHEDULE_ID)
+            .last_release_round();
+        for schedule_id in self.registered_unlock_schedule_ids().iter() {
+            let schedule_release_round = self
+                .unlock_schedule_by_id(schedule_id)
+                .get()
+                .last_release_round();
+            if schedule_release_round > last_release_round {
+                last_release_round = schedule_release_round;
+            }
+        }
+
+        last_release_round
     }
 
+    #[label("vesting")]
     #[view(getClaimableTokens)]
     fn compute_claimable_tokens(&self, address: &ManagedAddress) -> BigUint {
         let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
@@ -106,39 +418,79 @@ pub trait TokenReleaseModule:
             "Already claimed all tokens"
         );
 
-        let unlock_schedule_mapper = self.unlock_schedule();
-        let unlock_schedule = if unlock_schedule_mapper.is_empty() {
-            UnlockSchedule::default()
-        } else {
-            unlock_schedule_mapper.get()
-        };
+        let current_claimable_tokens =
+            self.compute_unlocked_tokens(address, &user_total_claimable_balance);
+
+        current_claimable_tokens - user_claimed_balance
+    }
 
+    /// The part of `total_amount` released so far, according to the user's unlock schedule
+    fn compute_unlocked_tokens(&self, address: &ManagedAddress, total_amount: &BigUint) -> BigUint {
+        let unlock_schedule = self.get_user_unlock_schedule(address);
         let current_round = self.blockchain().get_block_round();
+        unlock_schedule.compute_unlocked_amount(total_amount, current_round)
+    }
 
-        let mut claimable_percentage = 0u64;
-        for milestone in unlock_schedule.milestones.iter() {
-            if milestone.release_round <= current_round {
-                claimable_percentage += milestone.percentage;
-            } else {
-                break;
-            }
+    fn add_vesting_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        if !user_info.has_claimed {
+            user_info.claimable_tokens =
+                self.compute_unlocked_tokens(address, &user_info.claimable_tokens);
+            return;
         }
 
-        let current_claimable_tokens =
-            &user_total_claimable_balance * claimable_percentage / MAX_PERCENTAGE;
-
-        current_claimable_tokens - user_claimed_balance
+        let user_claimed_balance = self.user_claimed_balance(address).get();
+        if !self.is_claim_deadline_passed() {
+            let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
+            let unlocked_tokens =
+                self.compute_unlocked_tokens(address, &user_total_claimable_balance);
+            if unlocked_tokens > user_claimed_balance {
+                user_info.claimable_tokens = unlocked_tokens - &user_claimed_balance;
+            }
+        }
+        user_info.claimed_tokens = user_claimed_balance;
     }
 
+    #[label("vesting")]
     #[view(getUserTotalClaimableBalance)]
     #[storage_mapper("userTotalClaimableBalance")]
     fn user_total_claimable_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    #[label("vesting")]
     #[view(getUserClaimedBalance)]
     #[storage_mapper("userClaimedBalance")]
     fn user_claimed_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
-    #[view(getUnlockSchedule)]
-    #[storage_mapper("unlockSchedule")]
+    #[label("vesting")]
+    #[view(getUnlockScheduleSegments)]
+    #[storage_mapper("unlockScheduleSegments")]
     fn unlock_schedule(&self) -> SingleValueMapper<UnlockSchedule<Self::Api>>;
+
+    #[storage_mapper("unlockSchedule")]
+    fn legacy_unlock_milestones_schedule(
+        &self,
+    ) -> SingleValueMapper<UnlockMilestonesSchedule<Self::Api>>;
+
+    #[label("vesting")]
+    #[view(getUnlockScheduleById)]
+    #[storage_mapper("unlockScheduleById")]
+    fn unlock_schedule_by_id(
+        &self,
+        schedule_id: UnlockScheduleId,
+    ) -> SingleValueMapper<UnlockSchedule<Self::Api>>;
+
+    #[storage_mapper("registeredUnlockScheduleIds")]
+    fn registered_unlock_schedule_ids(&self) -> UnorderedSetMapper<UnlockScheduleId>;
+
+    #[label("vesting")]
+    #[view(getGuaranteedTicketsUnlockScheduleId)]
+    #[storage_mapper("guaranteedTicketsUnlockScheduleId")]
+    fn guaranteed_tickets_unlock_schedule_id(&self) -> SingleValueMapper<UnlockScheduleId>;
+
+    #[label("vesting")]
+    #[view(getUserUnlockScheduleId)]
+    #[storage_mapper("userUnlockScheduleId")]
+    fn user_unlock_schedule_id(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<UnlockScheduleId>;
 }
@@ -0,0 +1,154 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::config::FIRST_SALE_ROUND_ID;
+
+use crate::token_release::UnlockScheduleId;
+
+pub const VESTING_POSITION_AMOUNT: u32 = 1;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingPositionAttributes<M: ManagedTypeApi> {
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub unlock_schedule_id: UnlockScheduleId,
+}
+
+#[multiversx_sc::module]
+pub trait VestingPositionModule:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::token_release::TokenReleaseModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Once issued, the tokens which are not released yet at the first claim
+    /// are held by an NFT instead of the user's address
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueVestingPositionToken)]
+    fn issue_vesting_position_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        self.require_before_claim_period_for_all_sale_rounds();
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.emit_issue_vesting_position_token_event(
+            token_display_name.clone(),
+            token_ticker.clone(),
+        );
+        self.vesting_position_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    /// Sends the tokens released so far, and an updated position if not all of them were released.
+    /// Positions may be held by anyone, so they stay redeemable after the claim deadline.
+    #[payable("*")]
+    #[endpoint(redeemVestingPosition)]
+    fn redeem_vesting_position(&self) {
+        self.require_not_paused();
+
+        let payment = self.call_value().single_esdt();
+        let position_token_mapper = self.vesting_position_token();
+        position_token_mapper.require_same_token(&payment.token_identifier);
+
+        let mut attributes: VestingPositionAttributes<Self::Api> =
+            position_token_mapper.get_token_attributes(payment.token_nonce);
+        let current_round = self.blockchain().get_block_round();
+        let unlocked_amount = self
+            .get_unlock_schedule(attributes.unlock_schedule_id)
+            .compute_unlocked_amount(&attributes.total_amount, current_round);
+        require!(
+            unlocked_amount > attributes.claimed_amount,
+            "No tokens released yet"
+        );
+
+        position_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        let claimable_tokens = &unlocked_amount - &attributes.claimed_amount;
+        let launchpad_token_id = self.launchpad_token_id().get();
+        self.send()
+            .direct_esdt(&caller, &launchpad_token_id, 0, &claimable_tokens);
+        self.record_launchpad_tokens_claim(&claimable_tokens);
+        self.vesting_positions_balance()
+            .update(|balance| *balance -= &claimable_tokens);
+        self.emit_claim_launchpad_tokens_event(
+            FIRST_SALE_ROUND_ID,
+            EsdtTokenPayment::new(launchpad_token_id, 0, claimable_tokens),
+        );
+
+        attributes.claimed_amount = unlocked_amount;
+        if attributes.claimed_amount < attributes.total_amount {
+            self.mint_vesting_position(&caller, attributes);
+        }
+    }
+
+    fn are_vesting_positions_enabled(&self) -> bool {
+        !self.vesting_position_token().is_empty()
+    }
+
+    /// Moves the user's unreleased tokens, along with their unlock schedule, into a new position
+    fn convert_into_vesting_position(&self, user: &ManagedAddress) {
+        let total_amount = self.user_total_claimable_balance(user).get();
+        let claimed_amount = self.user_claimed_balance(user).get();
+        if claimed_amount >= total_amount {
+            return;
+        }
+
+        self.user_total_claimable_balance(user).clear();
+        self.user_claimed_balance(user).clear();
+        let unlock_schedule_id = self.user_unlock_schedule_id(user).take();
+        self.vesting_positions_balance()
+            .update(|balance| *balance += &total_amount - &claimed_amount);
+
+        self.mint_vesting_position(
+            user,
+            VestingPositionAttributes {
+                total_amount,
+                claimed_amount,
+                unlock_schedule_id,
+            },
+        );
+    }
+
+    fn mint_vesting_position(
+        &self,
+        user: &ManagedAddress,
+        attributes: VestingPositionAttributes<Self::Api>,
+    ) {
+        let position = self.vesting_position_token().nft_create_and_send(
+            user,
+            BigUint::from(VESTING_POSITION_AMOUNT),
+            &attributes,
+        );
+
+        self.emit_mint_vesting_position_event(user.clone(), position, attributes);
+    }
+
+    #[inline]
+    fn get_vesting_positions_balance(&self) -> BigUint {
+        self.vesting_positions_balance().get()
+    }
+
+    /// Launchpad tokens held by vesting positions, which were not redeemed yet
+    #[view(getVestingPositionsBalance)]
+    #[storage_mapper("vestingPositionsBalance")]
+    fn vesting_positions_balance(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getVestingPositionTokenId)]
+    #[storage_mapper("vestingPositionToken")]
+    fn vesting_position_token(&self) -> NonFungibleTokenMapper<Self::Api>;
+}
@@ -1,19 +1,30 @@
+pub mod v1_storage;
+
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::storage::{
+    mappers::{SingleValueMapper, StorageMapper},
+    StorageKey,
+};
 use multiversx_sc::types::{
-    Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, MultiValueEncodedCounted,
+    Address, EgldOrEsdtTokenIdentifier, ManagedBuffer, MultiValueEncoded, MultiValueEncodedCounted,
     OperationCompletionStatus,
 };
 
 use launchpad_common::{
-    config::ConfigModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
     launch_stage::{Flags, LaunchStageModule},
     tickets::{TicketsModule, WINNING_TICKET},
     user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
-use launchpad_guaranteed_tickets_v2::token_release::TokenReleaseModule;
 use launchpad_guaranteed_tickets_v2::{
     guaranteed_tickets_init::GuaranteedTicketsInitModule, LaunchpadGuaranteedTickets,
 };
+use launchpad_guaranteed_tickets_v2::{
+    storage_migration::StorageMigrationModule,
+    token_release::{TokenReleaseModule, UnlockCurve, UnlockScheduleId},
+};
+use launchpad_test_support::assert_events;
 use multiversx_sc_modules::pause::PauseModule;
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, rust_biguint,
@@ -21,6 +32,8 @@ use multiversx_sc_scenario::{
     DebugApi,
 };
 
+use self::v1_storage::{decode_hex, V1Snapshot, V1_LAUNCHPAD, V1_OWNER, V1_PARTICIPANTS};
+
 pub static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
 pub const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100;
 pub const CONFIRM_START_ROUND: u64 = 5;
@@ -93,59 +106,130 @@ where
 
         // add tickets
         // first user - 1 ticket, second user - 2 tickets, 3rd user - 3 tickets
-        b_mock
-            .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
-                let mut args = MultiValueEncoded::new();
-                args.push(
-                    (
-                        managed_address!(&participants[0]),
-                        1,
-                        MultiValueEncodedCounted::new(),
-                    )
-                        .into(),
-                );
-                args.push(
-                    (
-                        managed_address!(&participants[1]),
-                        2,
-                        MultiValueEncodedCounted::new(),
-                    )
-                        .into(),
-                );
-                let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
-                guaranteed_tickets_info.push((1, 3).into());
-                args.push(
-                    (
-                        managed_address!(&participants[2]),
-                        MAX_TIER_TICKETS,
-                        guaranteed_tickets_info,
-                    )
-                        .into(),
-                );
-                sc.add_tickets_endpoint(args);
-
-                // 1 ticket for the guaranteed entry gets removed
-                assert_eq!(sc.nr_winning_tickets().get(), nr_winning_tickets - 1);
-                assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
-                assert!(sc
-                    .users_with_guaranteed_ticket()
-                    .contains(&managed_address!(participants.last().unwrap())));
-            })
-            .assert_ok();
+        let add_tickets_result = b_mock.execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
+            let mut args = MultiValueEncoded::new();
+            args.push(
+                (
+                    managed_address!(&participants[0]),
+                    1,
+                    MultiValueEncodedCounted::new(),
+                )
+                    .into(),
+            );
+            args.push(
+                (
+                    managed_address!(&participants[1]),
+                    2,
+                    MultiValueEncodedCounted::new(),
+                )
+                    .into(),
+            );
+            let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
+            guaranteed_tickets_info.push((1, 3).into());
+            args.push(
+                (
+                    managed_address!(&participants[2]),
+                    MAX_TIER_TICKETS,
+                    guaranteed_tickets_info,
+                )
+                    .into(),
+            );
+            sc.add_tickets_endpoint(args);
+
+            // 1 ticket for the guaranteed entry gets removed
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                nr_winning_tickets - 1
+            );
+            assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
+            assert!(sc
+                .users_with_guaranteed_ticket()
+                .contains(&managed_address!(participants.last().unwrap())));
+        });
+        add_tickets_result.assert_ok();
+        assert_events(
+            &add_tickets_result,
+            &[
+                "addUserTickets",
+                "addUserTickets",
+                "addUserTickets",
+                "addTickets",
+            ],
+        );
 
         // deposit launchpad tokens
-        b_mock
-            .execute_esdt_transfer(
-                &owner_address,
-                &lp_wrapper,
+        let deposit_result = b_mock.execute_esdt_transfer(
+            &owner_address,
+            &lp_wrapper,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &total_launchpad_tokens,
+            |sc| {
+                sc.deposit_launchpad_tokens_endpoint();
+            },
+        );
+        deposit_result.assert_ok();
+        assert_events(&deposit_result, &["depositLaunchpadTokens"]);
+
+        Self {
+            b_mock,
+            owner_address,
+            participants,
+            lp_wrapper,
+        }
+    }
+
+    /// Recreates the accounts and the launchpad storage left by a v1 sale, with the v2 code deployed.
+    /// The launchpad is not upgraded yet.
+    pub fn new_from_v1_snapshot(snapshot: &V1Snapshot, lp_builder: LaunchpadBuilder) -> Self {
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = Address::from_slice(&decode_hex(V1_OWNER));
+        let participants: Vec<Address> = V1_PARTICIPANTS
+            .iter()
+            .map(|address| Address::from_slice(&decode_hex(address)))
+            .collect();
+        let lp_address = Address::from_slice(&decode_hex(V1_LAUNCHPAD));
+
+        let mut lp_balances = (0, 0);
+        for &(address, egld_balance, launchpad_token_balance) in snapshot.balances {
+            if address == V1_LAUNCHPAD {
+                lp_balances = (egld_balance, launchpad_token_balance);
+                continue;
+            }
+
+            let address = Address::from_slice(&decode_hex(address));
+            b_mock.create_user_account_fixed_address(&address, &rust_biguint!(egld_balance));
+            b_mock.set_esdt_balance(
+                &address,
                 LAUNCHPAD_TOKEN_ID,
-                0,
-                &total_launchpad_tokens,
-                |sc| {
-                    sc.deposit_launchpad_tokens_endpoint();
-                },
-            )
+                &rust_biguint!(launchpad_token_balance),
+            );
+        }
+
+        let lp_wrapper = b_mock.create_sc_account_fixed_address(
+            &lp_address,
+            &rust_biguint!(lp_balances.0),
+            Some(&owner_address),
+            lp_builder,
+            "buy tickets = win.wasm",
+        );
+        b_mock.set_esdt_balance(
+            &lp_address,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(lp_balances.1),
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &lp_wrapper, &rust_biguint!(0), |_| {
+                for &(key_name, key_args, value) in snapshot.storage {
+                    let mut key = StorageKey::<DebugApi>::new(key_name.as_bytes());
+                    key.append_bytes(&decode_hex(key_args));
+                    SingleValueMapper::<DebugApi, ManagedBuffer<DebugApi>>::new(key)
+                        .set(ManagedBuffer::new_from_bytes(&decode_hex(value)));
+                }
+            })
             .assert_ok();
+        b_mock.set_block_round(snapshot.block_round);
 
         Self {
             b_mock,
@@ -161,7 +245,12 @@ where
             &self.lp_wrapper,
             &rust_biguint!(TICKET_COST * nr_tickets as u64),
             |sc| {
-                sc.confirm_tickets(nr_tickets);
+                sc.confirm_tickets(
+                    nr_tickets,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
     }
@@ -172,7 +261,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let result = sc.filter_tickets();
+                let result = sc.filter_tickets(OptionalValue::None);
                 assert_eq!(result, OperationCompletionStatus::Completed);
             },
         )
@@ -186,13 +275,14 @@ where
             |sc| {
                 let base_winning = NR_WINNING_TICKETS - nr_whales;
                 for ticket_id in 1..=base_winning {
-                    sc.ticket_status(ticket_id).set(WINNING_TICKET);
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id)
+                        .set(WINNING_TICKET);
                 }
 
                 sc.claimable_ticket_payment()
                     .set(&managed_biguint!(TICKET_COST * (base_winning as u64)));
 
-                sc.flags().set(&Flags {
+                sc.flags(FIRST_SALE_ROUND_ID).set(&Flags {
                     were_tickets_filtered: true,
                     has_winner_selection_process_started: true,
                     were_winners_selected: true,
@@ -208,7 +298,19 @@ where
             &self.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let result = sc.select_winners();
+                let result = sc.select_winners(OptionalValue::None);
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+    }
+
+    pub fn abort_winner_selection(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.abort_winner_selection(OptionalValue::None);
                 assert_eq!(result, OperationCompletionStatus::Completed);
             },
         )
@@ -244,6 +346,76 @@ where
         )
     }
 
+    pub fn push_releases(&mut self, caller: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.lp_wrapper, &rust_biguint!(0), |sc| {
+                let result = sc.push_releases_endpoint();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            })
+    }
+
+    pub fn set_claim_deadline_round(&mut self, deadline_round: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_deadline_round_endpoint(deadline_round);
+            },
+        )
+    }
+
+    pub fn cleanup_storage(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.cleanup_storage_endpoint();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+    }
+
+    pub fn upgrade(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.upgrade();
+            },
+        )
+    }
+
+    /// Writes a value as stored by an earlier version of the contract
+    pub fn set_raw_storage(&mut self, key_name: &str, value: &str) {
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.lp_wrapper,
+                &rust_biguint!(0),
+                |_| {
+                    let key = StorageKey::<DebugApi>::new(key_name.as_bytes());
+                    SingleValueMapper::<DebugApi, ManagedBuffer<DebugApi>>::new(key)
+                        .set(ManagedBuffer::new_from_bytes(&decode_hex(value)));
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn migrate_storage(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.migrate_storage();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+    }
+
     pub fn set_unlock_schedule(&mut self, unlock_milestones: Vec<(u64, u64)>) {
         let _ = self.b_mock.execute_tx(
             &self.owner_address,
@@ -260,6 +432,45 @@ where
         );
     }
 
+    pub fn set_unlock_schedule_segments(
+        &mut self,
+        unlock_segments: Vec<(UnlockCurve, u64, u64, u64, u64)>,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut segments = MultiValueEncoded::new();
+                for segment in unlock_segments {
+                    segments.push(segment.into());
+                }
+
+                sc.set_unlock_schedule_segments(segments);
+            },
+        )
+    }
+
+    pub fn register_unlock_schedule(
+        &mut self,
+        schedule_id: UnlockScheduleId,
+        unlock_segments: Vec<(UnlockCurve, u64, u64, u64, u64)>,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut segments = MultiValueEncoded::new();
+                for segment in unlock_segments {
+                    segments.push(segment.into());
+                }
+
+                sc.register_unlock_schedule(schedule_id, segments);
+            },
+        )
+    }
+
     pub fn pause_contract(&mut self) {
         let _ = self.b_mock.execute_tx(
             &self.owner_address,
@@ -0,0 +1,369 @@
+//! Storage of a launchpad-guaranteed-tickets (v1) sale, recorded from the v1 contract
+//! before the upgrade to v2. Keys are split into their name and the hex encoded arguments.
+//!
+//! Participants, with 10 EGLD tickets and the minimum of 2 confirmed tickets for the staking guaranteed ticket:
+//! - 0: 1 staking ticket, confirmed
+//! - 1: 2 energy tickets and migrated tokens, confirmed
+//! - 2: 2 staking and 1 energy tickets and migrated tokens, confirmed
+//! - 3: 2 staking tickets, confirmed, then blacklisted
+//! - 4: 1 staking ticket, not confirmed
+
+pub struct V1Snapshot {
+    pub block_round: u64,
+    /// `(address, EGLD balance, launchpad token balance)`
+    pub balances: &'static [(&'static str, u64, u64)],
+    /// `(key name, hex key arguments, hex value)` of the launchpad
+    pub storage: &'static [(&'static str, &'static str, &'static str)],
+}
+
+pub fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+pub const V1_OWNER: &str = "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925";
+pub const V1_LAUNCHPAD: &str = "00000000000000001ba0460af9324ac6df5b6ffb66be6df2547872c2f29ba4c2";
+pub const V1_PARTICIPANTS: [&str; 5] = [
+    "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+    "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+    "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+    "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+    "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+];
+
+/// During the confirmation period, with the unlock schedule set
+pub const V1_CONFIRMATION_PERIOD_SNAPSHOT: V1Snapshot = V1Snapshot {
+    block_round: 5,
+    balances: &[
+        (
+            "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925",
+            0,
+            0,
+        ),
+        (
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            90,
+            0,
+        ),
+        (
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            80,
+            0,
+        ),
+        (
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            70,
+            0,
+        ),
+        (
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            100,
+            0,
+        ),
+        (
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+            100,
+            0,
+        ),
+        (
+            "00000000000000001ba0460af9324ac6df5b6ffb66be6df2547872c2f29ba4c2",
+            60,
+            600,
+        ),
+    ],
+    storage: &[
+        (
+            "blacklistUserTicketStatus",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            "00000002000000000000000100000000",
+        ),
+        (
+            "blacklisted",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            "01",
+        ),
+        (
+            "configuration",
+            "",
+            "0000000000000005000000000000000a000000000000000f",
+        ),
+        ("flags", "", "00000000"),
+        ("lastTicketId", "", "09"),
+        ("launchpadTokenId", "", "4c41554e43482d313233343536"),
+        ("launchpadTokensDeposited", "", "01"),
+        ("launchpadTokensPerWinningTicket", "", "64"),
+        ("minConfirmedForGuaranteedTicket", "", "02"),
+        (
+            "nrConfirmedTickets",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "02",
+        ),
+        (
+            "nrConfirmedTickets",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "01",
+        ),
+        (
+            "nrConfirmedTickets",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "03",
+        ),
+        ("nrWinningTickets", "", "03"),
+        (
+            "supportAddress",
+            "",
+            "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925",
+        ),
+        (
+            "ticketBatch",
+            "00000001",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e00000001",
+        ),
+        (
+            "ticketBatch",
+            "00000002",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d700000002",
+        ),
+        (
+            "ticketBatch",
+            "00000004",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb00000003",
+        ),
+        (
+            "ticketBatch",
+            "00000007",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b00000002",
+        ),
+        (
+            "ticketBatch",
+            "00000009",
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e00000001",
+        ),
+        ("ticketPrice", "", "0000000445474c44000000010a"),
+        (
+            "ticketRangeForAddress",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "0000000200000003",
+        ),
+        (
+            "ticketRangeForAddress",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "0000000100000001",
+        ),
+        (
+            "ticketRangeForAddress",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            "0000000700000008",
+        ),
+        (
+            "ticketRangeForAddress",
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+            "0000000900000009",
+        ),
+        (
+            "ticketRangeForAddress",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "0000000400000006",
+        ),
+        ("totalGuaranteedTickets", "", "03"),
+        ("totalLaunchpadTokensDeposited", "", "0258"),
+        (
+            "unlockSchedule",
+            "",
+            "000000000000000f0000000000001388000000000000000200000000000009c4000000000000000a",
+        ),
+        (
+            "userTicketStatus",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "00000000000000020000000000000001",
+        ),
+        (
+            "userTicketStatus",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "00000001000000000000000000000000",
+        ),
+        (
+            "userTicketStatus",
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+            "00000001000000000000000000000000",
+        ),
+        (
+            "userTicketStatus",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "00000002000000010000000100000001",
+        ),
+        (
+            "usersWithGuaranteedTicket.index",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "01",
+        ),
+        (
+            "usersWithGuaranteedTicket.index",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "02",
+        ),
+        (
+            "usersWithGuaranteedTicket.item",
+            "00000001",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+        ),
+        (
+            "usersWithGuaranteedTicket.item",
+            "00000002",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+        ),
+        ("usersWithGuaranteedTicket.len", "", "02"),
+    ],
+};
+
+/// At the start of the claim period, after the winner selection and the claim of participant 2
+pub const V1_CLAIM_PERIOD_SNAPSHOT: V1Snapshot = V1Snapshot {
+    block_round: 15,
+    balances: &[
+        (
+            "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925",
+            0,
+            0,
+        ),
+        (
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            90,
+            0,
+        ),
+        (
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            80,
+            0,
+        ),
+        (
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            70,
+            150,
+        ),
+        (
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            100,
+            0,
+        ),
+        (
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+            100,
+            0,
+        ),
+        (
+            "00000000000000001ba0460af9324ac6df5b6ffb66be6df2547872c2f29ba4c2",
+            60,
+            450,
+        ),
+    ],
+    storage: &[
+        (
+            "blacklistUserTicketStatus",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            "00000002000000000000000100000000",
+        ),
+        (
+            "blacklisted",
+            "376da11fe3ab3d0eaaddb418ccb49b5426d5c2504f526f7766580f6e45984e3b",
+            "01",
+        ),
+        ("claimableTicketPayment", "", "3c"),
+        (
+            "claimedTokens",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "01",
+        ),
+        (
+            "configuration",
+            "",
+            "0000000000000005000000000000000a000000000000000f",
+        ),
+        ("flags", "", "01010101"),
+        ("lastTicketId", "", "06"),
+        ("launchpadTokenId", "", "4c41554e43482d313233343536"),
+        ("launchpadTokensDeposited", "", "01"),
+        ("launchpadTokensPerWinningTicket", "", "64"),
+        ("minConfirmedForGuaranteedTicket", "", "02"),
+        (
+            "nrConfirmedTickets",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "02",
+        ),
+        (
+            "nrConfirmedTickets",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "01",
+        ),
+        ("nrWinningTickets", "", "03"),
+        (
+            "supportAddress",
+            "",
+            "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925",
+        ),
+        (
+            "ticketBatch",
+            "00000001",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e00000001",
+        ),
+        (
+            "ticketBatch",
+            "00000002",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d700000002",
+        ),
+        ("ticketPosToId", "00000001", "01"),
+        ("ticketPosToId", "00000003", "02"),
+        ("ticketPrice", "", "0000000445474c44000000010a"),
+        (
+            "ticketRangeForAddress",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "0000000200000003",
+        ),
+        (
+            "ticketRangeForAddress",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "0000000100000001",
+        ),
+        ("ticketStatus", "00000001", "01"),
+        ("ticketStatus", "00000002", "01"),
+        ("ticketStatus", "00000003", "01"),
+        ("totalGuaranteedTickets", "", "03"),
+        ("totalLaunchpadTokensDeposited", "", "0258"),
+        (
+            "unlockSchedule",
+            "",
+            "000000000000000f0000000000001388000000000000000200000000000009c4000000000000000a",
+        ),
+        (
+            "userClaimedBalance",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "96",
+        ),
+        (
+            "userTicketStatus",
+            "12771355e46cd47c71ed1721fd5319b383cca3a1f9fce3aa1c8cd3bd37af20d7",
+            "00000000000000020000000000000001",
+        ),
+        (
+            "userTicketStatus",
+            "2b32db6c2c0a6235fb1397e8225ea85e0f0e6e8c7b126d0016ccbde0e667151e",
+            "00000001000000000000000000000000",
+        ),
+        (
+            "userTicketStatus",
+            "4391a5c79ffdc79883036503ca551673c09deec28df432a8d88debc7fa2ec91e",
+            "00000001000000000000000000000000",
+        ),
+        (
+            "userTicketStatus",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "00000002000000010000000100000001",
+        ),
+        (
+            "userTotalClaimableBalance",
+            "fe15c0d3ebe314fad720a08b839a004c2e6386f5aecc19ec74807d1920cb6aeb",
+            "012c",
+        ),
+    ],
+};
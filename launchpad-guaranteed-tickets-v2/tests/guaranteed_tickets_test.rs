@@ -3,13 +3,25 @@
 mod guaranteed_tickets_setup;
 
 use guaranteed_tickets_setup::{
+    v1_storage::{V1_CLAIM_PERIOD_SNAPSHOT, V1_CONFIRMATION_PERIOD_SNAPSHOT},
     LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
-    LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
+    LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, NR_LAUNCHPAD_PARTICIPANTS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
 };
 use launchpad_common::{
-    config::ConfigModule,
+    blacklist::BlacklistModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID, MIN_CLAIM_PERIOD_ROUNDS},
+    launch_stage::{LaunchStage, LaunchStageModule},
+    launchpad_stats::{LaunchpadStats, LaunchpadStatsModule},
+    ongoing_operation::{OngoingOperationModule, OngoingOperationType},
+    operation_progress::{OperationPhase, OperationProgress},
+    permissions::PermissionsModule,
+    random::Random,
     setup::SetupModule,
-    tickets::{TicketsModule, WINNING_TICKET},
+    storage_cleanup::StorageCleanupModule,
+    tickets::{TicketBatchInfo, TicketRange, TicketsModule, WINNING_TICKET},
+    user_info::{UserInfo, UserNftStatus},
+    user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
 use launchpad_guaranteed_tickets_v2::{
@@ -17,13 +29,23 @@ use launchpad_guaranteed_tickets_v2::{
         GuaranteedTicketWinnersModule, GuaranteedTicketsSelectionOperation,
     },
     guaranteed_tickets_init::GuaranteedTicketsInitModule,
-    token_release::TokenReleaseModule,
+    proceeds_escrow::ProceedsEscrowModule,
+    storage_migration::StorageMigrationModule,
+    token_release::{TokenReleaseModule, UnlockCurve},
+    vesting_position::{VestingPositionAttributes, VestingPositionModule},
     LaunchpadGuaranteedTickets,
 };
+use launchpad_test_support::assert_events;
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::storage::mappers::StorageTokenWrapper;
 use multiversx_sc::types::{
-    EgldOrEsdtTokenIdentifier, MultiValueEncoded, MultiValueEncodedCounted,
+    EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedVec, MultiValueEncoded,
+    MultiValueEncodedCounted,
+};
+use multiversx_sc_modules::pause::PauseModule;
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi,
 };
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
 
 use crate::guaranteed_tickets_setup::NR_WINNING_TICKETS;
 
@@ -60,27 +82,45 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
         })
         .assert_ok();
 
@@ -90,27 +130,48 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 1
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
         })
         .assert_ok();
 
@@ -178,26 +239,44 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
         })
         .assert_ok();
@@ -208,26 +287,47 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 2
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
         .assert_ok();
@@ -302,15 +402,21 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 2
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
         })
         .assert_ok();
@@ -329,13 +435,19 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -345,13 +457,22 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -443,19 +564,22 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -480,16 +604,28 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                ); // randomly selected -> leftover_ticket
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                ); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -498,16 +634,31 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(),
+                    WINNING_TICKET
+                ); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -837,6 +988,7 @@ fn condition_checks_test() {
                 sc.set_ticket_price(
                     EgldOrEsdtTokenIdentifier::egld(),
                     managed_biguint!(TICKET_COST),
+                    OptionalValue::None,
                 );
             },
         )
@@ -1007,7 +1159,7 @@ fn blacklist_scenario_test() {
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -1050,7 +1202,7 @@ fn blacklist_scenario_test() {
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             let blacklisted_user_guaranteed_tickets = 1;
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -1132,13 +1284,16 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1149,12 +1304,24 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1321,7 +1488,10 @@ fn remove_from_blacklist_underflow_and_empty_storage_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.nr_winning_tickets().get(), nr_winning_tickets);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                nr_winning_tickets
+            );
         })
         .assert_ok();
 
@@ -1638,13 +1808,98 @@ fn vesting_with_four_milestones_test() {
 }
 
 #[test]
-fn unlock_milestones_wrong_step_and_order_test() {
+fn vesting_with_cliff_linear_and_step_segments_test() {
+    let nr_winning_tickets = 1;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    // steps need at least one round each, and segments may not overlap
+    lp_setup
+        .set_unlock_schedule_segments(vec![(UnlockCurve::Step, 15, 17, 3, 10_000)])
+        .assert_user_error("Invalid unlock schedule");
+    lp_setup
+        .set_unlock_schedule_segments(vec![
+            (UnlockCurve::Linear, 15, 22, 0, 5_000),
+            (UnlockCurve::Linear, 20, 25, 0, 5_000),
+        ])
+        .assert_user_error("Invalid unlock schedule");
+
+    // 10% at round 15, 60% linearly over rounds 15-22, and 30% in 3 steps over rounds 22-31
+    lp_setup
+        .set_unlock_schedule_segments(vec![
+            (UnlockCurve::Cliff, 15, 15, 0, 1_000),
+            (UnlockCurve::Linear, 15, 22, 0, 6_000),
+            (UnlockCurve::Step, 22, 31, 3, 3_000),
+        ])
+        .assert_ok();
+    let participant = &lp_setup.participants[0].clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(participant, 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // repeated claims only ever get what the schedule released so far, rounded down once
+    let expected_balances = [
+        (15, 10),
+        (16, 18),
+        (17, 27),
+        (18, 35),
+        (19, 44),
+        (20, 52),
+        (21, 61),
+        (22, 70),
+        (24, 70),
+        (25, 80),
+        (28, 90),
+        (30, 90),
+        (31, 100),
+    ];
+    for (round, expected_balance) in expected_balances {
+        lp_setup.b_mock.set_block_round(round);
+        lp_setup.claim_user(participant).assert_ok();
+        lp_setup.b_mock.check_esdt_balance(
+            participant,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(expected_balance),
+        );
+    }
+
+    lp_setup
+        .claim_user(participant)
+        .assert_error(4, "Already claimed all tokens");
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn unlock_schedules_per_user_and_category_test() {
     let mut lp_setup = LaunchpadSetup::new(
         NR_WINNING_TICKETS,
         launchpad_guaranteed_tickets_v2::contract_obj,
     );
+    let participants = lp_setup.participants.clone();
+    lp_setup.set_unlock_schedule(vec![(CLAIM_START_ROUND, 10_000)]);
 
-    let unlock_milestones = vec![(10, 3000), (30, 3000), (20, 4000)];
+    lp_setup
+        .register_unlock_schedule(0, vec![(UnlockCurve::Cliff, 30, 30, 0, 10_000)])
+        .assert_user_error("Invalid unlock schedule ID");
+    lp_setup
+        .register_unlock_schedule(1, vec![(UnlockCurve::Linear, 15, 25, 0, 10_000)])
+        .assert_ok();
+    lp_setup
+        .register_unlock_schedule(2, vec![(UnlockCurve::Cliff, 30, 30, 0, 10_000)])
+        .assert_ok();
 
     lp_setup
         .b_mock
@@ -1653,18 +1908,10 @@ fn unlock_milestones_wrong_step_and_order_test() {
             &lp_setup.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let mut unlock_schedule = MultiValueEncoded::new();
-                for milestone in unlock_milestones.clone() {
-                    unlock_schedule.push(milestone.into());
-                }
-                sc.set_unlock_schedule(unlock_schedule);
+                sc.set_guaranteed_tickets_unlock_schedule(3);
             },
         )
-        .assert_user_error("Invalid unlock schedule");
-
-    // Retry after setup period has passed
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
-
+        .assert_user_error("Unknown unlock schedule");
     lp_setup
         .b_mock
         .execute_tx(
@@ -1672,48 +1919,12 @@ fn unlock_milestones_wrong_step_and_order_test() {
             &lp_setup.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let mut unlock_schedule = MultiValueEncoded::new();
-                for milestone in unlock_milestones {
-                    unlock_schedule.push(milestone.into());
-                }
-                sc.set_unlock_schedule(unlock_schedule);
+                sc.add_tickets_with_unlock_schedule_endpoint(3, MultiValueEncoded::new());
             },
         )
-        .assert_user_error("Add tickets period has passed");
-}
-
-#[test]
-fn multiple_guaranteed_tickets_test() {
-    let nr_winning_tickets = 9;
-    let mut lp_setup = LaunchpadSetup::new(
-        nr_winning_tickets,
-        launchpad_guaranteed_tickets_v2::contract_obj,
-    );
-
-    // Set unlock schedule: 100% release immediately
-    let unlock_milestones = vec![(0, 10000)];
-    lp_setup.set_unlock_schedule(unlock_milestones);
-
-    let mut participants = lp_setup.participants.clone();
-
-    let new_participant = lp_setup
-        .b_mock
-        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64));
-    participants.push(new_participant.clone());
+        .assert_user_error("Unknown unlock schedule");
 
-    let second_new_participant = lp_setup
-        .b_mock
-        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 2));
-    participants.push(second_new_participant.clone());
-
-    let third_new_participant = lp_setup
-        .b_mock
-        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 3));
-    participants.push(third_new_participant.clone());
-
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
-
-    // Set guaranteed tickets for users
+    // the guaranteed tickets winners vest linearly, and the second user is assigned the cliff
     lp_setup
         .b_mock
         .execute_tx(
@@ -1721,244 +1932,2104 @@ fn multiple_guaranteed_tickets_test() {
             &lp_setup.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let mut args = MultiValueEncoded::new();
-                // New participant: 1 guaranteed ticket if confirms 2
+                sc.set_guaranteed_tickets_unlock_schedule(1);
+
                 let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
-                guaranteed_tickets_info.push((1, 2).into());
+                guaranteed_tickets_info.push((1, 3).into());
+                let mut args = MultiValueEncoded::new();
                 args.push(
                     (
-                        managed_address!(&new_participant),
-                        2,
+                        managed_address!(&participants[2]),
+                        MAX_TIER_TICKETS,
                         guaranteed_tickets_info,
                     )
                         .into(),
                 );
-                // Second new participant: complex guaranteed tickets structure
-                let mut guaranteed_tickets_info2 = MultiValueEncodedCounted::new();
-                guaranteed_tickets_info2.push((1, 6).into());
-                guaranteed_tickets_info2.push((2, 4).into());
-                guaranteed_tickets_info2.push((3, 3).into());
-                args.push(
-                    (
-                        managed_address!(&second_new_participant),
-                        6,
-                        guaranteed_tickets_info2,
-                    )
-                        .into(),
-                );
+                sc.set_ticket_allowances_endpoint(args);
+
+                let mut args = MultiValueEncoded::new();
                 args.push(
                     (
-                        managed_address!(&participants[5]),
-                        9,
+                        managed_address!(&participants[1]),
+                        2,
                         MultiValueEncodedCounted::new(),
                     )
                         .into(),
                 );
-                sc.add_tickets_endpoint(args);
+                sc.set_ticket_allowances_with_unlock_schedule_endpoint(2, args);
+
+                assert_eq!(
+                    sc.user_unlock_schedule_id(&managed_address!(&participants[0]))
+                        .get(),
+                    0
+                );
+                assert_eq!(
+                    sc.user_unlock_schedule_id(&managed_address!(&participants[1]))
+                        .get(),
+                    2
+                );
+                assert_eq!(
+                    sc.user_unlock_schedule_id(&managed_address!(&participants[2]))
+                        .get(),
+                    1
+                );
             },
         )
         .assert_ok();
 
-    // Confirm tickets
     lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
-    lp_setup.confirm(&participants[0], 1).assert_ok();
-    lp_setup.confirm(&participants[1], 2).assert_ok();
-    lp_setup.confirm(&participants[2], 3).assert_ok();
-    lp_setup.confirm(&participants[3], 2).assert_ok();
-    lp_setup.confirm(&participants[4], 4).assert_ok();
-    lp_setup.confirm(&participants[5], 9).assert_ok();
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
 
-    // Set round to winner selection
     lp_setup
         .b_mock
         .set_block_round(WINNER_SELECTION_START_ROUND);
-
-    // Filter tickets and select winners
     lp_setup.filter_tickets().assert_ok();
-    lp_setup.select_winners().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
     lp_setup.distribute_tickets().assert_ok();
 
-    // Set round to claim period
-    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
-
-    // Claim for all users
-    for participant in participants.iter().take(6) {
-        lp_setup.claim_user(participant).assert_ok();
+    // every user won one ticket, and claims until all of it is released
+    let expected_balances = [
+        (CLAIM_START_ROUND, [100, 0, 0]),
+        (20, [100, 0, 50]),
+        (25, [100, 0, 100]),
+        (30, [100, 100, 100]),
+    ];
+    let mut claimed_balances = [0u64; NR_LAUNCHPAD_PARTICIPANTS];
+    for (round, balances) in expected_balances {
+        lp_setup.b_mock.set_block_round(round);
+        for (i, participant) in participants.iter().enumerate() {
+            if claimed_balances[i] == LAUNCHPAD_TOKENS_PER_TICKET {
+                continue;
+            }
+
+            lp_setup.claim_user(participant).assert_ok();
+            lp_setup.b_mock.check_esdt_balance(
+                participant,
+                LAUNCHPAD_TOKEN_ID,
+                &rust_biguint!(balances[i]),
+            );
+            claimed_balances[i] = balances[i];
+        }
     }
 
-    // Check balances
-    // First user: 1 ticket, no guarantee, 1 winning ticket
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[0], &rust_biguint!(3 * TICKET_COST));
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            for participant in &participants {
+                assert_eq!(
+                    sc.user_claimed_balance(&managed_address!(participant))
+                        .get(),
+                    managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+                );
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn vesting_position_test() {
+    DebugApi::dummy();
+    static POSITION_TOKEN_ID: &[u8] = b"VEST-123456";
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
     lp_setup
-        .b_mock
-        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+        .set_unlock_schedule_segments(vec![
+            (UnlockCurve::Cliff, 15, 15, 0, 2_500),
+            (UnlockCurve::Linear, 15, 25, 0, 7_500),
+        ])
+        .assert_ok();
 
-    // Second user: 2 tickets, no guarantee, no winning tickets
+    lp_setup.b_mock.set_esdt_local_roles(
+        lp_setup.lp_wrapper.address_ref(),
+        POSITION_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[1], &rust_biguint!(3 * TICKET_COST));
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vesting_position_token()
+                    .set_token_id(managed_token_id!(POSITION_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
     lp_setup
         .b_mock
-        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
 
-    // Third user: 3 tickets, 1 guaranteed, one winning ticket
+    // the first claim sends the released 25%, and the rest is held by the position
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[2], &rust_biguint!(2 * TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(25));
+    lp_setup.b_mock.check_nft_balance(
+        &participants[0],
+        POSITION_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        Some(&VestingPositionAttributes::<DebugApi> {
+            total_amount: managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+            claimed_amount: managed_biguint!(25),
+            unlock_schedule_id: 0,
+        }),
+    );
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let user = managed_address!(&participants[0]);
+            assert!(sc.user_total_claimable_balance(&user).is_empty());
+            assert!(sc.user_claimed_balance(&user).is_empty());
+        })
+        .assert_ok();
+
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(25));
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            POSITION_TOKEN_ID,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.redeem_vesting_position();
+            },
+        )
+        .assert_user_error("No tokens released yet");
+    lp_setup
+        .b_mock
+        .set_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(25));
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(25),
+            |sc| {
+                sc.redeem_vesting_position();
+            },
+        )
+        .assert_user_error("Invalid payment token");
+
+    // the position moves to the second user, who redeems it over time
+    let attributes = VestingPositionAttributes::<DebugApi> {
+        total_amount: managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        claimed_amount: managed_biguint!(25),
+        unlock_schedule_id: 0,
+    };
+    lp_setup.b_mock.set_nft_balance(
+        &participants[0],
+        POSITION_TOKEN_ID,
+        1,
+        &rust_biguint!(0),
+        &attributes,
+    );
+    lp_setup.b_mock.set_nft_balance(
+        &participants[1],
+        POSITION_TOKEN_ID,
+        1,
+        &rust_biguint!(1),
+        &attributes,
+    );
+
+    lp_setup.b_mock.set_block_round(20);
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            POSITION_TOKEN_ID,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.redeem_vesting_position();
+            },
+        )
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(37));
+    lp_setup
+        .b_mock
+        .check_nft_balance::<VestingPositionAttributes<DebugApi>>(
+            &participants[1],
+            POSITION_TOKEN_ID,
+            1,
+            &rust_biguint!(0),
+            None,
+        );
+    lp_setup.b_mock.check_nft_balance(
+        &participants[1],
+        POSITION_TOKEN_ID,
+        2,
+        &rust_biguint!(1),
+        Some(&VestingPositionAttributes::<DebugApi> {
+            total_amount: managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+            claimed_amount: managed_biguint!(62),
+            unlock_schedule_id: 0,
+        }),
+    );
+
+    // the last redemption sends the rest, without a new position
+    lp_setup.b_mock.set_block_round(25);
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            POSITION_TOKEN_ID,
+            2,
+            &rust_biguint!(1),
+            |sc| {
+                sc.redeem_vesting_position();
+            },
+        )
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(75));
+    lp_setup
+        .b_mock
+        .check_nft_balance::<VestingPositionAttributes<DebugApi>>(
+            &participants[1],
+            POSITION_TOKEN_ID,
+            2,
+            &rust_biguint!(0),
+            None,
+        );
+    lp_setup
+        .b_mock
+        .check_nft_balance::<VestingPositionAttributes<DebugApi>>(
+            lp_setup.lp_wrapper.address_ref(),
+            POSITION_TOKEN_ID,
+            3,
+            &rust_biguint!(0),
+            None,
+        );
+
+    // the second user's own allocation is released at once, so no position is minted
+    lp_setup.claim_user(&participants[1]).assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(175));
+    lp_setup
+        .b_mock
+        .check_nft_balance::<VestingPositionAttributes<DebugApi>>(
+            &participants[1],
+            POSITION_TOKEN_ID,
+            3,
+            &rust_biguint!(0),
+            None,
+        );
+}
+
+#[test]
+fn push_releases_skips_users_without_tickets_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let owner_address = lp_setup.owner_address.clone();
+    let participants = lp_setup.participants.clone();
+    lp_setup.set_unlock_schedule(vec![(CLAIM_START_ROUND, 10_000)]);
+
+    // the second user never confirms, so their tickets are filtered out
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.push_releases(&owner_address).assert_ok();
+
+    let mut total_claimed = rust_biguint!(0);
+    for p in [&participants[0], &participants[2]] {
+        total_claimed += lp_setup.b_mock.get_esdt_balance(p, LAUNCHPAD_TOKEN_ID, 0);
+    }
+    assert_eq!(
+        total_claimed,
+        rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64)
+    );
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let claim_list = sc.claim_list(FIRST_SALE_ROUND_ID);
+            assert!(claim_list.contains(&managed_address!(&participants[0])));
+            assert!(!claim_list.contains(&managed_address!(&participants[1])));
+            assert!(claim_list.contains(&managed_address!(&participants[2])));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn push_releases_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let owner_address = lp_setup.owner_address.clone();
+    let participants = lp_setup.participants.clone();
+    let support_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup
+        .set_unlock_schedule_segments(vec![
+            (UnlockCurve::Cliff, 15, 15, 0, 2_500),
+            (UnlockCurve::Linear, 15, 25, 0, 7_500),
+        ])
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // the third user never claims, so their results are computed by the push
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup.claim_user(&participants[1]).assert_ok();
+
+    lp_setup
+        .push_releases(&support_address)
+        .assert_user_error("Permission denied");
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_support_address(managed_address!(&support_address));
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(20);
+    let result = lp_setup.push_releases(&support_address);
+    result.assert_ok();
+    assert_events(
+        &result,
+        &[
+            "pushRelease",
+            "pushRelease",
+            "launchpadResultsComputed",
+            "refundTicketPayment",
+            "pushRelease",
+        ],
+    );
+    for p in participants.iter() {
+        lp_setup
+            .b_mock
+            .check_esdt_balance(p, LAUNCHPAD_TOKEN_ID, &rust_biguint!(62));
+    }
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert!(sc
+                .claim_list(FIRST_SALE_ROUND_ID)
+                .contains(&managed_address!(&participants[2])));
+        })
+        .assert_ok();
+
+    // nothing was released since the push
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(62));
+
+    // simulate a push interrupted after the first user
+    lp_setup.b_mock.set_block_round(25);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.current_ongoing_operation()
+                    .set(OngoingOperationType::PushReleases {
+                        user_index: 2,
+                        nr_users: 6,
+                    });
+            },
+        )
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::new(OperationPhase::PushReleases, 1, 5)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.push_releases(&owner_address).assert_ok();
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(62));
+    for p in participants.iter().skip(1) {
+        lp_setup.b_mock.check_esdt_balance(
+            p,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        );
+    }
+
+    // fully claimed users are skipped
+    let result = lp_setup.push_releases(&support_address);
+    result.assert_ok();
+    assert_events(&result, &["pushRelease"]);
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.user_claimed_balance(&managed_address!(&participants[1]))
+                    .get(),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+            assert!(sc.current_ongoing_operation().is_empty());
+        })
+        .assert_ok();
+
+    lp_setup.pause_contract();
+    lp_setup
+        .push_releases(&support_address)
+        .assert_user_error("Contract is paused");
+}
+
+#[test]
+fn proceeds_escrow_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let owner_address = lp_setup.owner_address.clone();
+    let participants = lp_setup.participants.clone();
+    let approver = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let other_approver = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let winner_balance = rust_biguint!(TICKET_COST * (MAX_TIER_TICKETS as u64 - 1));
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push(3_000);
+                milestones.push(3_000);
+                sc.set_proceeds_escrow(managed_address!(&approver), 5_000, milestones);
+            },
+        )
+        .assert_user_error("Escrow milestones must add up to 100%");
+
+    // the project may not approve its own milestones
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push(10_000);
+                sc.set_proceeds_escrow(managed_address!(&owner_address), 5_000, milestones);
+            },
+        )
+        .assert_user_error("Escrow approver must differ from the owner");
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push(3_000);
+                milestones.push(3_000);
+                milestones.push(4_000);
+                sc.set_proceeds_escrow(managed_address!(&approver), 5_000, milestones);
+            },
+        )
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push(10_000);
+                sc.set_proceeds_escrow(managed_address!(&other_approver), 5_000, milestones);
+            },
+        )
+        .assert_user_error("Escrow approver cannot be changed");
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // nothing is released before the first milestone is approved
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(0));
+
+    // changing the support address does not change the approver
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_support_address(managed_address!(&owner_address));
+                sc.approve_escrow_milestone();
+            },
+        )
+        .assert_user_error("Permission denied");
+    lp_setup
+        .b_mock
+        .execute_tx(&approver, &lp_setup.lp_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_escrow_milestone();
+        })
+        .assert_ok();
+    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(9));
+
+    // the second milestone is approved by the winners, once half of the tickets voted
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &other_approver,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vote_escrow_milestone();
+            },
+        )
+        .assert_user_error("Only winners may vote");
+
+    // winners vote whether or not they claimed
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    let result = lp_setup.b_mock.execute_tx(
+        &participants[0],
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.vote_escrow_milestone();
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["voteEscrowMilestone"]);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vote_escrow_milestone();
+            },
+        )
+        .assert_user_error("Already voted");
+
+    let result = lp_setup.b_mock.execute_tx(
+        &participants[1],
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.vote_escrow_milestone();
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["voteEscrowMilestone", "approveEscrowMilestone"]);
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.nr_approved_escrow_milestones().get(), 2);
+            assert_eq!(sc.escrow_milestone_votes(1).get(), 2);
+        })
+        .assert_ok();
+
+    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(18));
+
+    // the last 40% is refunded to the winners, as one third each
+    lp_setup
+        .b_mock
+        .execute_tx(&approver, &lp_setup.lp_wrapper, &rust_biguint!(0), |sc| {
+            sc.declare_project_failed();
+        })
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(&approver, &lp_setup.lp_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve_escrow_milestone();
+        })
+        .assert_user_error("Project declared failed");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_proceeds_refund();
+            },
+        )
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[0], &(&winner_balance + 4u32));
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_proceeds_refund();
+            },
+        )
+        .assert_user_error("Nothing to refund");
+
+    // the last winner is refunded before claiming, and only once
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_proceeds_refund();
+            },
+        )
+        .assert_ok();
+    lp_setup.claim_user(&participants[2]).assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_proceeds_refund();
+            },
+        )
+        .assert_user_error("Nothing to refund");
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &(&winner_balance + 4u32));
+
+    // the owner keeps the released proceeds only
+    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(18));
+}
+
+#[test]
+fn unlock_milestones_wrong_step_and_order_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    let unlock_milestones = vec![(10, 3000), (30, 3000), (20, 4000)];
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut unlock_schedule = MultiValueEncoded::new();
+                for milestone in unlock_milestones.clone() {
+                    unlock_schedule.push(milestone.into());
+                }
+                sc.set_unlock_schedule(unlock_schedule);
+            },
+        )
+        .assert_user_error("Invalid unlock schedule");
+
+    // Retry after setup period has passed
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut unlock_schedule = MultiValueEncoded::new();
+                for milestone in unlock_milestones {
+                    unlock_schedule.push(milestone.into());
+                }
+                sc.set_unlock_schedule(unlock_schedule);
+            },
+        )
+        .assert_user_error("Add tickets period has passed");
+}
+
+#[test]
+fn multiple_guaranteed_tickets_test() {
+    let nr_winning_tickets = 9;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    // Set unlock schedule: 100% release immediately
+    let unlock_milestones = vec![(0, 10000)];
+    lp_setup.set_unlock_schedule(unlock_milestones);
+
+    let mut participants = lp_setup.participants.clone();
+
+    let new_participant = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64));
+    participants.push(new_participant.clone());
+
+    let second_new_participant = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 2));
+    participants.push(second_new_participant.clone());
+
+    let third_new_participant = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 3));
+    participants.push(third_new_participant.clone());
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+
+    // Set guaranteed tickets for users
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                // New participant: 1 guaranteed ticket if confirms 2
+                let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
+                guaranteed_tickets_info.push((1, 2).into());
+                args.push(
+                    (
+                        managed_address!(&new_participant),
+                        2,
+                        guaranteed_tickets_info,
+                    )
+                        .into(),
+                );
+                // Second new participant: complex guaranteed tickets structure
+                let mut guaranteed_tickets_info2 = MultiValueEncodedCounted::new();
+                guaranteed_tickets_info2.push((1, 6).into());
+                guaranteed_tickets_info2.push((2, 4).into());
+                guaranteed_tickets_info2.push((3, 3).into());
+                args.push(
+                    (
+                        managed_address!(&second_new_participant),
+                        6,
+                        guaranteed_tickets_info2,
+                    )
+                        .into(),
+                );
+                args.push(
+                    (
+                        managed_address!(&participants[5]),
+                        9,
+                        MultiValueEncodedCounted::new(),
+                    )
+                        .into(),
+                );
+                sc.add_tickets_endpoint(args);
+            },
+        )
+        .assert_ok();
+
+    // Confirm tickets
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+    lp_setup.confirm(&participants[3], 2).assert_ok();
+    lp_setup.confirm(&participants[4], 4).assert_ok();
+    lp_setup.confirm(&participants[5], 9).assert_ok();
+
+    // Set round to winner selection
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // Filter tickets and select winners
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // Set round to claim period
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    // Claim for all users
+    for participant in participants.iter().take(6) {
+        lp_setup.claim_user(participant).assert_ok();
+    }
+
+    // Check balances
+    // First user: 1 ticket, no guarantee, 1 winning ticket
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[0], &rust_biguint!(3 * TICKET_COST));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+
+    // Second user: 2 tickets, no guarantee, no winning tickets
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &rust_biguint!(3 * TICKET_COST));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+
+    // Third user: 3 tickets, 1 guaranteed, one winning ticket
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(2 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // New participant: 2 tickets, 1 guaranteed, 1 winning ticket
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[3], &rust_biguint!(TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[3],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Second new participant: 4 tickets, 4 guaranteed (6 initial guaranteed), 4 winning tickets
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[4], &rust_biguint!(2 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[4],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(4 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Third new participant: 9 tickets, 0 guaranteed, 4 winning tickets
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[5], &rust_biguint!(7 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[5],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Owner claims
+    lp_setup.claim_owner().assert_ok();
+
+    // Check owner's balances
+    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 9 winning tickets
+
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0), // All tokens were distributed
+    );
+
+    // Check contract balances
+    lp_setup
+        .b_mock
+        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn set_ticket_allowances_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let unlock_milestones = vec![(0, 10000)];
+    lp_setup.set_unlock_schedule(unlock_milestones);
+    let participants = lp_setup.participants.clone();
+
+    // the guaranteed ticket moves from the third user to the first one,
+    // who also gets 2 more tickets, and the second user is removed
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+    let set_allowances_result = lp_setup.b_mock.execute_tx(
+        &lp_setup.owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+            let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
+            guaranteed_tickets_info.push((1, 3).into());
+            args.push(
+                (
+                    managed_address!(&participants[0]),
+                    MAX_TIER_TICKETS,
+                    guaranteed_tickets_info,
+                )
+                    .into(),
+            );
+            args.push(
+                (
+                    managed_address!(&participants[1]),
+                    0,
+                    MultiValueEncodedCounted::new(),
+                )
+                    .into(),
+            );
+            args.push(
+                (
+                    managed_address!(&participants[2]),
+                    MAX_TIER_TICKETS,
+                    MultiValueEncodedCounted::new(),
+                )
+                    .into(),
+            );
+            sc.set_ticket_allowances_endpoint(args);
+
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
+            assert_eq!(sc.total_guaranteed_tickets().get(), 1);
+            assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
+            assert!(sc
+                .users_with_guaranteed_ticket()
+                .contains(&managed_address!(&participants[0])));
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    &managed_address!(&participants[0])
+                ),
+                MAX_TIER_TICKETS
+            );
+            assert!(sc
+                .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &managed_address!(&participants[1]))
+                .is_empty());
+            assert!(sc
+                .user_ticket_status(&managed_address!(&participants[1]))
+                .is_empty());
+        },
+    );
+    set_allowances_result.assert_ok();
+    assert_events(
+        &set_allowances_result,
+        &[
+            "setUserTicketAllowance",
+            "setUserTicketAllowance",
+            "setUserTicketAllowance",
+        ],
+    );
+
+    // blacklisted guaranteed users must be removed from the blacklist first
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut blacklist = MultiValueEncoded::new();
+                blacklist.push(managed_address!(&participants[0]));
+                sc.add_users_to_blacklist_endpoint(blacklist);
+
+                let mut args = MultiValueEncoded::new();
+                args.push(
+                    (
+                        managed_address!(&participants[0]),
+                        1,
+                        MultiValueEncodedCounted::new(),
+                    )
+                        .into(),
+                );
+                sc.set_ticket_allowances_endpoint(args);
+            },
+        )
+        .assert_user_error("Cannot change the allowance of a blacklisted user");
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_ticket_allowances_endpoint(MultiValueEncoded::new());
+            },
+        )
+        .assert_user_error("Add tickets period has passed");
+
+    lp_setup
+        .confirm(&participants[0], MAX_TIER_TICKETS)
+        .assert_ok();
+    lp_setup
+        .confirm(&participants[1], 1)
+        .assert_user_error("Trying to confirm too many tickets");
+    lp_setup
+        .confirm(&participants[2], MAX_TIER_TICKETS)
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    let mut nr_winning_tickets_per_user = Vec::new();
+    for p in [&participants[0], &participants[2]] {
+        lp_setup
+            .b_mock
+            .execute_query(&lp_setup.lp_wrapper, |sc| {
+                nr_winning_tickets_per_user.push(
+                    sc.get_winning_ticket_ids_for_address(FIRST_SALE_ROUND_ID, managed_address!(p))
+                        .len(),
+                );
+            })
+            .assert_ok();
+        lp_setup.claim_user(p).assert_ok();
+    }
+
+    // the guaranteed ticket is won no matter the base selection
+    assert!(nr_winning_tickets_per_user[0] >= 1);
+    assert_eq!(
+        nr_winning_tickets_per_user.iter().sum::<usize>(),
+        NR_WINNING_TICKETS
+    );
+    lp_setup.b_mock.check_egld_balance(
+        &participants[1],
+        &rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64),
+    );
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn contract_pause_test() {
+    let nr_winning_tickets = 1;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let unlock_milestones = vec![(0, 10000)];
+    lp_setup.set_unlock_schedule(unlock_milestones);
+    let participant = &lp_setup.participants[0].clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    // Check pause functionality
+    lp_setup.pause_contract();
+    lp_setup
+        .confirm(participant, 1)
+        .assert_error(4, "Contract is paused");
+    lp_setup.unpause_contract();
+
+    lp_setup.confirm(participant, 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // Check pause functionality
+    lp_setup.pause_contract();
+    lp_setup
+        .filter_tickets()
+        .assert_error(4, "Contract is paused");
+    lp_setup.unpause_contract();
+
+    lp_setup.filter_tickets().assert_ok();
+
+    // Check pause functionality
+    lp_setup.pause_contract();
+    lp_setup
+        .select_winners()
+        .assert_error(4, "Contract is paused");
+    lp_setup.unpause_contract();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+
+    // Check pause functionality
+    lp_setup.pause_contract();
+    lp_setup
+        .distribute_tickets()
+        .assert_error(4, "Contract is paused");
+    lp_setup.unpause_contract();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    // Check pause functionality
+    lp_setup.pause_contract();
+    lp_setup
+        .claim_user(participant)
+        .assert_error(4, "Contract is paused");
+    lp_setup.unpause_contract();
+
+    lp_setup.claim_user(participant).assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        participant,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
+
+#[test]
+fn fair_chance_allocation_and_ticket_redistribution_test() {
+    let nr_winning_tickets = 9;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    // Set unlock schedule: 100% release immediately
+    let unlock_milestones = vec![(0, 10000)];
+    lp_setup.set_unlock_schedule(unlock_milestones);
+
+    let mut participants = lp_setup.participants.clone();
+
+    let new_participant = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 2));
+    participants.push(new_participant.clone());
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+
+    // Set guaranteed tickets for users
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                // New participant with complex structure
+                let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
+                guaranteed_tickets_info.push((1, 6).into());
+                guaranteed_tickets_info.push((2, 4).into());
+                guaranteed_tickets_info.push((3, 3).into());
+                args.push(
+                    (
+                        managed_address!(&new_participant),
+                        6,
+                        guaranteed_tickets_info,
+                    )
+                        .into(),
+                );
+
+                sc.add_tickets_endpoint(args);
+            },
+        )
+        .assert_ok();
+
+    // Confirm tickets
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+    lp_setup.confirm(&participants[3], 4).assert_ok();
+
+    // Set round to winner selection
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // Filter tickets and select winners
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                nr_winning_tickets
+            );
+        })
+        .assert_ok();
+
+    // Set round to claim period
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    // Claim for all users
+    for participant in participants.iter().take(4) {
+        lp_setup.claim_user(participant).assert_ok();
+    }
+
+    // Check balances
+    // First user: 1 ticket, no guarantee, no winning tickets
+    let winning_tickets_user1 = 0u64;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[0], &rust_biguint!(3 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user1 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Second user: 2 tickets, no guarantee, 2 winning tickets
+    let winning_tickets_user2 = 2;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &rust_biguint!(TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Third user: 3 tickets, 1 guaranteed, 3 winning ticket
+    let winning_tickets_user3 = 3;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user3 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // New participant: 4 tickets, 5 guaranteed, 4 winning ticket
+    let winning_tickets_user4 = 4;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[3], &rust_biguint!(2 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[3],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user4 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Check correct nr_winning_tickets
+    // Does not work without ticket permutation
+    assert_eq!(
+        nr_winning_tickets as u64,
+        winning_tickets_user1
+            + winning_tickets_user2
+            + winning_tickets_user3
+            + winning_tickets_user4
+    );
+
+    // Owner claims
+    lp_setup.claim_owner().assert_ok();
+
+    // Check owner's balances
+    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 9 winning tickets
+
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0), // All tokens were distributed
+    );
+
+    // Check contract balances
+    lp_setup
+        .b_mock
+        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn no_unlock_schedule_test() {
+    let nr_winning_tickets = 6;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    // Do not set unlock schedule: 100% release immediately
+
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+
+    // Confirm tickets
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    // Set round to winner selection
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // Filter tickets and select winners
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                nr_winning_tickets
+            );
+        })
+        .assert_ok();
+
+    // Set round to claim period
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    // Claim for all users
+    for participant in participants.iter().take(3) {
+        lp_setup.claim_user(participant).assert_ok();
+    }
+
+    // Check balances
+    // First user: 1 ticket, no guarantee, 1 winning ticket
+    let winning_tickets_user1 = 1;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[0], &rust_biguint!(2 * TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user1 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Second user: 2 tickets, no guarantee, 2 winning tickets
+    let winning_tickets_user2 = 2;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &rust_biguint!(TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(winning_tickets_user2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Third user: 3 tickets, 1 guaranteed, 3 winning ticket
+    let winning_tickets_user3 = 3;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
         &participants[2],
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        &rust_biguint!(winning_tickets_user3 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // Check correct nr_winning_tickets
+    // Does not work without ticket permutation
+    assert_eq!(
+        nr_winning_tickets as u64,
+        winning_tickets_user1 + winning_tickets_user2 + winning_tickets_user3
+    );
+
+    // Owner claims
+    lp_setup.claim_owner().assert_ok();
+
+    // Check owner's balances
+    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 6 winning tickets
+
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0), // All tokens were distributed
+    );
+
+    // Check contract balances
+    lp_setup
+        .b_mock
+        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn no_participants_test() {
+    let nr_winning_tickets = 6;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+
+    // Confirm tickets
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    // No participant confirms
+
+    // Set round to winner selection
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // Filter tickets and select winners
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // Set round to claim period
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    // Owner claims
+    lp_setup.claim_owner().assert_ok();
+
+    // Check owner's balances
+    let nr_confirmed_tickets = 0u64;
+    let expected_owner_egld = TICKET_COST * nr_confirmed_tickets; // 0
+
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(nr_winning_tickets as u64 * LAUNCHPAD_TOKENS_PER_TICKET), // All tokens were refunded to the owner
     );
 
-    // New participant: 2 tickets, 1 guaranteed, 1 winning ticket
+    // Check contract balances
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[3], &rust_biguint!(TICKET_COST));
+        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
     lp_setup.b_mock.check_esdt_balance(
-        &participants[3],
+        lp_setup.lp_wrapper.address_ref(),
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+        &rust_biguint!(0),
     );
+}
 
-    // Second new participant: 4 tickets, 4 guaranteed (6 initial guaranteed), 4 winning tickets
+#[test]
+fn operation_progress_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    let participants = lp_setup.participants.clone();
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    // nothing to report before winner selection
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[4], &rust_biguint!(2 * TICKET_COST));
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::default()
+            );
+            assert_eq!(sc.get_estimated_operation_calls(50_000_000), 0);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    // filtering is the next pending step
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::new(OperationPhase::FilterTickets, 0, 6)
+            );
+            assert_eq!(sc.get_estimated_operation_calls(50_000_000), 1);
+        })
+        .assert_ok();
+
+    lp_setup.filter_tickets().assert_ok();
+
+    // simulate an interrupted winner selection
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.current_ongoing_operation()
+                    .set(OngoingOperationType::SelectWinners {
+                        rng: Random::default(),
+                        ticket_position: 2,
+                    });
+                sc.measured_gas_per_iteration().set(1_000_000);
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let mut expected_progress =
+                OperationProgress::new(OperationPhase::SelectWinners, 1, NR_WINNING_TICKETS - 2);
+            expected_progress.gas_per_iteration = 1_000_000;
+            assert_eq!(sc.get_ongoing_operation_progress(), expected_progress);
+            assert_eq!(sc.get_estimated_operation_calls(11_000_000), 1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let _ = sc.get_estimated_operation_calls(10_500_000);
+        })
+        .assert_user_error("Gas limit too low");
+
+    // base selection done, guaranteed tickets distribution is pending
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_operation();
+            },
+        )
+        .assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::new(OperationPhase::GuaranteedTicketsSelection, 0, 1)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::default()
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn abort_winner_selection_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    let participants = lp_setup.participants.clone();
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+
+    // nothing was selected yet
+    lp_setup
+        .abort_winner_selection()
+        .assert_user_error("No winner selection to abort");
+
+    // simulate a winner selection interrupted after the first position
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).set(WINNING_TICKET);
+                sc.ticket_pos_to_id(FIRST_SALE_ROUND_ID, 4).set(1);
+                sc.current_ongoing_operation()
+                    .set(OngoingOperationType::SelectWinners {
+                        rng: Random::default(),
+                        ticket_position: 2,
+                    });
+            },
+        )
+        .assert_ok();
+
+    lp_setup.abort_winner_selection().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_pos_to_id(FIRST_SALE_ROUND_ID, 4).get(), 0);
+            assert!(sc.current_ongoing_operation().is_empty());
+
+            let flags = sc.flags(FIRST_SALE_ROUND_ID).get();
+            assert!(flags.were_tickets_filtered);
+            assert!(!flags.were_winners_selected);
+            assert!(!flags.was_additional_step_completed);
+        })
+        .assert_ok();
+
+    // selection can be restarted from scratch
+    lp_setup.select_winners().assert_ok();
+
+    // a completed base selection may still be aborted before the additional step
+    lp_setup.abort_winner_selection().assert_ok();
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.claimable_ticket_payment().get(), managed_biguint!(0));
+            for ticket_id in 1..=6 {
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get(),
+                    false
+                );
+            }
+        })
+        .assert_ok();
+
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup
+        .abort_winner_selection()
+        .assert_user_error("No winner selection to abort");
+}
+
+#[test]
+fn storage_cleanup_after_claim_deadline_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    lp_setup.set_unlock_schedule(vec![(CLAIM_START_ROUND, 10_000)]);
+    let participants = lp_setup.participants.clone();
+
+    // a blacklisted user keeps the blacklist entry after the tickets walk
+    let blacklisted_user = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let address = managed_address!(&blacklisted_user);
+                sc.blacklist().add(&address);
+                sc.blacklisted_users().insert(address);
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .set_claim_deadline_round(CLAIM_START_ROUND + MIN_CLAIM_PERIOD_ROUNDS - 1)
+        .assert_user_error("Claim deadline is too close");
+
+    let claim_deadline_round = CLAIM_START_ROUND + MIN_CLAIM_PERIOD_ROUNDS;
+    lp_setup
+        .set_claim_deadline_round(claim_deadline_round + 5)
+        .assert_ok();
+    lp_setup
+        .set_claim_deadline_round(claim_deadline_round)
+        .assert_user_error("Claim deadline cannot be moved earlier");
+
+    let claim_deadline_round = claim_deadline_round + 5;
+
+    // only the first user claims before the deadline
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup
+        .cleanup_storage()
+        .assert_user_error("Claim deadline has not passed yet");
+
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+    lp_setup
+        .claim_user(&participants[1])
+        .assert_user_error("Claim period has ended");
+
+    lp_setup.cleanup_storage().assert_ok();
+
+    // winning tickets: ID 1 (claimed), ID 2 for user[1] and ID 4 for user[2]
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            for ticket_id in 1..=6 {
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get(),
+                    false
+                );
+                assert!(sc.ticket_batch(FIRST_SALE_ROUND_ID, ticket_id).is_empty());
+            }
+
+            for p in &participants[1..] {
+                let address = managed_address!(p);
+                assert!(sc
+                    .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &address)
+                    .is_empty());
+                assert_eq!(
+                    sc.nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &address).get(),
+                    0
+                );
+                assert!(sc.user_ticket_status(&address).is_empty());
+            }
+
+            // the claimers, blacklisted users and participants are cleaned up as well
+            let address = managed_address!(&participants[0]);
+            assert!(!sc.claim_list(FIRST_SALE_ROUND_ID).contains(&address));
+            assert!(sc.claimed_users(FIRST_SALE_ROUND_ID).is_empty());
+            assert_eq!(sc.user_claimed_balance(&address).get(), managed_biguint!(0));
+            assert!(sc.blacklisted_users().is_empty());
+            assert!(!sc
+                .blacklist()
+                .contains(&managed_address!(&blacklisted_user)));
+            assert!(sc.participants().is_empty());
+            assert!(sc.current_ongoing_operation().is_empty());
+            assert!(sc.storage_cleanup_completed().get());
+        })
+        .assert_ok();
+
+    // all the ticket payments and the unclaimed launchpad tokens are forwarded to the owner
+    lp_setup
+        .b_mock
+        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
     lp_setup.b_mock.check_esdt_balance(
-        &participants[4],
+        lp_setup.lp_wrapper.address_ref(),
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(4 * LAUNCHPAD_TOKENS_PER_TICKET),
+        &rust_biguint!(0),
+    );
+    lp_setup
+        .b_mock
+        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(TICKET_COST * 6));
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+
+    lp_setup
+        .cleanup_storage()
+        .assert_user_error("Storage already cleaned up");
+}
+
+#[test]
+fn storage_cleanup_keeps_vesting_positions_test() {
+    DebugApi::dummy();
+    static POSITION_TOKEN_ID: &[u8] = b"VEST-123456";
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    lp_setup
+        .set_unlock_schedule_segments(vec![
+            (UnlockCurve::Cliff, 15, 15, 0, 2_500),
+            (UnlockCurve::Linear, 15, 1_015, 0, 7_500),
+        ])
+        .assert_ok();
+    lp_setup
+        .register_unlock_schedule(1, vec![(UnlockCurve::Cliff, 2_000, 2_000, 0, 10_000)])
+        .assert_ok();
+
+    lp_setup.b_mock.set_esdt_local_roles(
+        lp_setup.lp_wrapper.address_ref(),
+        POSITION_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
     );
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vesting_position_token()
+                    .set_token_id(managed_token_id!(POSITION_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
 
-    // Third new participant: 9 tickets, 0 guaranteed, 4 winning tickets
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[5], &rust_biguint!(7 * TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[5],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
-
-    // Owner claims
-    lp_setup.claim_owner().assert_ok();
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
 
-    // Check owner's balances
-    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 9 winning tickets
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
 
+    // every registered schedule must be fully released before the claim period ends
     lp_setup
-        .b_mock
-        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+        .set_claim_deadline_round(CLAIM_START_ROUND + MIN_CLAIM_PERIOD_ROUNDS)
+        .assert_user_error(
+            "Claim deadline must leave the minimum claim period after the last release",
+        );
+    let claim_deadline_round = 2_000 + MIN_CLAIM_PERIOD_ROUNDS;
+    lp_setup
+        .set_claim_deadline_round(claim_deadline_round)
+        .assert_ok();
+
+    // the tokens held by the position stay in the contract
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+    lp_setup.cleanup_storage().assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(75),
+    );
     lp_setup.b_mock.check_esdt_balance(
         &lp_setup.owner_address,
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0), // All tokens were distributed
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
     );
 
-    // Check contract balances
+    // and can still be redeemed after the deadline
     lp_setup
         .b_mock
-        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
+        .execute_esdt_transfer(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            POSITION_TOKEN_ID,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.redeem_vesting_position();
+                assert_eq!(sc.vesting_positions_balance().get(), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
     lp_setup.b_mock.check_esdt_balance(
-        lp_setup.lp_wrapper.address_ref(),
+        &participants[0],
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0),
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
     );
 }
 
 #[test]
-fn contract_pause_test() {
-    let nr_winning_tickets = 1;
+fn user_info_test() {
     let mut lp_setup = LaunchpadSetup::new(
-        nr_winning_tickets,
+        NR_WINNING_TICKETS,
         launchpad_guaranteed_tickets_v2::contract_obj,
     );
-    let unlock_milestones = vec![(0, 10000)];
-    lp_setup.set_unlock_schedule(unlock_milestones);
-    let participant = &lp_setup.participants[0].clone();
+    lp_setup.set_unlock_schedule(vec![
+        (CLAIM_START_ROUND, 5_000),
+        (CLAIM_START_ROUND + 5, 5_000),
+    ]);
+    let participants = lp_setup.participants.clone();
 
     lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
 
-    // Check pause functionality
-    lp_setup.pause_contract();
     lp_setup
-        .confirm(participant, 1)
-        .assert_error(4, "Contract is paused");
-    lp_setup.unpause_contract();
-
-    lp_setup.confirm(participant, 1).assert_ok();
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&participants[2]));
+            assert_eq!(
+                user_info,
+                UserInfo {
+                    launch_stage: LaunchStage::Confirm,
+                    ticket_allowance: MAX_TIER_TICKETS,
+                    confirmed_tickets: 3,
+                    guaranteed_tickets: 1,
+                    is_blacklisted: false,
+                    winning_tickets: 0,
+                    ticket_payment_refund: managed_biguint!(0),
+                    claimable_tokens: managed_biguint!(0),
+                    claimed_tokens: managed_biguint!(0),
+                    has_claimed: false,
+                    nft_status: UserNftStatus::None,
+                }
+            );
+        })
+        .assert_ok();
 
     lp_setup
         .b_mock
         .set_block_round(WINNER_SELECTION_START_ROUND);
-
-    // Check pause functionality
-    lp_setup.pause_contract();
-    lp_setup
-        .filter_tickets()
-        .assert_error(4, "Contract is paused");
-    lp_setup.unpause_contract();
-
     lp_setup.filter_tickets().assert_ok();
-
-    // Check pause functionality
-    lp_setup.pause_contract();
-    lp_setup
-        .select_winners()
-        .assert_error(4, "Contract is paused");
-    lp_setup.unpause_contract();
     lp_setup.select_base_winners_mock(1).assert_ok();
-
-    // Check pause functionality
-    lp_setup.pause_contract();
-    lp_setup
-        .distribute_tickets()
-        .assert_error(4, "Contract is paused");
-    lp_setup.unpause_contract();
     lp_setup.distribute_tickets().assert_ok();
 
+    // user[1] won ticket ID 2 and lost ticket ID 3, half of the tokens are unlocked
     lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&participants[1]));
+            assert_eq!(user_info.launch_stage, LaunchStage::Claim);
+            assert_eq!(user_info.confirmed_tickets, 2);
+            assert_eq!(user_info.winning_tickets, 1);
+            assert_eq!(
+                user_info.ticket_payment_refund,
+                managed_biguint!(TICKET_COST)
+            );
+            assert_eq!(
+                user_info.claimable_tokens,
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2)
+            );
+            assert!(!user_info.has_claimed);
+        })
+        .assert_ok();
 
-    // Check pause functionality
-    lp_setup.pause_contract();
+    lp_setup.claim_user(&participants[1]).assert_ok();
     lp_setup
-        .claim_user(participant)
-        .assert_error(4, "Contract is paused");
-    lp_setup.unpause_contract();
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&participants[1]));
+            assert_eq!(user_info.confirmed_tickets, 0);
+            assert_eq!(user_info.ticket_payment_refund, managed_biguint!(0));
+            assert_eq!(user_info.claimable_tokens, managed_biguint!(0));
+            assert_eq!(
+                user_info.claimed_tokens,
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2)
+            );
+            assert!(user_info.has_claimed);
+        })
+        .assert_ok();
 
-    lp_setup.claim_user(participant).assert_ok();
-    lp_setup.b_mock.check_esdt_balance(
-        participant,
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND + 5);
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&participants[1]));
+            assert_eq!(
+                user_info.claimable_tokens,
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2)
+            );
+        })
+        .assert_ok();
 }
 
 #[test]
-fn fair_chance_allocation_and_ticket_redistribution_test() {
-    let nr_winning_tickets = 9;
+fn launchpad_stats_test() {
     let mut lp_setup = LaunchpadSetup::new(
-        nr_winning_tickets,
+        NR_WINNING_TICKETS,
         launchpad_guaranteed_tickets_v2::contract_obj,
     );
+    lp_setup.set_unlock_schedule(vec![(CLAIM_START_ROUND, 10_000)]);
+    let participants = lp_setup.participants.clone();
 
-    // Set unlock schedule: 100% release immediately
-    let unlock_milestones = vec![(0, 10000)];
-    lp_setup.set_unlock_schedule(unlock_milestones);
-
-    let mut participants = lp_setup.participants.clone();
-
-    let new_participant = lp_setup
-        .b_mock
-        .create_user_account(&rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64 * 2));
-    participants.push(new_participant.clone());
-
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
 
-    // Set guaranteed tickets for users
+    // user[0] is refunded their single ticket
     lp_setup
         .b_mock
         .execute_tx(
@@ -1966,301 +4037,605 @@ fn fair_chance_allocation_and_ticket_redistribution_test() {
             &lp_setup.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let mut args = MultiValueEncoded::new();
-                // New participant with complex structure
-                let mut guaranteed_tickets_info = MultiValueEncodedCounted::new();
-                guaranteed_tickets_info.push((1, 6).into());
-                guaranteed_tickets_info.push((2, 4).into());
-                guaranteed_tickets_info.push((3, 3).into());
-                args.push(
-                    (
-                        managed_address!(&new_participant),
-                        6,
-                        guaranteed_tickets_info,
-                    )
-                        .into(),
-                );
-
-                sc.add_tickets_endpoint(args);
+                let mut blacklist = MultiValueEncoded::new();
+                blacklist.push(managed_address!(&participants[0]));
+                sc.add_users_to_blacklist_endpoint(blacklist);
             },
         )
         .assert_ok();
 
-    // Confirm tickets
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
-    lp_setup.confirm(&participants[0], 1).assert_ok();
-    lp_setup.confirm(&participants[1], 2).assert_ok();
-    lp_setup.confirm(&participants[2], 3).assert_ok();
-    lp_setup.confirm(&participants[3], 4).assert_ok();
-
-    // Set round to winner selection
     lp_setup
         .b_mock
         .set_block_round(WINNER_SELECTION_START_ROUND);
-
-    // Filter tickets and select winners
     lp_setup.filter_tickets().assert_ok();
-    lp_setup.select_winners().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
     lp_setup.distribute_tickets().assert_ok();
 
+    // user[1] won both tickets, user[2] won only the guaranteed one
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[1]).assert_ok();
+    lp_setup.claim_user(&participants[2]).assert_ok();
+
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.nr_winning_tickets().get(), nr_winning_tickets);
+            assert_eq!(
+                sc.get_launchpad_stats(),
+                LaunchpadStats {
+                    total_tickets: 5,
+                    nr_confirmed_users: 3,
+                    nr_confirmed_tickets: 6,
+                    total_ticket_payment: managed_biguint!(TICKET_COST * 6),
+                    nr_refunded_tickets: 3,
+                    total_ticket_payment_refunded: managed_biguint!(TICKET_COST * 3),
+                    nr_claimed_users: 2,
+                    total_launchpad_tokens_claimed: managed_biguint!(
+                        LAUNCHPAD_TOKENS_PER_TICKET * 3
+                    ),
+                    nr_nft_confirmations: 0,
+                }
+            );
         })
         .assert_ok();
+}
 
-    // Set round to claim period
-    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
-
-    // Claim for all users
-    for participant in participants.iter().take(4) {
-        lp_setup.claim_user(participant).assert_ok();
-    }
-
-    // Check balances
-    // First user: 1 ticket, no guarantee, no winning tickets
-    let winning_tickets_user1 = 0u64;
-    lp_setup
-        .b_mock
-        .check_egld_balance(&participants[0], &rust_biguint!(3 * TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[0],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user1 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
-
-    // Second user: 2 tickets, no guarantee, 2 winning tickets
-    let winning_tickets_user2 = 2;
-    lp_setup
-        .b_mock
-        .check_egld_balance(&participants[1], &rust_biguint!(TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[1],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user2 * LAUNCHPAD_TOKENS_PER_TICKET),
+#[test]
+fn paginated_views_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
     );
+    lp_setup.set_unlock_schedule(vec![(CLAIM_START_ROUND, 10_000)]);
+    let participants = lp_setup.participants.clone();
 
-    // Third user: 3 tickets, 1 guaranteed, 3 winning ticket
-    let winning_tickets_user3 = 3;
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[2], &rust_biguint!(0));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[2],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user3 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let ticket_batches: Vec<_> = sc
+                .get_ticket_batches(0, 10, OptionalValue::None)
+                .into_iter()
+                .collect();
+            assert_eq!(ticket_batches.len(), NR_LAUNCHPAD_PARTICIPANTS);
+            for (i, ticket_batch) in ticket_batches.iter().enumerate() {
+                assert_eq!(ticket_batch.address, managed_address!(&participants[i]));
+                assert_eq!(ticket_batch.nr_tickets, i + 1);
+                assert_eq!(ticket_batch.nr_confirmed_tickets, 0);
+            }
+
+            let ticket_batches: Vec<_> = sc
+                .get_ticket_batches(1, 1, OptionalValue::None)
+                .into_iter()
+                .collect();
+            assert_eq!(
+                ticket_batches,
+                vec![TicketBatchInfo {
+                    address: managed_address!(&participants[1]),
+                    ticket_ranges: ManagedVec::from_single_item(TicketRange {
+                        first_id: 2,
+                        last_id: 3,
+                    }),
+                    nr_tickets: 2,
+                    nr_confirmed_tickets: 0,
+                }]
+            );
+            assert_eq!(sc.get_ticket_batches(3, 10, OptionalValue::None).len(), 0);
 
-    // New participant: 4 tickets, 5 guaranteed, 4 winning ticket
-    let winning_tickets_user4 = 4;
-    lp_setup
-        .b_mock
-        .check_egld_balance(&participants[3], &rust_biguint!(2 * TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[3],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user4 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+            let guaranteed_users = sc.get_guaranteed_users(0, 10).to_vec();
+            assert_eq!(guaranteed_users.len(), 1);
+            assert_eq!(
+                guaranteed_users.get(0).clone_value(),
+                managed_address!(&participants[2])
+            );
+        })
+        .assert_ok();
 
-    // Check correct nr_winning_tickets
-    // Does not work without ticket permutation
-    assert_eq!(
-        nr_winning_tickets as u64,
-        winning_tickets_user1
-            + winning_tickets_user2
-            + winning_tickets_user3
-            + winning_tickets_user4
-    );
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
 
-    // Owner claims
-    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut blacklist = MultiValueEncoded::new();
+                blacklist.push(managed_address!(&participants[0]));
+                sc.add_users_to_blacklist_endpoint(blacklist);
+            },
+        )
+        .assert_ok();
 
-    // Check owner's balances
-    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 9 winning tickets
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let blacklisted_users = sc.get_blacklisted_users(0, 10).to_vec();
+            assert_eq!(blacklisted_users.len(), 1);
+            assert_eq!(
+                blacklisted_users.get(0).clone_value(),
+                managed_address!(&participants[0])
+            );
+            assert_eq!(sc.get_blacklisted_users(1, 10).len(), 0);
+        })
+        .assert_ok();
 
     lp_setup
         .b_mock
-        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
-    lp_setup.b_mock.check_esdt_balance(
-        &lp_setup.owner_address,
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0), // All tokens were distributed
-    );
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[1]).assert_ok();
 
-    // Check contract balances
     lp_setup
         .b_mock
-        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
-    lp_setup.b_mock.check_esdt_balance(
-        lp_setup.lp_wrapper.address_ref(),
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0),
-    );
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let claimed_users = sc.get_claimed_users(0, 10, OptionalValue::None).to_vec();
+            assert_eq!(claimed_users.len(), 1);
+            assert_eq!(
+                claimed_users.get(0).clone_value(),
+                managed_address!(&participants[1])
+            );
+
+            // filtered out and claimed users no longer have tickets
+            let ticket_batches: Vec<_> = sc
+                .get_ticket_batches(0, 10, OptionalValue::None)
+                .into_iter()
+                .collect();
+            assert_eq!(
+                ticket_batches,
+                vec![TicketBatchInfo {
+                    address: managed_address!(&participants[2]),
+                    ticket_ranges: ManagedVec::from_single_item(TicketRange {
+                        first_id: 3,
+                        last_id: 5,
+                    }),
+                    nr_tickets: 3,
+                    nr_confirmed_tickets: 3,
+                }]
+            );
+        })
+        .assert_ok();
 }
 
 #[test]
-fn no_unlock_schedule_test() {
-    let nr_winning_tickets = 6;
+fn events_test() {
     let mut lp_setup = LaunchpadSetup::new(
-        nr_winning_tickets,
+        NR_WINNING_TICKETS,
         launchpad_guaranteed_tickets_v2::contract_obj,
     );
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
 
-    // Do not set unlock schedule: 100% release immediately
+    let result = lp_setup.b_mock.execute_tx(
+        &owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut milestones = MultiValueEncoded::new();
+            milestones.push((CLAIM_START_ROUND, 10_000).into());
+            sc.set_unlock_schedule(milestones);
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["setUnlockSchedule"]);
 
-    let participants = lp_setup.participants.clone();
+    let result = lp_setup.b_mock.execute_tx(
+        &owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.set_confirmation_period_start_round(CONFIRM_START_ROUND, OptionalValue::None);
+            sc.add_support_address(managed_address!(&owner_address));
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["setTimeline", "setSupportAddress"]);
 
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+    // the indexed topics are the caller, the round and the epoch
+    assert_eq!(result.result_logs[0].topics[1], owner_address.to_vec());
 
-    // Confirm tickets
     lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
-    lp_setup.confirm(&participants[0], 1).assert_ok();
-    lp_setup.confirm(&participants[1], 2).assert_ok();
-    lp_setup.confirm(&participants[2], 3).assert_ok();
+    for (i, p) in participants.iter().enumerate() {
+        let result = lp_setup.confirm(p, i + 1);
+        result.assert_ok();
+        assert_events(&result, &["confirmTickets"]);
+    }
+
+    let result = lp_setup.b_mock.execute_tx(
+        &owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[0]));
+            sc.add_users_to_blacklist_endpoint(blacklist);
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["refundTicketPayment", "addUsersToBlacklist"]);
+
+    let result = lp_setup.b_mock.execute_tx(
+        &owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[0]));
+            sc.remove_guaranteed_users_from_blacklist_endpoint(blacklist);
+        },
+    );
+    result.assert_ok();
+    assert_events(&result, &["removeUsersFromBlacklist"]);
 
-    // Set round to winner selection
     lp_setup
         .b_mock
         .set_block_round(WINNER_SELECTION_START_ROUND);
+    let result = lp_setup.filter_tickets();
+    result.assert_ok();
+    assert_events(&result, &["filterTicketsCompleted"]);
 
-    // Filter tickets and select winners
-    lp_setup.filter_tickets().assert_ok();
-    lp_setup.select_winners().assert_ok();
-    lp_setup.distribute_tickets().assert_ok();
+    let result = lp_setup.select_winners();
+    result.assert_ok();
+    assert_events(&result, &["selectWinnersCompleted"]);
+
+    let result = lp_setup.distribute_tickets();
+    result.assert_ok();
+    assert_events(&result, &["distributeGuaranteedTicketsCompleted"]);
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in participants.iter().skip(1) {
+        let mut nr_winning_tickets = 0;
+        lp_setup
+            .b_mock
+            .execute_query(&lp_setup.lp_wrapper, |sc| {
+                let ticket_ranges =
+                    sc.try_get_ticket_ranges(FIRST_SALE_ROUND_ID, &managed_address!(p));
+                for ticket_range in ticket_ranges.iter() {
+                    for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                        if sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get() == WINNING_TICKET
+                        {
+                            nr_winning_tickets += 1;
+                        }
+                    }
+                }
+            })
+            .assert_ok();
+
+        let mut expected_events = vec!["launchpadResultsComputed"];
+        if nr_winning_tickets < MAX_TIER_TICKETS {
+            expected_events.push("refundTicketPayment");
+        }
+        if nr_winning_tickets > 0 {
+            expected_events.push("claimLaunchpadTokens");
+        }
+
+        let result = lp_setup.claim_user(p);
+        result.assert_ok();
+        assert_events(&result, &expected_events);
+    }
+
+    let result = lp_setup.claim_owner();
+    result.assert_ok();
+    assert_events(&result, &["claimTicketPayment"]);
+
+    let claim_deadline_round = CLAIM_START_ROUND + MIN_CLAIM_PERIOD_ROUNDS;
+    let result = lp_setup.set_claim_deadline_round(claim_deadline_round);
+    result.assert_ok();
+    assert_events(&result, &["setClaimDeadlineRound"]);
+
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+    let result = lp_setup.cleanup_storage();
+    result.assert_ok();
+    assert_events(&result, &["storageCleanupCompleted"]);
+}
+
+#[test]
+fn storage_migration_from_v1_confirmation_period_test() {
+    let mut lp_setup = LaunchpadSetup::new_from_v1_snapshot(
+        &V1_CONFIRMATION_PERIOD_SNAPSHOT,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
 
+    // nothing can change the tickets until the migration completes
+    lp_setup.upgrade().assert_ok();
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.nr_winning_tickets().get(), nr_winning_tickets);
+            assert!(sc.is_paused());
+            assert!(sc.storage_migration_pending().get());
         })
         .assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&participants[0]));
+                sc.add_users_to_blacklist_endpoint(users);
+            },
+        )
+        .assert_user_error("Storage migration pending");
 
-    // Set round to claim period
-    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
-
-    // Claim for all users
-    for participant in participants.iter().take(3) {
-        lp_setup.claim_user(participant).assert_ok();
-    }
+    let result = lp_setup.migrate_storage();
+    result.assert_ok();
+    assert_events(&result, &["storageMigrationCompleted"]);
+    lp_setup.unpause_contract();
 
-    // Check balances
-    // First user: 1 ticket, no guarantee, 1 winning ticket
-    let winning_tickets_user1 = 1;
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[0], &rust_biguint!(2 * TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[0],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user1 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert!(!sc.storage_migration_pending().get());
+            assert!(sc.legacy_min_confirmed_for_guaranteed_ticket().is_empty());
+            assert_eq!(sc.participants().len(), participants.len());
+            assert_eq!(sc.blacklisted_users().len(), 1);
+            assert!(sc
+                .blacklisted_users()
+                .contains(&managed_address!(&participants[3])));
+
+            let (total_tickets_allowance, guaranteed_tickets_info) = sc
+                .user_tickets_status(managed_address!(&participants[0]))
+                .into_tuple();
+            assert_eq!(total_tickets_allowance, 1);
+            assert!(guaranteed_tickets_info.is_empty());
+
+            // the migration ticket requires all the energy tickets confirmed
+            let (total_tickets_allowance, guaranteed_tickets_info) = sc
+                .user_tickets_status(managed_address!(&participants[1]))
+                .into_tuple();
+            assert_eq!(total_tickets_allowance, 2);
+            assert_eq!(guaranteed_tickets_info.len(), 1);
+            let migration_info = guaranteed_tickets_info.get(0);
+            assert_eq!(migration_info.guaranteed_tickets, 1);
+            assert_eq!(migration_info.min_confirmed_tickets, 2);
+
+            // holding both tickets, the staking one requires all the tickets confirmed
+            let (total_tickets_allowance, guaranteed_tickets_info) = sc
+                .user_tickets_status(managed_address!(&participants[2]))
+                .into_tuple();
+            assert_eq!(total_tickets_allowance, 3);
+            assert_eq!(guaranteed_tickets_info.len(), 2);
+            let migration_info = guaranteed_tickets_info.get(0);
+            assert_eq!(migration_info.guaranteed_tickets, 1);
+            assert_eq!(migration_info.min_confirmed_tickets, 1);
+            let staking_info = guaranteed_tickets_info.get(1);
+            assert_eq!(staking_info.guaranteed_tickets, 1);
+            assert_eq!(staking_info.min_confirmed_tickets, 3);
+
+            let blacklist_status = sc
+                .blacklist_user_ticket_status(&managed_address!(&participants[3]))
+                .get();
+            assert_eq!(blacklist_status.total_tickets_allowance, 2);
+            let staking_info = blacklist_status.guaranteed_tickets_info.get(0);
+            assert_eq!(staking_info.guaranteed_tickets, 1);
+            assert_eq!(staking_info.min_confirmed_tickets, 2);
+
+            let ticket_range: Vec<(usize, usize)> = sc
+                .get_ticket_range_for_address(
+                    &managed_address!(&participants[2]),
+                    OptionalValue::None,
+                )
+                .into_iter()
+                .map(|range| range.into_tuple())
+                .collect();
+            assert_eq!(ticket_range, vec![(4, 6)]);
+        })
+        .assert_ok();
 
-    // Second user: 2 tickets, no guarantee, 2 winning tickets
-    let winning_tickets_user2 = 2;
+    // the sale continues with the migrated tickets
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[1], &rust_biguint!(TICKET_COST));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[1],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user2 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&participants[3]));
+                sc.remove_guaranteed_users_from_blacklist_endpoint(users);
+            },
+        )
+        .assert_ok();
+    lp_setup.confirm(&participants[3], 2).assert_ok();
 
-    // Third user: 3 tickets, 1 guaranteed, 3 winning ticket
-    let winning_tickets_user3 = 3;
     lp_setup
         .b_mock
-        .check_egld_balance(&participants[2], &rust_biguint!(0));
-    lp_setup.b_mock.check_esdt_balance(
-        &participants[2],
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(winning_tickets_user3 * LAUNCHPAD_TOKENS_PER_TICKET),
-    );
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
 
-    // Check correct nr_winning_tickets
-    // Does not work without ticket permutation
-    assert_eq!(
-        nr_winning_tickets as u64,
-        winning_tickets_user1 + winning_tickets_user2 + winning_tickets_user3
+    // the vesting periods of the v1 schedule become cliffs of the default schedule
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND + 20);
+    for participant in &participants[..4] {
+        lp_setup.claim_user(participant).assert_ok();
+    }
+
+    // every guaranteed ticket is won, and the 8 confirmed tickets share the 6 winning ones
+    let mut total_claimed = 0u64;
+    for (participant, nr_confirmed_tickets, nr_guaranteed_tickets) in [
+        (&participants[0], 1, 0),
+        (&participants[1], 2, 1),
+        (&participants[2], 3, 2),
+        (&participants[3], 2, 1),
+    ] {
+        let claimed = lp_setup
+            .b_mock
+            .get_esdt_balance(participant, LAUNCHPAD_TOKEN_ID, 0)
+            .to_u64_digits()
+            .first()
+            .copied()
+            .unwrap_or_default();
+        let nr_winning_tickets = claimed / LAUNCHPAD_TOKENS_PER_TICKET;
+        assert!(nr_winning_tickets >= nr_guaranteed_tickets);
+        lp_setup.b_mock.check_egld_balance(
+            participant,
+            &rust_biguint!(
+                100 - TICKET_COST * nr_confirmed_tickets
+                    + TICKET_COST * (nr_confirmed_tickets - nr_winning_tickets)
+            ),
+        );
+        total_claimed += claimed;
+    }
+    assert_eq!(total_claimed, 6 * LAUNCHPAD_TOKENS_PER_TICKET);
+}
+
+#[test]
+fn storage_migration_from_v1_claim_period_test() {
+    let mut lp_setup = LaunchpadSetup::new_from_v1_snapshot(
+        &V1_CLAIM_PERIOD_SNAPSHOT,
+        launchpad_guaranteed_tickets_v2::contract_obj,
     );
+    let participants = lp_setup.participants.clone();
 
-    // Owner claims
-    lp_setup.claim_owner().assert_ok();
+    lp_setup.upgrade().assert_ok();
+    lp_setup.migrate_storage().assert_ok();
+    lp_setup.unpause_contract();
 
-    // Check owner's balances
-    let expected_owner_egld = TICKET_COST * nr_winning_tickets as u64; // 6 winning tickets
+    // participant 2 claimed in v1, so only the remaining ticket batches are found
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.participants().len(), 2);
+            assert!(sc
+                .participants()
+                .contains(&managed_address!(&participants[0])));
+            assert!(sc
+                .participants()
+                .contains(&managed_address!(&participants[1])));
+            assert!(sc.claimed_users(FIRST_SALE_ROUND_ID).is_empty());
+        })
+        .assert_ok();
 
+    // the v1 claim list and blacklist cannot be enumerated, so the owner lists their users
     lp_setup
         .b_mock
-        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
-    lp_setup.b_mock.check_esdt_balance(
-        &lp_setup.owner_address,
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0), // All tokens were distributed
-    );
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&participants[0]));
+                users.push(managed_address!(&participants[2]));
+                users.push(managed_address!(&participants[2]));
+                assert_eq!(sc.backfill_claimed_users(users), 1);
+
+                let claimed_users: Vec<_> = sc
+                    .get_claimed_users(0, 10, OptionalValue::None)
+                    .into_iter()
+                    .collect();
+                assert_eq!(claimed_users, vec![managed_address!(&participants[2])]);
+
+                // a v1 blacklist entry, of a user whose tickets were filtered out
+                sc.blacklist().add(&managed_address!(&participants[3]));
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&participants[3]));
+                users.push(managed_address!(&participants[1]));
+                assert_eq!(sc.backfill_blacklisted_users(users), 1);
+                assert!(sc
+                    .blacklisted_users()
+                    .contains(&managed_address!(&participants[3])));
+                assert_eq!(sc.blacklisted_users().len(), 1);
+            },
+        )
+        .assert_ok();
+
+    // half was claimed in v1 with the initial release, a quarter is released at each cliff
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND + 10);
+    for participant in &participants[..2] {
+        lp_setup.claim_user(participant).assert_ok();
+    }
+    for (participant, claimed) in [
+        (&participants[0], 75),
+        (&participants[1], 150),
+        (&participants[2], 150),
+    ] {
+        lp_setup.b_mock.check_esdt_balance(
+            participant,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(claimed),
+        );
+    }
 
-    // Check contract balances
     lp_setup
         .b_mock
-        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
-    lp_setup.b_mock.check_esdt_balance(
-        lp_setup.lp_wrapper.address_ref(),
-        LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0),
-    );
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let claimed_users = sc.claimed_users(FIRST_SALE_ROUND_ID);
+            assert_eq!(claimed_users.len(), 3);
+            assert!(claimed_users.contains(&managed_address!(&participants[2])));
+        })
+        .assert_ok();
+
+    // the backfilled v1 claimer is reached by the push without claiming again
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND + 20);
+    let owner_address = lp_setup.owner_address.clone();
+    lp_setup.push_releases(&owner_address).assert_ok();
+    for (participant, claimed) in [
+        (&participants[0], 100),
+        (&participants[1], 200),
+        (&participants[2], 300),
+    ] {
+        lp_setup.b_mock.check_esdt_balance(
+            participant,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(claimed),
+        );
+    }
 }
 
 #[test]
-fn no_participants_test() {
-    let nr_winning_tickets = 6;
-    let mut lp_setup = LaunchpadSetup::new(
-        nr_winning_tickets,
-        launchpad_guaranteed_tickets_v2::contract_obj,
+fn unlock_milestones_migration_test() {
+    let mut lp_setup = LaunchpadSetup::new(1, launchpad_guaranteed_tickets_v2::contract_obj);
+    let participant = lp_setup.participants[0].clone();
+
+    // milestones (15, 50%) and (25, 50%), as stored before the unlock segments
+    lp_setup.set_raw_storage(
+        "unlockSchedule",
+        "00000002000000000000000f00000000000013880000000000000019\
+         0000000000001388",
     );
+    lp_setup.upgrade().assert_ok();
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert!(sc.legacy_unlock_milestones_schedule().is_empty());
+            assert!(!sc.unlock_schedule().is_empty());
+        })
+        .assert_ok();
 
-    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
-
-    // Confirm tickets
     lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
-    // No participant confirms
+    lp_setup.confirm(&participant, 1).assert_ok();
 
-    // Set round to winner selection
     lp_setup
         .b_mock
         .set_block_round(WINNER_SELECTION_START_ROUND);
-
-    // Filter tickets and select winners
     lp_setup.filter_tickets().assert_ok();
-    lp_setup.select_winners().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
     lp_setup.distribute_tickets().assert_ok();
 
-    // Set round to claim period
+    // each milestone becomes a cliff segment
     lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
-
-    // Owner claims
-    lp_setup.claim_owner().assert_ok();
-
-    // Check owner's balances
-    let nr_confirmed_tickets = 0u64;
-    let expected_owner_egld = TICKET_COST * nr_confirmed_tickets; // 0
-
-    lp_setup
-        .b_mock
-        .check_egld_balance(&lp_setup.owner_address, &rust_biguint!(expected_owner_egld));
+    lp_setup.claim_user(&participant).assert_ok();
     lp_setup.b_mock.check_esdt_balance(
-        &lp_setup.owner_address,
+        &participant,
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(nr_winning_tickets as u64 * LAUNCHPAD_TOKENS_PER_TICKET), // All tokens were refunded to the owner
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2),
     );
 
-    // Check contract balances
-    lp_setup
-        .b_mock
-        .check_egld_balance(lp_setup.lp_wrapper.address_ref(), &rust_biguint!(0));
+    lp_setup.b_mock.set_block_round(25);
+    lp_setup.claim_user(&participant).assert_ok();
     lp_setup.b_mock.check_esdt_balance(
-        lp_setup.lp_wrapper.address_ref(),
+        &participant,
         LAUNCHPAD_TOKEN_ID,
-        &rust_biguint!(0),
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
     );
 }
@@ -0,0 +1,45 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+use crate::token_release::UnlockSchedule;
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetUnlockScheduleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    unlock_schedule: UnlockSchedule,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_set_unlock_schedule_event(&self, unlock_schedule: UnlockSchedule) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_unlock_schedule_event(
+            user.clone(),
+            round,
+            epoch,
+            SetUnlockScheduleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                unlock_schedule,
+            },
+        )
+    }
+
+    #[event("setUnlockSchedule")]
+    fn set_unlock_schedule_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_unlock_schedule_event: SetUnlockScheduleEvent<Self::Api>,
+    );
+}
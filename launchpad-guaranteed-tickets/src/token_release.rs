@@ -1,11 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::config;
+use launchpad_common::{config, config::FIRST_SALE_ROUND_ID, launch_stage, user_info::UserInfo};
 
 pub const MAX_PERCENTAGE: u64 = 10_000;
 
-#[derive(TopEncode, TopDecode, TypeAbi)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
 pub struct UnlockSchedule {
     claim_start_round: u64,
     initial_release_percentage: u64,
@@ -30,12 +30,23 @@ impl UnlockSchedule {
             vesting_release_period,
         }
     }
+
+    pub fn last_release_round(&self) -> u64 {
+        if self.initial_release_percentage == MAX_PERCENTAGE {
+            return self.claim_start_round;
+        }
+
+        self.claim_start_round + self.vesting_release_times * self.vesting_release_period
+    }
 }
 
 #[multiversx_sc::module]
-pub trait TokenReleaseModule: config::ConfigModule {
+pub trait TokenReleaseModule:
+    config::ConfigModule + launch_stage::LaunchStageModule + crate::events::EventsModule
+{
     #[only_owner]
     #[endpoint(setUnlockSchedule)]
+    #[label("vesting")]
     fn set_unlock_schedule(
         &self,
         claim_start_round: u64,
@@ -44,7 +55,7 @@ pub trait TokenReleaseModule: config::ConfigModule {
         vesting_release_percentage: u64,
         vesting_release_period: u64,
     ) {
-        let configuration = self.configuration();
+        let configuration = self.configuration(FIRST_SALE_ROUND_ID);
         require!(
             !configuration.is_empty(),
             "Timeline configuration is not set"
@@ -81,10 +92,14 @@ pub trait TokenReleaseModule: config::ConfigModule {
             vesting_release_period,
         );
 
-        self.unlock_schedule().set(unlock_schedule);
+        self.require_release_before_claim_deadline(unlock_schedule.last_release_round());
+        self.unlock_schedule().set(&unlock_schedule);
+
+        self.emit_set_unlock_schedule_event(unlock_schedule);
     }
 
     #[view(getClaimableTokens)]
+    #[label("vesting")]
     fn compute_claimable_tokens(&self, address: &ManagedAddress) -> BigUint {
         let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
         if user_total_claimable_balance == 0 {
@@ -97,6 +112,16 @@ pub trait TokenReleaseModule: config::ConfigModule {
             "Already claimed all tokens"
         );
 
+        let current_claimable_tokens = self.compute_unlocked_tokens(&user_total_claimable_balance);
+        if current_claimable_tokens == 0 {
+            return BigUint::zero();
+        }
+
+        current_claimable_tokens - user_claimed_balance
+    }
+
+    /// The part of `total_amount` released so far, according to the unlock schedule
+    fn compute_unlocked_tokens(&self, total_amount: &BigUint) -> BigUint {
         let unlock_schedule_mapper = self.unlock_schedule();
         if unlock_schedule_mapper.is_empty() {
             return BigUint::zero();
@@ -108,7 +133,7 @@ pub trait TokenReleaseModule: config::ConfigModule {
         }
 
         if unlock_schedule.initial_release_percentage == MAX_PERCENTAGE {
-            return user_total_claimable_balance;
+            return total_amount.clone();
         }
 
         let rounds_passed = current_round - unlock_schedule.claim_start_round;
@@ -118,21 +143,48 @@ pub trait TokenReleaseModule: config::ConfigModule {
         }
         let claimable_percentage = unlock_schedule.initial_release_percentage
             + unlock_schedule.vesting_release_percentage * claimable_periods;
-        let current_claimable_tokens =
-            &user_total_claimable_balance * claimable_percentage / MAX_PERCENTAGE;
 
-        current_claimable_tokens - user_claimed_balance
+        total_amount * claimable_percentage / MAX_PERCENTAGE
+    }
+
+    fn get_last_release_round(&self) -> u64 {
+        let unlock_schedule_mapper = self.unlock_schedule();
+        if unlock_schedule_mapper.is_empty() {
+            return 0;
+        }
+
+        unlock_schedule_mapper.get().last_release_round()
+    }
+
+    fn add_vesting_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        if !user_info.has_claimed {
+            user_info.claimable_tokens = self.compute_unlocked_tokens(&user_info.claimable_tokens);
+            return;
+        }
+
+        let user_claimed_balance = self.user_claimed_balance(address).get();
+        if !self.is_claim_deadline_passed() {
+            let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
+            let unlocked_tokens = self.compute_unlocked_tokens(&user_total_claimable_balance);
+            if unlocked_tokens > user_claimed_balance {
+                user_info.claimable_tokens = unlocked_tokens - &user_claimed_balance;
+            }
+        }
+        user_info.claimed_tokens = user_claimed_balance;
     }
 
     #[view(getUserTotalClaimableBalance)]
+    #[label("vesting")]
     #[storage_mapper("userTotalClaimableBalance")]
     fn user_total_claimable_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
     #[view(getUserClaimedBalance)]
+    #[label("vesting")]
     #[storage_mapper("userClaimedBalance")]
     fn user_claimed_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
     #[view(getUnlockSchedule)]
+    #[label("vesting")]
     #[storage_mapper("unlockSchedule")]
     fn unlock_schedule(&self) -> SingleValueMapper<UnlockSchedule>;
 }
@@ -1,9 +1,10 @@
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{
     Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, OperationCompletionStatus,
 };
 
 use launchpad_common::{
-    config::ConfigModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
     launch_stage::{Flags, LaunchStageModule},
     tickets::{TicketsModule, WINNING_TICKET},
     user_interactions::UserInteractionsModule,
@@ -98,7 +99,10 @@ where
                 sc.add_tickets_endpoint(args);
 
                 // 1 ticket for the max tier gets removed
-                assert_eq!(sc.nr_winning_tickets().get(), nr_winning_tickets - 1);
+                assert_eq!(
+                    sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                    nr_winning_tickets - 1
+                );
                 assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
                 assert!(sc
                     .users_with_guaranteed_ticket()
@@ -136,7 +140,12 @@ where
             &self.lp_wrapper,
             &rust_biguint!(TICKET_COST * nr_tickets as u64),
             |sc| {
-                sc.confirm_tickets(nr_tickets);
+                sc.confirm_tickets(
+                    nr_tickets,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
     }
@@ -147,7 +156,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let result = sc.filter_tickets();
+                let result = sc.filter_tickets(OptionalValue::None);
                 assert_eq!(result, OperationCompletionStatus::Completed);
             },
         )
@@ -161,13 +170,14 @@ where
             |sc| {
                 let base_winning = NR_WINNING_TICKETS - nr_whales;
                 for ticket_id in 1..=base_winning {
-                    sc.ticket_status(ticket_id).set(WINNING_TICKET);
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id)
+                        .set(WINNING_TICKET);
                 }
 
                 sc.claimable_ticket_payment()
                     .set(&managed_biguint!(TICKET_COST * (base_winning as u64)));
 
-                sc.flags().set(&Flags {
+                sc.flags(FIRST_SALE_ROUND_ID).set(&Flags {
                     were_tickets_filtered: true,
                     has_winner_selection_process_started: true,
                     were_winners_selected: true,
@@ -7,7 +7,8 @@ use guaranteed_tickets_setup::{
     LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
 };
 use launchpad_common::{
-    config::ConfigModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
+    ongoing_operation::OngoingOperationModule,
     setup::SetupModule,
     tickets::{TicketsModule, WINNING_TICKET},
     winner_selection::WinnerSelectionModule,
@@ -19,8 +20,9 @@ use launchpad_guaranteed_tickets::{
     guaranteed_tickets_init::GuaranteedTicketsInitModule,
     LaunchpadGuaranteedTickets,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
 
 use crate::guaranteed_tickets_setup::NR_WINNING_TICKETS;
 
@@ -55,27 +57,45 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
         })
         .assert_ok();
 
@@ -85,27 +105,48 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 1
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
         })
         .assert_ok();
 
@@ -171,26 +212,44 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
         })
         .assert_ok();
@@ -201,26 +260,47 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 2
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
         .assert_ok();
@@ -287,15 +367,21 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 2
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
         })
         .assert_ok();
@@ -314,13 +400,19 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -330,13 +422,22 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -417,19 +518,22 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -454,16 +558,28 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                ); // randomly selected -> leftover_ticket
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                ); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -472,16 +588,31 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(),
+                    WINNING_TICKET
+                ); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -772,6 +903,7 @@ fn condition_checks_test() {
                 sc.set_ticket_price(
                     EgldOrEsdtTokenIdentifier::egld(),
                     managed_biguint!(TICKET_COST),
+                    OptionalValue::None,
                 );
             },
         )
@@ -923,7 +1055,7 @@ fn blacklist_scenario_test() {
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -966,7 +1098,7 @@ fn blacklist_scenario_test() {
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             let blacklisted_user_guaranteed_tickets = 1;
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -1048,13 +1180,16 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1065,12 +1200,24 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1228,3 +1375,51 @@ fn confirm_less_tickets_than_total_available_with_vesting_scenario_test() {
         &rust_biguint!(0),
     );
 }
+
+#[test]
+fn upgrade_during_additional_selection_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets::contract_obj,
+    );
+
+    // operation saved by a contract from before the selection pipeline
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let op: GuaranteedTicketsSelectionOperation<DebugApi> =
+                    GuaranteedTicketsSelectionOperation::default();
+                sc.save_additional_selection_progress(&op);
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.upgrade();
+            },
+        )
+        .assert_error(4, "Cannot upgrade during the additional selection");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.current_ongoing_operation().clear();
+                sc.upgrade();
+            },
+        )
+        .assert_ok();
+}
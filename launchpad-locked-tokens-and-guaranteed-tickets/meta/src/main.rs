@@ -1,4 +0,0 @@
-fn main() {
-    multiversx_sc_meta_lib::cli_main::<launchpad_locked_tokens_and_guaranteed_tickets::AbiProvider>(
-    );
-}
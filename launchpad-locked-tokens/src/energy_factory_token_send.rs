@@ -0,0 +1,119 @@
+multiversx_sc::imports!();
+
+use crate::locked_launchpad_token_send::MAX_PERCENTAGE;
+
+pub mod energy_factory_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait EnergyFactoryProxy {
+        #[payable("*")]
+        #[endpoint(lockTokens)]
+        fn lock_tokens(
+            &self,
+            lock_epochs: u64,
+            opt_destination: OptionalValue<ManagedAddress>,
+        ) -> EsdtTokenPayment;
+    }
+}
+
+#[multiversx_sc::module]
+pub trait EnergyFactoryTokenSend:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + crate::locked_launchpad_token_send::LockedLaunchpadTokenSend
+    + crate::events::EventsModule
+{
+    /// Once set, the locked part of the launchpad tokens is locked through the energy factory,
+    /// for one of the `lock_epochs_options` chosen by the user at claim time
+    #[only_owner]
+    #[endpoint(setEnergyFactoryLockOptions)]
+    fn set_energy_factory_lock_options(
+        &self,
+        sc_address: ManagedAddress,
+        lock_epochs_options: MultiValueEncoded<u64>,
+    ) {
+        self.require_before_claim_period_for_all_sale_rounds();
+        require!(
+            !sc_address.is_zero() && self.blockchain().is_smart_contract(&sc_address),
+            "Invalid SC address"
+        );
+        require!(!lock_epochs_options.is_empty(), "No lock periods");
+
+        let lock_epochs_options = lock_epochs_options.to_vec();
+        for lock_epochs in lock_epochs_options.iter() {
+            require!(lock_epochs > 0, "Invalid lock period");
+        }
+
+        self.energy_factory_sc_address().set(&sc_address);
+        self.lock_epochs_options().set(&lock_epochs_options);
+        self.emit_set_energy_factory_lock_options_event(sc_address, lock_epochs_options);
+    }
+
+    fn is_energy_factory_set(&self) -> bool {
+        !self.energy_factory_sc_address().is_empty()
+    }
+
+    fn require_allowed_lock_epochs(&self, lock_epochs: u64) {
+        let is_allowed = self
+            .lock_epochs_options()
+            .get()
+            .iter()
+            .any(|lock_epochs_option| lock_epochs_option == lock_epochs);
+        require!(is_allowed, "Lock period not allowed");
+    }
+
+    fn send_energy_locked_launchpad_tokens(
+        &self,
+        dest_address: &ManagedAddress,
+        launchpad_tokens: &EsdtTokenPayment<Self::Api>,
+        lock_epochs: u64,
+    ) {
+        let lock_percentage = self.get_locked_percentage();
+        let lock_amount = &launchpad_tokens.amount * lock_percentage / MAX_PERCENTAGE;
+        let unlocked_amount = &launchpad_tokens.amount - &lock_amount;
+        if lock_amount > 0 {
+            let locked_tokens = EsdtTokenPayment::new(
+                launchpad_tokens.token_identifier.clone(),
+                launchpad_tokens.token_nonce,
+                lock_amount,
+            );
+            let sc_address = self.energy_factory_sc_address().get();
+            let _: IgnoreValue = self
+                .energy_factory_proxy_builder(sc_address)
+                .lock_tokens(lock_epochs, OptionalValue::Some(dest_address.clone()))
+                .with_esdt_transfer(locked_tokens.clone())
+                .execute_on_dest_context();
+
+            let unlock_epoch = self.blockchain().get_block_epoch() + lock_epochs;
+            self.emit_lock_launchpad_tokens_event(
+                dest_address.clone(),
+                locked_tokens,
+                unlock_epoch,
+            );
+        }
+
+        if unlocked_amount > 0 {
+            self.send().direct_esdt(
+                dest_address,
+                &launchpad_tokens.token_identifier,
+                launchpad_tokens.token_nonce,
+                &unlocked_amount,
+            );
+        }
+    }
+
+    #[view(getEnergyFactoryScAddress)]
+    #[storage_mapper("energyFactoryScAddress")]
+    fn energy_factory_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getLockEpochsOptions)]
+    #[storage_mapper("lockEpochsOptions")]
+    fn lock_epochs_options(&self) -> SingleValueMapper<ManagedVec<u64>>;
+
+    #[proxy]
+    fn energy_factory_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> energy_factory_proxy::Proxy<Self::Api>;
+}
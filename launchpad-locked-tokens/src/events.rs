@@ -0,0 +1,171 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+use crate::locked_launchpad_token_send::LockTranche;
+
+#[derive(TypeAbi, TopEncode)]
+pub struct LockLaunchpadTokensEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    destination: ManagedAddress<M>,
+    locked_tokens: EsdtTokenPayment<M>,
+    unlock_epoch: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetLaunchpadTokensLockTranchesEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    lock_tranches: ManagedVec<M, LockTranche>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetSimpleLockScAddressEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    simple_lock_sc_address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetEnergyFactoryLockOptionsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    energy_factory_sc_address: ManagedAddress<M>,
+    lock_epochs_options: ManagedVec<M, u64>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_lock_launchpad_tokens_event(
+        &self,
+        destination: ManagedAddress,
+        locked_tokens: EsdtTokenPayment<Self::Api>,
+        unlock_epoch: u64,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.lock_launchpad_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            LockLaunchpadTokensEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                destination,
+                locked_tokens,
+                unlock_epoch,
+            },
+        )
+    }
+
+    #[event("lockLaunchpadTokens")]
+    fn lock_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        lock_launchpad_tokens_event: LockLaunchpadTokensEvent<Self::Api>,
+    );
+
+    fn emit_set_launchpad_tokens_lock_tranches_event(
+        &self,
+        lock_tranches: ManagedVec<LockTranche>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_launchpad_tokens_lock_tranches_event(
+            user.clone(),
+            round,
+            epoch,
+            SetLaunchpadTokensLockTranchesEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                lock_tranches,
+            },
+        )
+    }
+
+    #[event("setLaunchpadTokensLockTranches")]
+    fn set_launchpad_tokens_lock_tranches_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_launchpad_tokens_lock_tranches_event: SetLaunchpadTokensLockTranchesEvent<Self::Api>,
+    );
+
+    fn emit_set_simple_lock_sc_address_event(&self, simple_lock_sc_address: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_simple_lock_sc_address_event(
+            user.clone(),
+            round,
+            epoch,
+            SetSimpleLockScAddressEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                simple_lock_sc_address,
+            },
+        )
+    }
+
+    #[event("setSimpleLockScAddress")]
+    fn set_simple_lock_sc_address_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_simple_lock_sc_address_event: SetSimpleLockScAddressEvent<Self::Api>,
+    );
+
+    fn emit_set_energy_factory_lock_options_event(
+        &self,
+        energy_factory_sc_address: ManagedAddress,
+        lock_epochs_options: ManagedVec<u64>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_energy_factory_lock_options_event(
+            user.clone(),
+            round,
+            epoch,
+            SetEnergyFactoryLockOptionsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                energy_factory_sc_address,
+                lock_epochs_options,
+            },
+        )
+    }
+
+    #[event("setEnergyFactoryLockOptions")]
+    fn set_energy_factory_lock_options_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_energy_factory_lock_options_event: SetEnergyFactoryLockOptionsEvent<Self::Api>,
+    );
+}
@@ -3,8 +3,16 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{launch_stage::Flags, *};
+use launchpad_common::{
+    config::{SaleRoundId, FIRST_SALE_ROUND_ID},
+    launch_stage::Flags,
+    operation_progress::OperationProgress,
+    user_info::UserInfo,
+    *,
+};
 
+pub mod energy_factory_token_send;
+pub mod events;
 pub mod locked_launchpad_token_send;
 
 #[multiversx_sc::contract]
@@ -13,15 +21,23 @@ pub trait LaunchpadLockedTokens:
     + launch_stage::LaunchStageModule
     + config::ConfigModule
     + setup::SetupModule
+    + storage_cleanup::StorageCleanupModule
     + tickets::TicketsModule
     + winner_selection::WinnerSelectionModule
     + ongoing_operation::OngoingOperationModule
+    + operation_progress::OperationProgressModule
     + permissions::PermissionsModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
+    + launchpad_stats::LaunchpadStatsModule
     + user_interactions::UserInteractionsModule
+    + user_info::UserInfoModule
+    + events::EventsModule
     + locked_launchpad_token_send::LockedLaunchpadTokenSend
+    + energy_factory_token_send::EnergyFactoryTokenSend
     + common_events::CommonEventsModule
+    + kyc::KycModule
+    + sale_rounds::SaleRoundsModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -36,9 +52,8 @@ pub trait LaunchpadLockedTokens:
         confirmation_period_start_round: u64,
         winner_selection_start_round: u64,
         claim_start_round: u64,
-        launchpad_tokens_lock_percentage: u32,
-        launchpad_tokens_unlock_epoch: u64,
         simple_lock_sc_address: ManagedAddress,
+        launchpad_tokens_lock_tranches: MultiValueEncoded<MultiValue2<u32, u64>>,
     ) {
         let flags = Flags {
             has_winner_selection_process_started: false,
@@ -58,9 +73,8 @@ pub trait LaunchpadLockedTokens:
             flags,
         );
 
-        self.try_set_launchpad_tokens_lock_percentage(launchpad_tokens_lock_percentage);
-        self.try_set_launchpad_tokens_unlock_epoch(launchpad_tokens_unlock_epoch);
         self.try_set_simple_lock_sc_address(simple_lock_sc_address);
+        self.try_set_launchpad_tokens_lock_tranches(launchpad_tokens_lock_tranches);
     }
 
     #[only_owner]
@@ -69,20 +83,49 @@ pub trait LaunchpadLockedTokens:
         &self,
         address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) {
-        self.add_tickets(address_number_pairs);
+        self.add_tickets(FIRST_SALE_ROUND_ID, address_number_pairs);
+    }
+
+    /// Sets the absolute ticket allowance of users who were already added, or adds new users.
+    /// Setting an allowance to 0 removes the user from the sale.
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.set_ticket_allowances(FIRST_SALE_ROUND_ID, address_number_pairs);
     }
 
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
     fn deposit_launchpad_tokens_endpoint(&self) {
-        let nr_winning_tickets = self.nr_winning_tickets().get();
-        self.deposit_launchpad_tokens(nr_winning_tickets);
+        let total_winning_tickets = self.get_total_winning_tickets();
+        self.deposit_launchpad_tokens(total_winning_tickets);
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::send_locked_launchpad_tokens);
+    fn claim_launchpad_tokens_endpoint(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) {
+        require!(!self.is_energy_factory_set(), "Must choose a lock period");
+
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.claim_launchpad_tokens(sale_round_id, Self::send_locked_launchpad_tokens);
+    }
+
+    /// Used once the energy factory is set, `lock_epochs` being one of the allowed lock periods
+    #[endpoint(claimLaunchpadTokensWithLockPeriod)]
+    fn claim_launchpad_tokens_with_lock_period_endpoint(
+        &self,
+        lock_epochs: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        self.require_allowed_lock_epochs(lock_epochs);
+
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.claim_launchpad_tokens(sale_round_id, |sc: &Self, address, launchpad_tokens| {
+            sc.send_energy_locked_launchpad_tokens(address, launchpad_tokens, lock_epochs)
+        });
     }
 
     #[only_owner]
@@ -95,4 +138,48 @@ pub trait LaunchpadLockedTokens:
     fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
         self.add_users_to_blacklist(&users_list.to_vec());
     }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::no_vesting_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.cleanup_storage(
+            Self::no_additional_user_cleanup,
+            Self::no_reserved_launchpad_tokens,
+        )
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(
+        &self,
+        address: ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> UserInfo<Self::Api> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_user_info_base(sale_round_id, &address, Self::no_additional_user_info)
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> OperationProgress {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_operation_progress(sale_round_id, Self::no_additional_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(
+        &self,
+        gas_limit: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let progress = self.get_ongoing_operation_progress(opt_sale_round_id);
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
 }
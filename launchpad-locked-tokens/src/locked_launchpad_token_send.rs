@@ -1,6 +1,7 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
-const MAX_PERCENTAGE: u32 = 10_000; // 100%
+pub const MAX_PERCENTAGE: u32 = 10_000; // 100%
 
 pub mod simple_lock_proxy {
     multiversx_sc::imports!();
@@ -13,22 +14,96 @@ pub mod simple_lock_proxy {
     }
 }
 
+/// The share of the launchpad tokens locked until `unlock_epoch`.
+/// Tranches which are already unlocked at claim time are sent liquid.
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug,
+)]
+pub struct LockTranche {
+    pub percentage: u32,
+    pub unlock_epoch: u64,
+}
+
 #[multiversx_sc::module]
-pub trait LockedLaunchpadTokenSend {
-    fn try_set_launchpad_tokens_lock_percentage(&self, lock_percentage: u32) {
-        require!(
-            lock_percentage > 0 && lock_percentage <= MAX_PERCENTAGE,
-            "Invalid lock percentage"
+pub trait LockedLaunchpadTokenSend:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + crate::events::EventsModule
+{
+    #[only_owner]
+    #[label("locked-tokens")]
+    #[endpoint(setLaunchpadTokensLockTranches)]
+    fn set_launchpad_tokens_lock_tranches(
+        &self,
+        lock_tranches: MultiValueEncoded<MultiValue2<u32, u64>>,
+    ) {
+        self.require_before_claim_period_for_all_sale_rounds();
+
+        self.try_set_launchpad_tokens_lock_tranches(lock_tranches);
+        self.emit_set_launchpad_tokens_lock_tranches_event(
+            self.launchpad_tokens_lock_tranches().get(),
         );
+    }
 
-        self.launchpad_tokens_lock_percentage().set(lock_percentage);
+    #[only_owner]
+    #[label("locked-tokens")]
+    #[endpoint(setSimpleLockScAddress)]
+    fn set_simple_lock_sc_address(&self, sc_address: ManagedAddress) {
+        self.require_before_claim_period_for_all_sale_rounds();
+
+        self.try_set_simple_lock_sc_address(sc_address.clone());
+        self.emit_set_simple_lock_sc_address_event(sc_address);
     }
 
-    fn try_set_launchpad_tokens_unlock_epoch(&self, unlock_epoch: u64) {
+    /// Tranches are `(percentage, unlock epoch)` pairs, sorted by unlock epoch,
+    /// and their percentages must add up to `MAX_PERCENTAGE`.
+    /// Unlock epochs must be in the future, except for a first tranche with
+    /// unlock epoch 0, which is sent liquid.
+    fn try_set_launchpad_tokens_lock_tranches(
+        &self,
+        lock_tranches: MultiValueEncoded<MultiValue2<u32, u64>>,
+    ) {
         let current_epoch = self.blockchain().get_block_epoch();
-        require!(unlock_epoch > current_epoch, "Invalid unlock epoch");
+        let mut tranches = ManagedVec::<Self::Api, LockTranche>::new();
+        let mut total_percentage = 0;
+        for lock_tranche in lock_tranches {
+            let (percentage, unlock_epoch) = lock_tranche.into_tuple();
+            require!(
+                percentage > 0 && percentage <= MAX_PERCENTAGE - total_percentage,
+                "Invalid lock percentage"
+            );
+            require!(
+                unlock_epoch == 0 || unlock_epoch > current_epoch,
+                "Invalid unlock epoch"
+            );
+            if let Some(last_tranche) = tranches.iter().last() {
+                require!(
+                    unlock_epoch > last_tranche.unlock_epoch,
+                    "Lock tranches must be sorted by unlock epoch"
+                );
+            }
+
+            total_percentage += percentage;
+            tranches.push(LockTranche {
+                percentage,
+                unlock_epoch,
+            });
+        }
+        require!(
+            total_percentage == MAX_PERCENTAGE,
+            "Lock tranches must add up to 100%"
+        );
 
-        self.launchpad_tokens_unlock_epoch().set(unlock_epoch);
+        self.launchpad_tokens_lock_tranches().set(&tranches);
     }
 
     fn try_set_simple_lock_sc_address(&self, sc_address: ManagedAddress) {
@@ -40,6 +115,19 @@ pub trait LockedLaunchpadTokenSend {
         self.simple_lock_sc_address().set(&sc_address);
     }
 
+    /// The total percentage of the tranches which are still locked in the current epoch
+    fn get_locked_percentage(&self) -> u32 {
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.launchpad_tokens_lock_tranches()
+            .get()
+            .iter()
+            .filter(|lock_tranche| current_epoch < lock_tranche.unlock_epoch)
+            .map(|lock_tranche| lock_tranche.percentage)
+            .sum()
+    }
+
+    /// Locks every tranche through a separate simple lock call,
+    /// and sends the unlocked tranches along with the rounding leftovers
     fn send_locked_launchpad_tokens(
         &self,
         dest_address: &ManagedAddress,
@@ -47,25 +135,36 @@ pub trait LockedLaunchpadTokenSend {
     ) {
         let mut unlocked_amount = launchpad_tokens.amount.clone();
 
-        let unlock_epoch = self.launchpad_tokens_unlock_epoch().get();
         let current_epoch = self.blockchain().get_block_epoch();
-        if current_epoch < unlock_epoch {
-            let lock_percentage = self.launchpad_tokens_lock_percentage().get();
-            let lock_amount = &launchpad_tokens.amount * lock_percentage / MAX_PERCENTAGE;
-            if lock_amount > 0 {
-                unlocked_amount -= &lock_amount;
-
-                let sc_address = self.simple_lock_sc_address().get();
-                let _: IgnoreValue = self
-                    .simple_lock_proxy_builder(sc_address)
-                    .lock_tokens(unlock_epoch, dest_address.clone())
-                    .with_esdt_transfer((
-                        launchpad_tokens.token_identifier.clone(),
-                        launchpad_tokens.token_nonce,
-                        lock_amount,
-                    ))
-                    .execute_on_dest_context();
+        for lock_tranche in self.launchpad_tokens_lock_tranches().get().iter() {
+            if current_epoch >= lock_tranche.unlock_epoch {
+                continue;
+            }
+
+            let lock_amount = &launchpad_tokens.amount * lock_tranche.percentage / MAX_PERCENTAGE;
+            if lock_amount == 0 {
+                continue;
             }
+
+            unlocked_amount -= &lock_amount;
+
+            let locked_tokens = EsdtTokenPayment::new(
+                launchpad_tokens.token_identifier.clone(),
+                launchpad_tokens.token_nonce,
+                lock_amount,
+            );
+            let sc_address = self.simple_lock_sc_address().get();
+            let _: IgnoreValue = self
+                .simple_lock_proxy_builder(sc_address)
+                .lock_tokens(lock_tranche.unlock_epoch, dest_address.clone())
+                .with_esdt_transfer(locked_tokens.clone())
+                .execute_on_dest_context();
+
+            self.emit_lock_launchpad_tokens_event(
+                dest_address.clone(),
+                locked_tokens,
+                lock_tranche.unlock_epoch,
+            );
         }
 
         if unlocked_amount > 0 {
@@ -78,14 +177,13 @@ pub trait LockedLaunchpadTokenSend {
         }
     }
 
-    #[view(getLaunchpadTokensLockPercentage)]
-    #[storage_mapper("launchpadTokensLockPercentage")]
-    fn launchpad_tokens_lock_percentage(&self) -> SingleValueMapper<u32>;
-
-    #[view(getLaunchpadTokensUnlockEpoch)]
-    #[storage_mapper("launchpadTokensUnlockEpoch")]
-    fn launchpad_tokens_unlock_epoch(&self) -> SingleValueMapper<u64>;
+    #[view(getLaunchpadTokensLockTranches)]
+    #[label("locked-tokens")]
+    #[storage_mapper("launchpadTokensLockTranches")]
+    fn launchpad_tokens_lock_tranches(&self) -> SingleValueMapper<ManagedVec<LockTranche>>;
 
+    #[view(getSimpleLockScAddress)]
+    #[label("locked-tokens")]
     #[storage_mapper("simpleLockScAddress")]
     fn simple_lock_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
 
@@ -1,10 +1,18 @@
 multiversx_sc::derive_imports!();
 
 use launchpad_common::{
-    config::ConfigModule, user_interactions::UserInteractionsModule,
+    common_events::EVENTS_SCHEMA_VERSION,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
+    launch_stage::LaunchStageModule,
+    user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
-use launchpad_locked_tokens::LaunchpadLockedTokens;
+use launchpad_locked_tokens::{
+    energy_factory_token_send::EnergyFactoryTokenSend,
+    locked_launchpad_token_send::{LockTranche, LockedLaunchpadTokenSend, MAX_PERCENTAGE},
+    LaunchpadLockedTokens,
+};
+use multiversx_sc::codec::multi_types::{MultiValue2, OptionalValue};
 use multiversx_sc::{
     api::ManagedTypeApi,
     codec::{TopDecode, TopEncode},
@@ -32,10 +40,27 @@ const WINNER_SELECTION_START_ROUND: u64 = 20;
 const CLAIM_START_ROUND: u64 = 30;
 const LOCK_PERCENTAGE: u32 = 5_000; // 50%
 const UNLOCK_EPOCH: u64 = 10;
+const EARLY_UNLOCK_EPOCH: u64 = 5;
+const SHORT_LOCK_EPOCHS: u64 = 180;
+const LONG_LOCK_EPOCHS: u64 = 360;
+
+const HALF_LOCKED_TRANCHES: &[(u32, u64)] = &[
+    (MAX_PERCENTAGE - LOCK_PERCENTAGE, 0),
+    (LOCK_PERCENTAGE, UNLOCK_EPOCH),
+];
+
+fn lock_tranches(tranches: &[(u32, u64)]) -> MultiValueEncoded<DebugApi, MultiValue2<u32, u64>> {
+    let mut lock_tranches = MultiValueEncoded::new();
+    for (percentage, unlock_epoch) in tranches {
+        lock_tranches.push((*percentage, *unlock_epoch).into());
+    }
+
+    lock_tranches
+}
 
 #[test]
 fn launchpad_with_locked_tokens_out_test() {
-    let _ = DebugApi::dummy();
+    DebugApi::dummy();
     let mut b_mock = BlockchainStateWrapper::new();
     let rust_zero = rust_biguint!(0);
 
@@ -62,9 +87,423 @@ fn launchpad_with_locked_tokens_out_test() {
                 CONFIRM_START_ROUND,
                 WINNER_SELECTION_START_ROUND,
                 CLAIM_START_ROUND,
+                managed_address!(simple_lock_sc.address_ref()),
+                lock_tranches(HALF_LOCKED_TRANCHES),
+            );
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&user), 1).into());
+            sc.add_tickets_endpoint(tickets);
+
+            sc.launchpad_tokens_deposited().set(true);
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_balance(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(NR_WINNING_TICKETS as u64 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock.set_esdt_local_roles(
+        simple_lock_sc.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate],
+    );
+
+    // user confirm
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
+            sc.confirm_tickets(
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    // filter + select winners
+    b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.filter_tickets(OptionalValue::None);
+            sc.select_winners(OptionalValue::None);
+        })
+        .assert_ok();
+
+    // user claim
+    b_mock.set_block_round(CLAIM_START_ROUND);
+
+    let tx_result = b_mock.execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+        sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+    });
+    tx_result.assert_ok();
+
+    // the launchpad events have the identifier, caller, round and epoch as topics
+    let event_identifiers: Vec<String> = tx_result
+        .result_logs
+        .iter()
+        .filter(|log| log.address == *lp_sc.address_ref() && log.topics.len() == 4)
+        .filter(|log| log.data.len() == 1 && log.data[0][0] == EVENTS_SCHEMA_VERSION)
+        .map(|log| String::from_utf8(log.topics[0].clone()).unwrap())
+        .collect();
+    assert_eq!(
+        event_identifiers,
+        [
+            "launchpadResultsComputed",
+            "lockLaunchpadTokens",
+            "claimLaunchpadTokens"
+        ]
+    );
+
+    // check balance
+    b_mock.check_esdt_balance(
+        &user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2),
+    );
+
+    b_mock.check_nft_balance(
+        &user,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LAUNCHPAD_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+}
+
+#[test]
+fn launchpad_with_lock_tranches_out_test() {
+    DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+    let nr_winning_tickets = 2;
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let second_user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let simple_lock_sc =
+        b_mock.create_sc_account(&rust_zero, None, SimpleLockMock::new, "simple lock wasm");
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    // 20% liquid, 40% unlocked at EARLY_UNLOCK_EPOCH and 40% at UNLOCK_EPOCH
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                nr_winning_tickets,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                managed_address!(simple_lock_sc.address_ref()),
+                lock_tranches(&[
+                    (2_000, 0),
+                    (4_000, EARLY_UNLOCK_EPOCH),
+                    (4_000, UNLOCK_EPOCH),
+                ]),
+            );
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&first_user), 1).into());
+            tickets.push((managed_address!(&second_user), 1).into());
+            sc.add_tickets_endpoint(tickets);
+
+            sc.launchpad_tokens_deposited().set(true);
+        })
+        .assert_ok();
+
+    let invalid_tranches: [(&[(u32, u64)], &str); 5] = [
+        (&[], "Lock tranches must add up to 100%"),
+        (
+            &[(0, 0), (MAX_PERCENTAGE, UNLOCK_EPOCH)],
+            "Invalid lock percentage",
+        ),
+        (
+            &[
+                (LOCK_PERCENTAGE, UNLOCK_EPOCH),
+                (LOCK_PERCENTAGE, EARLY_UNLOCK_EPOCH),
+            ],
+            "Lock tranches must be sorted by unlock epoch",
+        ),
+        (
+            &[
+                (LOCK_PERCENTAGE, EARLY_UNLOCK_EPOCH),
+                (LOCK_PERCENTAGE + 1, UNLOCK_EPOCH),
+            ],
+            "Invalid lock percentage",
+        ),
+        (
+            &[
+                (LOCK_PERCENTAGE, EARLY_UNLOCK_EPOCH),
+                (LOCK_PERCENTAGE - 1, UNLOCK_EPOCH),
+            ],
+            "Lock tranches must add up to 100%",
+        ),
+    ];
+    for (tranches, err_message) in invalid_tranches {
+        b_mock
+            .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+                sc.try_set_launchpad_tokens_lock_tranches(lock_tranches(tranches));
+            })
+            .assert_user_error(err_message);
+    }
+
+    b_mock.set_esdt_balance(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(nr_winning_tickets as u64 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock.set_esdt_local_roles(
+        simple_lock_sc.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate],
+    );
+
+    // users confirm
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    for user in [&first_user, &second_user] {
+        b_mock
+            .execute_tx(user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
+                sc.confirm_tickets(
+                    1,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            })
+            .assert_ok();
+    }
+
+    // filter + select winners
+    b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.filter_tickets(OptionalValue::None);
+            sc.select_winners(OptionalValue::None);
+        })
+        .assert_ok();
+
+    // the first user gets one locked token per locked tranche
+    b_mock.set_block_round(CLAIM_START_ROUND);
+
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(
+        &first_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 5),
+    );
+    for (locked_token_nonce, unlock_epoch) in [(1, EARLY_UNLOCK_EPOCH), (2, UNLOCK_EPOCH)] {
+        b_mock.check_nft_balance(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            locked_token_nonce,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2 / 5),
+            Some(&LockedTokenAttributes::<DebugApi> {
+                original_token_id: managed_token_id_wrapped!(LAUNCHPAD_TOKEN_ID),
+                original_token_nonce: 0,
+                unlock_epoch,
+            }),
+        );
+    }
+
+    // the second user claims after the first tranche was unlocked, and gets it liquid
+    b_mock.set_block_epoch(EARLY_UNLOCK_EPOCH);
+
+    b_mock
+        .execute_tx(&second_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(
+        &second_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 3 / 5),
+    );
+    b_mock.check_nft_balance(
+        &second_user,
+        LOCKED_TOKEN_ID,
+        3,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2 / 5),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LAUNCHPAD_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+}
+
+#[test]
+fn set_locked_tokens_config_before_claim_test() {
+    DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let simple_lock_sc =
+        b_mock.create_sc_account(&rust_zero, None, SimpleLockMock::new, "simple lock wasm");
+    let other_simple_lock_sc =
+        b_mock.create_sc_account(&rust_zero, None, SimpleLockMock::new, "simple lock wasm");
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                managed_address!(simple_lock_sc.address_ref()),
+                lock_tranches(HALF_LOCKED_TRANCHES),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_simple_lock_sc_address(managed_address!(&owner));
+        })
+        .assert_user_error("Invalid SC address");
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_launchpad_tokens_lock_tranches(lock_tranches(&[(
                 LOCK_PERCENTAGE,
                 UNLOCK_EPOCH,
+            )]));
+        })
+        .assert_user_error("Lock tranches must add up to 100%");
+
+    // only the first tranche may already be unlocked, through unlock epoch 0
+    b_mock.set_block_epoch(EARLY_UNLOCK_EPOCH);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_launchpad_tokens_lock_tranches(lock_tranches(&[
+                (MAX_PERCENTAGE - LOCK_PERCENTAGE, 0),
+                (LOCK_PERCENTAGE, EARLY_UNLOCK_EPOCH),
+            ]));
+        })
+        .assert_user_error("Invalid unlock epoch");
+
+    // the config may still be changed after the add tickets period
+    b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_simple_lock_sc_address(managed_address!(other_simple_lock_sc.address_ref()));
+            sc.set_launchpad_tokens_lock_tranches(lock_tranches(&[(MAX_PERCENTAGE, UNLOCK_EPOCH)]));
+
+            assert_eq!(
+                sc.simple_lock_sc_address().get(),
+                managed_address!(other_simple_lock_sc.address_ref())
+            );
+            let tranches: Vec<LockTranche> =
+                sc.launchpad_tokens_lock_tranches().get().iter().collect();
+            assert_eq!(
+                tranches,
+                vec![LockTranche {
+                    percentage: MAX_PERCENTAGE,
+                    unlock_epoch: UNLOCK_EPOCH,
+                }]
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CLAIM_START_ROUND);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.flags(FIRST_SALE_ROUND_ID)
+                .update(|flags| flags.were_winners_selected = true);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_simple_lock_sc_address(managed_address!(simple_lock_sc.address_ref()));
+        })
+        .assert_user_error("Claim period has started");
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_launchpad_tokens_lock_tranches(lock_tranches(HALF_LOCKED_TRANCHES));
+        })
+        .assert_user_error("Claim period has started");
+}
+
+#[test]
+fn launchpad_with_energy_locked_tokens_out_test() {
+    DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let simple_lock_sc =
+        b_mock.create_sc_account(&rust_zero, None, SimpleLockMock::new, "simple lock wasm");
+    let energy_factory_sc = b_mock.create_sc_account(
+        &rust_zero,
+        None,
+        EnergyFactoryMock::new,
+        "energy factory wasm",
+    );
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    // setup
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
                 managed_address!(simple_lock_sc.address_ref()),
+                lock_tranches(HALF_LOCKED_TRANCHES),
+            );
+
+            let mut lock_epochs_options = MultiValueEncoded::new();
+            lock_epochs_options.push(SHORT_LOCK_EPOCHS);
+            lock_epochs_options.push(LONG_LOCK_EPOCHS);
+            sc.set_energy_factory_lock_options(
+                managed_address!(energy_factory_sc.address_ref()),
+                lock_epochs_options,
             );
 
             let mut tickets = MultiValueEncoded::new();
@@ -82,7 +521,7 @@ fn launchpad_with_locked_tokens_out_test() {
     );
 
     b_mock.set_esdt_local_roles(
-        simple_lock_sc.address_ref(),
+        energy_factory_sc.address_ref(),
         LOCKED_TOKEN_ID,
         &[EsdtLocalRole::NftCreate],
     );
@@ -92,7 +531,12 @@ fn launchpad_with_locked_tokens_out_test() {
 
     b_mock
         .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
-            sc.confirm_tickets(1);
+            sc.confirm_tickets(
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
         })
         .assert_ok();
 
@@ -101,17 +545,46 @@ fn launchpad_with_locked_tokens_out_test() {
 
     b_mock
         .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
-            sc.filter_tickets();
-            sc.select_winners();
+            sc.filter_tickets(OptionalValue::None);
+            sc.select_winners(OptionalValue::None);
         })
         .assert_ok();
 
     // user claim
     b_mock.set_block_round(CLAIM_START_ROUND);
 
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let mut lock_epochs_options = MultiValueEncoded::new();
+            lock_epochs_options.push(SHORT_LOCK_EPOCHS);
+            sc.set_energy_factory_lock_options(
+                managed_address!(energy_factory_sc.address_ref()),
+                lock_epochs_options,
+            );
+        })
+        .assert_user_error("Claim period has started");
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_user_error("Must choose a lock period");
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_with_lock_period_endpoint(
+                SHORT_LOCK_EPOCHS + 1,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Lock period not allowed");
+
     b_mock
         .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
-            sc.claim_launchpad_tokens_endpoint();
+            sc.claim_launchpad_tokens_with_lock_period_endpoint(
+                LONG_LOCK_EPOCHS,
+                OptionalValue::None,
+            );
         })
         .assert_ok();
 
@@ -130,7 +603,7 @@ fn launchpad_with_locked_tokens_out_test() {
         Some(&LockedTokenAttributes::<DebugApi> {
             original_token_id: managed_token_id_wrapped!(LAUNCHPAD_TOKEN_ID),
             original_token_nonce: 0,
-            unlock_epoch: UNLOCK_EPOCH,
+            unlock_epoch: LONG_LOCK_EPOCHS,
         }),
     );
 }
@@ -225,6 +698,77 @@ impl SimpleLockMock {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct EnergyFactoryMock {}
+
+impl ContractBase for EnergyFactoryMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for EnergyFactoryMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name != LOCK_FN_NAME {
+            return false;
+        }
+
+        self.call_lock_tokens();
+
+        true
+    }
+}
+
+impl EnergyFactoryMock {
+    pub fn new() -> Self {
+        EnergyFactoryMock {}
+    }
+
+    fn call_lock_tokens(&self) {
+        let api = TxContextStack::static_peek();
+        let args = api.input_ref().args.clone();
+        if args.is_empty() || args.len() > 2 {
+            panic!("Invalid args");
+        }
+
+        let lock_epochs = u64::top_decode(args[0].clone()).unwrap();
+        let dest_addr = match args.get(1) {
+            Some(arg) => ManagedAddress::<DebugApi>::top_decode(arg.clone()).unwrap(),
+            None => self.blockchain().get_caller(),
+        };
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let attributes = LockedTokenAttributes {
+            original_token_id: payment.token_identifier.clone(),
+            original_token_nonce: payment.token_nonce,
+            unlock_epoch: self.blockchain().get_block_epoch() + lock_epochs,
+        };
+        let locked_token_nonce = self.send().esdt_nft_create_compact_named(
+            &managed_token_id!(LOCKED_TOKEN_ID),
+            &payment.amount,
+            &payment.token_identifier.clone().into_name(),
+            &attributes,
+        );
+        self.send().direct_esdt(
+            &dest_addr,
+            &managed_token_id!(LOCKED_TOKEN_ID),
+            locked_token_nonce,
+            &payment.amount,
+        );
+
+        let output_payment = EsdtTokenPayment::new(
+            managed_token_id!(LOCKED_TOKEN_ID),
+            locked_token_nonce,
+            payment.amount,
+        );
+        let mut result = Vec::new();
+        output_payment.top_encode(&mut result).unwrap();
+        api.tx_result_cell
+            .try_lock()
+            .unwrap()
+            .result_values
+            .push(result);
+    }
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedDecode, NestedEncode, PartialEq, Debug)]
 pub struct LockedTokenAttributes<M: ManagedTypeApi> {
     pub original_token_id: EgldOrEsdtTokenIdentifier<M>,
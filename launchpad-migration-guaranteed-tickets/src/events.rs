@@ -0,0 +1,93 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+use crate::staking_allowance::{AllowanceSource, AllowanceTier};
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetAllowanceSnapshotEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    staking_sc_address: ManagedAddress<M>,
+    snapshot_round: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetAllowanceTiersEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    source: AllowanceSource,
+    tiers: ManagedVec<M, AllowanceTier<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_set_allowance_snapshot_event(
+        &self,
+        staking_sc_address: ManagedAddress,
+        snapshot_round: u64,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_allowance_snapshot_event(
+            user.clone(),
+            round,
+            epoch,
+            SetAllowanceSnapshotEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                staking_sc_address,
+                snapshot_round,
+            },
+        )
+    }
+
+    #[event("setAllowanceSnapshot")]
+    fn set_allowance_snapshot_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_allowance_snapshot_event: SetAllowanceSnapshotEvent<Self::Api>,
+    );
+
+    fn emit_set_allowance_tiers_event(
+        &self,
+        source: AllowanceSource,
+        tiers: ManagedVec<AllowanceTier<Self::Api>>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_allowance_tiers_event(
+            user.clone(),
+            round,
+            epoch,
+            SetAllowanceTiersEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                source,
+                tiers,
+            },
+        )
+    }
+
+    #[event("setAllowanceTiers")]
+    fn set_allowance_tiers_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_allowance_tiers_event: SetAllowanceTiersEvent<Self::Api>,
+    );
+}
@@ -2,9 +2,12 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID,
     ongoing_operation::{CONTINUE_OP, STOP_OP},
+    operation_progress::{OperationPhase, OperationProgress},
     random::Random,
-    tickets::{TicketRange, WINNING_TICKET},
+    selection_pipeline::AdditionalSelectionStep,
+    tickets::{TicketRanges, WINNING_TICKET},
 };
 use multiversx_sc::api::CryptoApi;
 
@@ -41,6 +44,7 @@ pub trait GuaranteedTicketWinnersModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
     + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
 {
     fn select_guaranteed_tickets(
@@ -51,6 +55,9 @@ pub trait GuaranteedTicketWinnersModule:
             self.min_confirmed_for_guaranteed_ticket().get();
         let mut users_whitelist = self.users_with_guaranteed_ticket();
         let mut users_left = users_whitelist.len();
+        if self.current_ongoing_operation().is_empty() {
+            self.guaranteed_users_selection_total().set(users_left);
+        }
 
         self.run_while_it_has_gas(|| {
             if users_left == 0 {
@@ -65,7 +72,9 @@ pub trait GuaranteedTicketWinnersModule:
             if user_ticket_status_mapper.is_empty() {
                 return CONTINUE_OP;
             }
-            let user_confirmed_tickets = self.nr_confirmed_tickets(&current_user).get();
+            let user_confirmed_tickets = self
+                .nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &current_user)
+                .get();
             let user_ticket_status = user_ticket_status_mapper.get();
             let user_total_tickets_allowance = user_ticket_status.staking_tickets_allowance
                 + user_ticket_status.energy_tickets_allowance;
@@ -90,14 +99,15 @@ pub trait GuaranteedTicketWinnersModule:
             }
 
             if user_guaranteed_tickets_no > 0 {
-                let ticket_range_mapper = self.ticket_range_for_address(&current_user);
-                if ticket_range_mapper.is_empty() {
+                let ticket_ranges_mapper =
+                    self.ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &current_user);
+                if ticket_ranges_mapper.is_empty() {
                     op.leftover_tickets += user_guaranteed_tickets_no;
                     return CONTINUE_OP;
                 }
 
-                let ticket_range: TicketRange = ticket_range_mapper.get();
-                let user_winning_tickets_no = self.winning_tickets_in_range(&ticket_range);
+                let ticket_ranges: TicketRanges<Self::Api> = ticket_ranges_mapper.get();
+                let user_winning_tickets_no = self.winning_tickets_in_ranges(&ticket_ranges);
 
                 if user_guaranteed_tickets_no <= user_winning_tickets_no {
                     op.leftover_tickets += user_guaranteed_tickets_no;
@@ -109,15 +119,21 @@ pub trait GuaranteedTicketWinnersModule:
 
                 op.leftover_tickets += user_guaranteed_tickets_no - remaining_tickets_to_be_won;
 
-                let mut current_ticket = ticket_range.first_id;
-                while remaining_tickets_to_be_won > 0 {
-                    let is_winning_ticket = self.ticket_status(current_ticket).get();
-                    if !is_winning_ticket {
-                        self.ticket_status(current_ticket).set(WINNING_TICKET);
-                        op.total_additional_winning_tickets += 1;
-                        remaining_tickets_to_be_won -= 1;
+                for ticket_range in ticket_ranges.iter() {
+                    let mut current_ticket = ticket_range.first_id;
+                    while remaining_tickets_to_be_won > 0 && current_ticket <= ticket_range.last_id
+                    {
+                        let is_winning_ticket = self
+                            .ticket_status(FIRST_SALE_ROUND_ID, current_ticket)
+                            .get();
+                        if !is_winning_ticket {
+                            self.ticket_status(FIRST_SALE_ROUND_ID, current_ticket)
+                                .set(WINNING_TICKET);
+                            op.total_additional_winning_tickets += 1;
+                            remaining_tickets_to_be_won -= 1;
+                        }
+                        current_ticket += 1;
                     }
-                    current_ticket += 1;
                 }
             }
             CONTINUE_OP
@@ -128,8 +144,8 @@ pub trait GuaranteedTicketWinnersModule:
         &self,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
     ) -> OperationCompletionStatus {
-        let nr_original_winning_tickets = self.nr_winning_tickets().get();
-        let last_ticket_pos = self.get_total_tickets();
+        let nr_original_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let last_ticket_pos = self.get_total_tickets(FIRST_SALE_ROUND_ID);
 
         self.run_while_it_has_gas(|| {
             if nr_original_winning_tickets + op.total_additional_winning_tickets >= last_ticket_pos
@@ -161,12 +177,14 @@ pub trait GuaranteedTicketWinnersModule:
         })
     }
 
-    fn winning_tickets_in_range(&self, ticket_range: &TicketRange) -> usize {
+    fn winning_tickets_in_ranges(&self, ticket_ranges: &TicketRanges<Self::Api>) -> usize {
         let mut winning_tickets_no = 0;
-        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
-            if ticket_status == WINNING_TICKET {
-                winning_tickets_no += 1;
+        for ticket_range in ticket_ranges.iter() {
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                let ticket_status = self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get();
+                if ticket_status == WINNING_TICKET {
+                    winning_tickets_no += 1;
+                }
             }
         }
 
@@ -179,25 +197,96 @@ pub trait GuaranteedTicketWinnersModule:
         current_ticket_position: usize,
         last_ticket_position: usize,
     ) -> AdditionalSelectionTryResult {
-        let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
+        let current_ticket_id =
+            self.get_ticket_id_from_pos(FIRST_SALE_ROUND_ID, current_ticket_position);
         if self.is_already_winning_ticket(current_ticket_id) {
             return AdditionalSelectionTryResult::CurrentAlreadyWinning;
         }
 
         let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
-        let winning_ticket_id = self.get_ticket_id_from_pos(rand_pos);
+        let winning_ticket_id = self.get_ticket_id_from_pos(FIRST_SALE_ROUND_ID, rand_pos);
         if self.is_already_winning_ticket(winning_ticket_id) {
             return AdditionalSelectionTryResult::NewlySelectedAlreadyWinning;
         }
 
-        self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
-        self.ticket_status(winning_ticket_id).set(WINNING_TICKET);
+        self.ticket_pos_to_id(FIRST_SALE_ROUND_ID, rand_pos)
+            .set(current_ticket_id);
+        self.ticket_status(FIRST_SALE_ROUND_ID, winning_ticket_id)
+            .set(WINNING_TICKET);
 
         AdditionalSelectionTryResult::Ok
     }
 
     #[inline]
     fn is_already_winning_ticket(&self, ticket_id: usize) -> bool {
-        self.ticket_status(ticket_id).get() == WINNING_TICKET
+        self.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get() == WINNING_TICKET
+    }
+
+    fn guaranteed_tickets_selection_progress(
+        &self,
+        op: &GuaranteedTicketsSelectionOperation<Self::Api>,
+    ) -> OperationProgress {
+        let users_left = self.users_with_guaranteed_ticket().len();
+        if users_left > 0 {
+            let total_users_mapper = self.guaranteed_users_selection_total();
+            let users_processed = if self.current_ongoing_operation().is_empty() {
+                0
+            } else {
+                total_users_mapper.get() - users_left
+            };
+
+            return OperationProgress::new(
+                OperationPhase::GuaranteedTicketsSelection,
+                users_processed,
+                users_left,
+            );
+        }
+
+        let nr_original_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let last_ticket_pos = self.get_total_tickets(FIRST_SALE_ROUND_ID);
+        let positions_left = last_ticket_pos
+            .saturating_sub(nr_original_winning_tickets + op.total_additional_winning_tickets);
+
+        OperationProgress::new(
+            OperationPhase::LeftoverTicketsDistribution,
+            op.leftover_ticket_pos_offset - 1,
+            core::cmp::min(op.leftover_tickets, positions_left),
+        )
+    }
+
+    #[storage_mapper("guaranteedUsersSelectionTotal")]
+    fn guaranteed_users_selection_total(&self) -> SingleValueMapper<usize>;
+}
+
+/// Selects the guaranteed tickets, then redistributes the ones that could not be awarded
+pub struct GuaranteedTicketsStep;
+
+impl<Sc: GuaranteedTicketWinnersModule> AdditionalSelectionStep<Sc> for GuaranteedTicketsStep {
+    type Operation = GuaranteedTicketsSelectionOperation<Sc::Api>;
+
+    fn run(&self, sc: &Sc, op: &mut Self::Operation) -> OperationCompletionStatus {
+        let run_result = sc.select_guaranteed_tickets(op);
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            return run_result;
+        }
+
+        sc.distribute_leftover_tickets(op)
+    }
+
+    fn on_complete(&self, sc: &Sc, op: &Self::Operation) {
+        let ticket_price = sc.ticket_price(FIRST_SALE_ROUND_ID).get();
+        let claimable_ticket_payment =
+            ticket_price.amount * (op.total_additional_winning_tickets as u32);
+        sc.claimable_ticket_payment()
+            .update(|claim_amt| *claim_amt += claimable_ticket_payment);
+
+        sc.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .update(|nr_winning| *nr_winning += op.total_additional_winning_tickets);
+
+        sc.emit_distribute_guaranteed_tickets_completed_event(op.total_additional_winning_tickets);
+    }
+
+    fn progress(&self, sc: &Sc, op: &Self::Operation) -> OperationProgress {
+        sc.guaranteed_tickets_selection_progress(op)
     }
 }
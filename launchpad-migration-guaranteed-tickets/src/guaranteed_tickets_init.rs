@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, pagination::get_page};
+
 pub const STAKING_GUARANTEED_TICKETS_NO: usize = 1;
 pub const MIGRATION_GUARANTEED_TICKETS_NO: usize = 1;
 
@@ -21,6 +23,10 @@ impl UserTicketsStatus {
             migration_guaranteed_tickets: 0usize,
         }
     }
+
+    pub fn guaranteed_tickets(&self) -> usize {
+        self.staking_guaranteed_tickets + self.migration_guaranteed_tickets
+    }
 }
 
 #[multiversx_sc::module]
@@ -29,54 +35,152 @@ pub trait GuaranteedTicketsInitModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
 {
     fn add_tickets_with_guaranteed_winners(
         &self,
         address_number_pairs: MultiValueEncoded<MultiValue4<ManagedAddress, usize, usize, bool>>,
     ) {
-        self.require_add_tickets_period();
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
 
-        let min_confirmed_for_guaranteed_ticket = self.min_confirmed_for_guaranteed_ticket().get();
-        let mut guaranteed_ticket_whitelist = self.users_with_guaranteed_ticket();
-        let mut total_winning_tickets = self.nr_winning_tickets().get();
+        let mut total_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
 
+        let mut total_users_count = 0;
+        let mut total_tickets_added = 0;
+        let mut total_guaranteed_tickets_added = 0;
         for multi_arg in address_number_pairs {
             let (buyer, nr_staking_tickets, nr_energy_tickets, has_migrated_tokens) =
                 multi_arg.into_tuple();
-            self.try_create_tickets(buyer.clone(), nr_staking_tickets + nr_energy_tickets);
+            let nr_tickets = nr_staking_tickets + nr_energy_tickets;
+            let first_ticket_id =
+                self.try_create_tickets(FIRST_SALE_ROUND_ID, buyer.clone(), nr_tickets);
 
-            let mut user_ticket_status =
-                UserTicketsStatus::new(nr_staking_tickets, nr_energy_tickets);
+            let user_ticket_status = self.grant_guaranteed_tickets(
+                &buyer,
+                UserTicketsStatus::new(nr_staking_tickets, nr_energy_tickets),
+                has_migrated_tokens,
+                &mut total_winning_tickets,
+                &mut total_guaranteed_tickets,
+            );
+            let user_guaranteed_tickets = user_ticket_status.guaranteed_tickets();
+            self.user_ticket_status(&buyer).set(user_ticket_status);
+            self.emit_add_user_tickets_event(
+                FIRST_SALE_ROUND_ID,
+                buyer,
+                first_ticket_id,
+                nr_tickets,
+                user_guaranteed_tickets,
+            );
 
-            if nr_staking_tickets >= min_confirmed_for_guaranteed_ticket {
-                require!(
-                    total_winning_tickets > 0,
-                    "Too many users with guaranteed ticket"
-                );
-                let _ = guaranteed_ticket_whitelist.insert(buyer.clone());
-                total_winning_tickets -= STAKING_GUARANTEED_TICKETS_NO;
-                total_guaranteed_tickets += STAKING_GUARANTEED_TICKETS_NO;
-                user_ticket_status.staking_guaranteed_tickets = STAKING_GUARANTEED_TICKETS_NO;
+            total_users_count += 1;
+            total_tickets_added += nr_tickets;
+            total_guaranteed_tickets_added += user_guaranteed_tickets;
+        }
+
+        self.total_guaranteed_tickets()
+            .set(total_guaranteed_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(total_winning_tickets);
+
+        self.emit_add_tickets_event(
+            FIRST_SALE_ROUND_ID,
+            total_users_count,
+            total_tickets_added,
+            total_guaranteed_tickets_added,
+        );
+    }
+
+    /// Sets the ticket allowances and guaranteed entries of users who were already added,
+    /// or adds new users. Users set to 0 tickets are removed from the sale.
+    fn set_tickets_with_guaranteed_winners(
+        &self,
+        address_number_pairs: MultiValueEncoded<MultiValue4<ManagedAddress, usize, usize, bool>>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+
+        let mut total_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
+        let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
+        for multi_arg in address_number_pairs {
+            let (address, nr_staking_tickets, nr_energy_tickets, has_migrated_tokens) =
+                multi_arg.into_tuple();
+            require!(
+                self.blacklist_user_ticket_status(&address).is_empty(),
+                "Cannot change the allowance of a blacklisted user"
+            );
+
+            let user_ticket_status_mapper = self.user_ticket_status(&address);
+            if !user_ticket_status_mapper.is_empty() {
+                let previous_guaranteed_tickets =
+                    user_ticket_status_mapper.take().guaranteed_tickets();
+                if previous_guaranteed_tickets > 0 {
+                    let _ = self.users_with_guaranteed_ticket().swap_remove(&address);
+                    total_winning_tickets += previous_guaranteed_tickets;
+                    total_guaranteed_tickets -= previous_guaranteed_tickets;
+                }
             }
 
-            if has_migrated_tokens {
-                require!(
-                    total_winning_tickets > 0,
-                    "Too many users with guaranteed ticket"
+            let nr_tickets = nr_staking_tickets + nr_energy_tickets;
+            let previous_nr_tickets =
+                self.try_set_ticket_allowance(FIRST_SALE_ROUND_ID, &address, nr_tickets);
+
+            let mut user_guaranteed_tickets = 0;
+            if nr_tickets > 0 {
+                let user_ticket_status = self.grant_guaranteed_tickets(
+                    &address,
+                    UserTicketsStatus::new(nr_staking_tickets, nr_energy_tickets),
+                    has_migrated_tokens,
+                    &mut total_winning_tickets,
+                    &mut total_guaranteed_tickets,
                 );
-                let _ = guaranteed_ticket_whitelist.insert(buyer.clone());
-                total_winning_tickets -= MIGRATION_GUARANTEED_TICKETS_NO;
-                total_guaranteed_tickets += MIGRATION_GUARANTEED_TICKETS_NO;
-                user_ticket_status.migration_guaranteed_tickets = MIGRATION_GUARANTEED_TICKETS_NO;
+                user_guaranteed_tickets = user_ticket_status.guaranteed_tickets();
+                user_ticket_status_mapper.set(user_ticket_status);
             }
 
-            self.user_ticket_status(&buyer).set(user_ticket_status);
+            self.emit_set_user_ticket_allowance_event(
+                FIRST_SALE_ROUND_ID,
+                address,
+                previous_nr_tickets,
+                nr_tickets,
+                user_guaranteed_tickets,
+            );
         }
 
         self.total_guaranteed_tickets()
             .set(total_guaranteed_tickets);
-        self.nr_winning_tickets().set(total_winning_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(total_winning_tickets);
+    }
+
+    /// Reserves the guaranteed tickets the user is entitled to out of the winning tickets
+    fn grant_guaranteed_tickets(
+        &self,
+        user: &ManagedAddress,
+        mut user_ticket_status: UserTicketsStatus,
+        has_migrated_tokens: bool,
+        total_winning_tickets: &mut usize,
+        total_guaranteed_tickets: &mut usize,
+    ) -> UserTicketsStatus {
+        let min_confirmed_for_guaranteed_ticket = self.min_confirmed_for_guaranteed_ticket().get();
+        if user_ticket_status.staking_tickets_allowance >= min_confirmed_for_guaranteed_ticket {
+            user_ticket_status.staking_guaranteed_tickets = STAKING_GUARANTEED_TICKETS_NO;
+        }
+        if has_migrated_tokens {
+            user_ticket_status.migration_guaranteed_tickets = MIGRATION_GUARANTEED_TICKETS_NO;
+        }
+
+        let user_guaranteed_tickets = user_ticket_status.guaranteed_tickets();
+        if user_guaranteed_tickets > 0 {
+            require!(
+                *total_winning_tickets >= user_guaranteed_tickets,
+                "Too many users with guaranteed ticket"
+            );
+            let _ = self.users_with_guaranteed_ticket().insert(user.clone());
+            *total_winning_tickets -= user_guaranteed_tickets;
+            *total_guaranteed_tickets += user_guaranteed_tickets;
+        }
+
+        user_ticket_status
     }
 
     fn clear_users_with_guaranteed_ticket_after_blacklist(
@@ -100,7 +204,7 @@ pub trait GuaranteedTicketsInitModule:
         }
 
         if nr_winning_tickets_removed > 0 {
-            self.nr_winning_tickets()
+            self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
                 .update(|nr_winning| *nr_winning += nr_winning_tickets_removed);
         }
         self.total_guaranteed_tickets()
@@ -108,13 +212,15 @@ pub trait GuaranteedTicketsInitModule:
     }
 
     fn remove_guaranteed_tickets_from_blacklist(&self, users: &ManagedVec<ManagedAddress>) {
-        let mut nr_winning_tickets = self.nr_winning_tickets().get();
+        let mut nr_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
         let mut whitelist = self.users_with_guaranteed_ticket();
         for user in users {
             let user_ticket_status_mapper = self.user_ticket_status(&user);
             if !user_ticket_status_mapper.is_empty()
-                || self.ticket_range_for_address(&user).is_empty()
+                || self
+                    .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &user)
+                    .is_empty()
             {
                 continue;
             }
@@ -129,11 +235,37 @@ pub trait GuaranteedTicketsInitModule:
             }
         }
 
-        self.nr_winning_tickets().set(nr_winning_tickets);
+        self.nr_winning_tickets(FIRST_SALE_ROUND_ID)
+            .set(nr_winning_tickets);
         self.total_guaranteed_tickets()
             .set(total_guaranteed_tickets);
     }
 
+    fn get_user_guaranteed_tickets(&self, user: &ManagedAddress) -> usize {
+        let user_ticket_status_mapper = self.user_ticket_status(user);
+        if user_ticket_status_mapper.is_empty() {
+            return 0;
+        }
+
+        let user_ticket_status = user_ticket_status_mapper.get();
+        user_ticket_status.staking_guaranteed_tickets
+            + user_ticket_status.migration_guaranteed_tickets
+    }
+
+    fn clear_user_ticket_status(&self, user: &ManagedAddress) {
+        self.user_ticket_status(user).clear();
+        self.blacklist_user_ticket_status(user).clear();
+    }
+
+    #[view(getGuaranteedUsers)]
+    fn get_guaranteed_users(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        get_page(&self.users_with_guaranteed_ticket(), offset, limit)
+    }
+
     #[storage_mapper("minConfirmedForGuaranteedTicket")]
     fn min_confirmed_for_guaranteed_ticket(&self) -> SingleValueMapper<usize>;
 
@@ -3,12 +3,17 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::launch_stage::Flags;
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID, launch_stage::Flags, operation_progress::OperationProgress,
+    selection_pipeline::SelectionSteps, user_info::UserInfo,
+};
 
-use crate::guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation;
+use crate::guaranteed_ticket_winners::GuaranteedTicketsStep;
 
+pub mod events;
 pub mod guaranteed_ticket_winners;
 pub mod guaranteed_tickets_init;
+pub mod staking_allowance;
 
 pub type UserTicketsStatus = MultiValue5<usize, usize, usize, usize, usize>;
 
@@ -18,16 +23,25 @@ pub trait LaunchpadMigrationGuaranteedTickets:
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
     + launchpad_common::tickets::TicketsModule
     + launchpad_common::winner_selection::WinnerSelectionModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::operation_progress::OperationProgressModule
     + launchpad_common::permissions::PermissionsModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::user_info::UserInfoModule
     + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::selection_pipeline::SelectionPipelineModule
+    + launchpad_common::claim_and_stake::ClaimAndStakeModule
     + guaranteed_tickets_init::GuaranteedTicketsInitModule
     + guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+    + staking_allowance::StakingAllowanceModule
+    + events::EventsModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -73,11 +87,22 @@ pub trait LaunchpadMigrationGuaranteedTickets:
         self.add_tickets_with_guaranteed_winners(address_number_pairs);
     }
 
+    /// Sets the absolute ticket allowance of users who were already added, or adds new users.
+    /// Setting an allowance to 0 removes the user from the sale.
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<MultiValue4<ManagedAddress, usize, usize, bool>>,
+    ) {
+        self.set_tickets_with_guaranteed_winners(address_number_pairs);
+    }
+
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
     fn deposit_launchpad_tokens_endpoint(&self) {
-        let base_selection_winning_tickets = self.nr_winning_tickets().get();
+        let base_selection_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         let reserved_tickets = self.total_guaranteed_tickets().get();
         let total_tickets = base_selection_winning_tickets + reserved_tickets;
 
@@ -103,55 +128,24 @@ pub trait LaunchpadMigrationGuaranteedTickets:
 
     #[endpoint(distributeGuaranteedTickets)]
     fn distribute_guaranteed_tickets_endpoint(&self) -> OperationCompletionStatus {
-        self.require_winner_selection_period();
-
-        let flags_mapper = self.flags();
-        let mut flags = flags_mapper.get();
-        require!(
-            flags.were_winners_selected,
-            "Must select winners for base launchpad first"
-        );
-        require!(
-            !flags.was_additional_step_completed,
-            "Already distributed tickets"
-        );
-
-        let mut current_operation: GuaranteedTicketsSelectionOperation<Self::Api> =
-            self.load_additional_selection_operation();
-        let first_op_run_result = self.select_guaranteed_tickets(&mut current_operation);
-        if first_op_run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
-            self.save_additional_selection_progress(&current_operation);
-
-            return first_op_run_result;
-        }
-
-        let second_op_run_result = self.distribute_leftover_tickets(&mut current_operation);
-        match second_op_run_result {
-            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
-                self.save_additional_selection_progress(&current_operation);
-            }
-            OperationCompletionStatus::Completed => {
-                flags.was_additional_step_completed = true;
-                flags_mapper.set(&flags);
-
-                let ticket_price = self.ticket_price().get();
-                let claimable_ticket_payment = ticket_price.amount
-                    * (current_operation.total_additional_winning_tickets as u32);
-                self.claimable_ticket_payment()
-                    .update(|claim_amt| *claim_amt += claimable_ticket_payment);
-
-                self.nr_winning_tickets().update(|nr_winning| {
-                    *nr_winning += current_operation.total_additional_winning_tickets
-                });
-            }
-        };
+        self.run_selection_pipeline(self.additional_selection_steps())
+    }
 
-        second_op_run_result
+    fn additional_selection_steps(&self) -> SelectionSteps<Self> {
+        &[&GuaranteedTicketsStep]
     }
 
     #[endpoint(claimLaunchpadTokens)]
     fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+        self.claim_launchpad_tokens(FIRST_SALE_ROUND_ID, Self::default_send_launchpad_tokens_fn);
+    }
+
+    /// Sends the claimed launchpad tokens to the staking endpoint set by the owner,
+    /// and the resulting position to the caller
+    #[endpoint(claimLaunchpadTokensAndStake)]
+    fn claim_launchpad_tokens_and_stake_endpoint(&self) {
+        self.require_claim_staking_endpoint_set();
+        self.claim_launchpad_tokens(FIRST_SALE_ROUND_ID, Self::stake_launchpad_tokens_fn);
     }
 
     #[only_owner]
@@ -165,7 +159,9 @@ pub trait LaunchpadMigrationGuaranteedTickets:
         let user_ticket_status_mapper = self.user_ticket_status(&address);
         require!(!user_ticket_status_mapper.is_empty(), "User not found");
         let user_ticket_status = user_ticket_status_mapper.get();
-        let user_confirmed_tickets_no = self.nr_confirmed_tickets(&address).get();
+        let user_confirmed_tickets_no = self
+            .nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &address)
+            .get();
 
         (
             user_ticket_status.staking_tickets_allowance,
@@ -176,4 +172,43 @@ pub trait LaunchpadMigrationGuaranteedTickets:
         )
             .into()
     }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::no_vesting_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.cleanup_storage(
+            Self::clear_user_ticket_status,
+            Self::no_reserved_launchpad_tokens,
+        )
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(&self, address: ManagedAddress) -> UserInfo<Self::Api> {
+        self.get_user_info_base(FIRST_SALE_ROUND_ID, &address, Self::add_user_info)
+    }
+
+    fn add_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        user_info.guaranteed_tickets = self.get_user_guaranteed_tickets(address);
+    }
+
+    fn get_additional_selection_progress(&self, encoded_data: ManagedBuffer) -> OperationProgress {
+        self.get_selection_pipeline_progress(self.additional_selection_steps(), encoded_data)
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(&self) -> OperationProgress {
+        self.get_operation_progress(FIRST_SALE_ROUND_ID, Self::get_additional_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(&self, gas_limit: u64) -> usize {
+        let progress = self.get_ongoing_operation_progress();
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
 }
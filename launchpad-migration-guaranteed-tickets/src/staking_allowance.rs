@@ -0,0 +1,185 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::config::FIRST_SALE_ROUND_ID;
+
+pub mod staking_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait StakingProxy {
+        #[view(getUserStakeAtRound)]
+        fn get_user_stake_at_round(&self, user: ManagedAddress, round: u64) -> BigUint;
+
+        #[view(getUserEnergyAtRound)]
+        fn get_user_energy_at_round(&self, user: ManagedAddress, round: u64) -> BigUint;
+    }
+}
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum AllowanceSource {
+    Staking,
+    Energy,
+}
+
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct AllowanceTier<M: ManagedTypeApi> {
+    pub min_amount: BigUint<M>,
+    pub nr_tickets: usize,
+}
+
+#[multiversx_sc::module]
+pub trait StakingAllowanceModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::events::EventsModule
+{
+    /// The staking contract must be able to report stake and energy as they were at `snapshot_round`.
+    /// Users who already registered keep the allowance computed at registration.
+    #[only_owner]
+    #[endpoint(setAllowanceSnapshot)]
+    fn set_allowance_snapshot(&self, staking_sc_address: ManagedAddress, snapshot_round: u64) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+        require!(
+            !staking_sc_address.is_zero()
+                && self.blockchain().is_smart_contract(&staking_sc_address),
+            "Invalid SC address"
+        );
+
+        self.staking_sc_address().set(&staking_sc_address);
+        self.allowance_snapshot_round().set(snapshot_round);
+        self.emit_set_allowance_snapshot_event(staking_sc_address, snapshot_round);
+    }
+
+    /// Tiers are pairs of (minimum amount, number of tickets), sorted ascending by both.
+    /// Users get the tickets of the highest tier they reach, or none below the first tier.
+    #[only_owner]
+    #[endpoint(setAllowanceTiers)]
+    fn set_allowance_tiers(
+        &self,
+        source: AllowanceSource,
+        tiers: MultiValueEncoded<MultiValue2<BigUint, usize>>,
+    ) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+
+        let mut tiers_vec = ManagedVec::new();
+        let mut previous_tier: Option<AllowanceTier<Self::Api>> = None;
+        for tier in tiers {
+            let (min_amount, nr_tickets) = tier.into_tuple();
+            let tier = AllowanceTier {
+                min_amount,
+                nr_tickets,
+            };
+            match &previous_tier {
+                Some(previous_tier) => require!(
+                    tier.min_amount > previous_tier.min_amount
+                        && tier.nr_tickets > previous_tier.nr_tickets,
+                    "Tiers must be sorted ascending"
+                ),
+                None => require!(
+                    tier.min_amount > 0 && tier.nr_tickets > 0,
+                    "Invalid allowance tier"
+                ),
+            }
+
+            tiers_vec.push(tier.clone());
+            previous_tier = Some(tier);
+        }
+
+        self.allowance_tiers(source).set(&tiers_vec);
+        self.emit_set_allowance_tiers_event(source, tiers_vec);
+    }
+
+    /// Creates the caller's tickets out of their stake and energy at the snapshot round.
+    /// Migration guaranteed tickets are still granted by the owner, through `setTicketAllowances`.
+    #[endpoint(registerForLaunchpad)]
+    fn register_for_launchpad(&self) {
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
+
+        let caller = self.blockchain().get_caller();
+        require!(!self.is_user_blacklisted(&caller), "User blacklisted");
+
+        let snapshot_round_mapper = self.allowance_snapshot_round();
+        require!(
+            !snapshot_round_mapper.is_empty(),
+            "Allowance snapshot not set"
+        );
+        let snapshot_round = snapshot_round_mapper.get();
+        require!(
+            self.blockchain().get_block_round() > snapshot_round,
+            "Snapshot round not reached"
+        );
+
+        let staking_sc_address = self.staking_sc_address().get();
+        let stake: BigUint = self
+            .staking_proxy_builder(staking_sc_address.clone())
+            .get_user_stake_at_round(caller.clone(), snapshot_round)
+            .execute_on_dest_context();
+        let energy: BigUint = self
+            .staking_proxy_builder(staking_sc_address)
+            .get_user_energy_at_round(caller.clone(), snapshot_round)
+            .execute_on_dest_context();
+
+        let nr_staking_tickets = self.get_tier_tickets(AllowanceSource::Staking, &stake);
+        let nr_energy_tickets = self.get_tier_tickets(AllowanceSource::Energy, &energy);
+        require!(
+            nr_staking_tickets + nr_energy_tickets > 0,
+            "Not eligible for tickets"
+        );
+
+        let mut address_number_pairs = MultiValueEncoded::new();
+        address_number_pairs.push((caller, nr_staking_tickets, nr_energy_tickets, false).into());
+        self.add_tickets_with_guaranteed_winners(address_number_pairs);
+    }
+
+    fn get_tier_tickets(&self, source: AllowanceSource, amount: &BigUint) -> usize {
+        let mut nr_tickets = 0;
+        for tier in self.allowance_tiers(source).get().iter() {
+            if &tier.min_amount > amount {
+                break;
+            }
+
+            nr_tickets = tier.nr_tickets;
+        }
+
+        nr_tickets
+    }
+
+    #[proxy]
+    fn staking_proxy_builder(&self, sc_address: ManagedAddress) -> staking_proxy::Proxy<Self::Api>;
+
+    #[view(getStakingScAddress)]
+    #[storage_mapper("stakingScAddress")]
+    fn staking_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAllowanceSnapshotRound)]
+    #[storage_mapper("allowanceSnapshotRound")]
+    fn allowance_snapshot_round(&self) -> SingleValueMapper<u64>;
+
+    #[view(getAllowanceTiers)]
+    #[storage_mapper("allowanceTiers")]
+    fn allowance_tiers(
+        &self,
+        source: AllowanceSource,
+    ) -> SingleValueMapper<ManagedVec<AllowanceTier<Self::Api>>>;
+}
@@ -0,0 +1,80 @@
+use multiversx_sc::{
+    codec::{TopDecode, TopEncode},
+    contract_base::{CallableContract, ContractBase},
+    types::{BigUint, ManagedAddress, ManagedBuffer},
+};
+use multiversx_sc_scenario::{testing_framework::TxContextStack, DebugApi};
+
+pub static GET_STAKE_FN_NAME: &str = "getUserStakeAtRound";
+pub static GET_ENERGY_FN_NAME: &str = "getUserEnergyAtRound";
+
+/// Reports the amounts set through `set_amount_at_round`, or 0 for any other user and round
+#[derive(Clone)]
+pub struct StakingMock {}
+
+impl ContractBase for StakingMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for StakingMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name != GET_STAKE_FN_NAME && fn_name != GET_ENERGY_FN_NAME {
+            return false;
+        }
+
+        self.call_get_amount_at_round(fn_name);
+
+        true
+    }
+}
+
+impl StakingMock {
+    pub fn new() -> Self {
+        StakingMock {}
+    }
+
+    pub fn set_amount_at_round(
+        &self,
+        fn_name: &str,
+        user: &ManagedAddress<DebugApi>,
+        round: u64,
+        amount: &BigUint<DebugApi>,
+    ) {
+        self.storage_raw()
+            .write(Self::amount_key(fn_name, user, round), amount);
+    }
+
+    fn call_get_amount_at_round(&self, fn_name: &str) {
+        let api = TxContextStack::static_peek();
+        let args = api.input_ref().args.clone();
+        if args.len() != 2 {
+            panic!("Invalid args");
+        }
+
+        let user = ManagedAddress::<DebugApi>::top_decode(args[0].clone()).unwrap();
+        let round = u64::top_decode(args[1].clone()).unwrap();
+        let amount: BigUint<DebugApi> = self
+            .storage_raw()
+            .read(Self::amount_key(fn_name, &user, round));
+
+        let mut result = Vec::new();
+        amount.top_encode(&mut result).unwrap();
+        api.tx_result_cell
+            .try_lock()
+            .unwrap()
+            .result_values
+            .push(result);
+    }
+
+    fn amount_key(
+        fn_name: &str,
+        user: &ManagedAddress<DebugApi>,
+        round: u64,
+    ) -> ManagedBuffer<DebugApi> {
+        let mut key = ManagedBuffer::new_from_bytes(fn_name.as_bytes());
+        key.append(user.as_managed_buffer());
+        key.append_bytes(&round.to_be_bytes());
+
+        key
+    }
+}
@@ -3,7 +3,7 @@
 mod migration_guaranteed_tickets_setup;
 
 use launchpad_common::{
-    config::ConfigModule,
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
     setup::SetupModule,
     tickets::{TicketsModule, WINNING_TICKET},
     winner_selection::WinnerSelectionModule,
@@ -13,14 +13,20 @@ use launchpad_migration_guaranteed_tickets::{
         GuaranteedTicketWinnersModule, GuaranteedTicketsSelectionOperation,
     },
     guaranteed_tickets_init::GuaranteedTicketsInitModule,
+    staking_allowance::{AllowanceSource, StakingAllowanceModule},
     LaunchpadMigrationGuaranteedTickets,
 };
 use migration_guaranteed_tickets_setup::{
+    staking_mock::{StakingMock, GET_ENERGY_FN_NAME, GET_STAKE_FN_NAME},
     LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
     LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::BlockchainStateWrapper,
+};
 
 use crate::migration_guaranteed_tickets_setup::NR_WINNING_TICKETS;
 
@@ -54,27 +60,45 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
         })
         .assert_ok();
 
@@ -84,27 +108,48 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 1
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
         })
         .assert_ok();
 
@@ -170,26 +215,44 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 1);
         })
         .assert_ok();
@@ -200,26 +263,47 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
 
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[0])
+                ),
                 1
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[1])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[1])
+                ),
                 2
             );
             assert_eq!(
-                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2])),
+                sc.get_number_of_winning_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    managed_address!(&participants[2])
+                ),
                 0
             );
 
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
         .assert_ok();
@@ -286,15 +370,21 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-
-            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 2
+            );
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
         })
         .assert_ok();
@@ -313,13 +403,19 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -329,13 +425,22 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -415,19 +520,22 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -452,16 +560,28 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                ); // randomly selected -> leftover_ticket
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                ); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                ); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -470,16 +590,31 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(),
+                    WINNING_TICKET
+                );
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
+                assert_eq!(
+                    sc.ticket_status(FIRST_SALE_ROUND_ID, 8).get(),
+                    WINNING_TICKET
+                ); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 9).get(), false);
+                assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 10).get(), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -768,6 +903,7 @@ fn condition_checks_test() {
                 sc.set_ticket_price(
                     EgldOrEsdtTokenIdentifier::egld(),
                     managed_biguint!(TICKET_COST),
+                    OptionalValue::None,
                 );
             },
         )
@@ -918,7 +1054,7 @@ fn blacklist_scenario_test() {
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -961,7 +1097,7 @@ fn blacklist_scenario_test() {
         .execute_query(&lp_setup.lp_wrapper, |sc| {
             let blacklisted_user_guaranteed_tickets = 1;
             assert_eq!(
-                sc.nr_winning_tickets().get(),
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
                 nr_winning_tickets
                     - nr_staking_guaranteed_tickets
                     - nr_migration_guaranteed_tickets
@@ -1043,13 +1179,16 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 7).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1060,12 +1199,24 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 4).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 5).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 6).get(), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1184,3 +1335,149 @@ fn confirm_less_tickets_than_total_available_scenario_test() {
         &rust_biguint!(0),
     );
 }
+
+#[test]
+fn register_with_staking_snapshot_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let whale = b_mock.create_user_account(&rust_zero);
+    let late_staker = b_mock.create_user_account(&rust_zero);
+    let small_staker = b_mock.create_user_account(&rust_zero);
+    let staking_sc = b_mock.create_sc_account(&rust_zero, None, StakingMock::new, "staking wasm");
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_migration_guaranteed_tickets::contract_obj,
+        "launchpad wasm",
+    );
+
+    let snapshot_round = 2;
+    b_mock
+        .execute_tx(&owner, &staking_sc, &rust_zero, |sc| {
+            let amounts = [
+                (GET_STAKE_FN_NAME, &whale, snapshot_round, 600),
+                (GET_ENERGY_FN_NAME, &whale, snapshot_round, 1_000),
+                (GET_STAKE_FN_NAME, &late_staker, snapshot_round, 100),
+                (GET_STAKE_FN_NAME, &late_staker, snapshot_round + 1, 600),
+                (GET_STAKE_FN_NAME, &small_staker, snapshot_round, 50),
+            ];
+            for (fn_name, user, round, amount) in amounts {
+                sc.set_amount_at_round(
+                    fn_name,
+                    &managed_address!(user),
+                    round,
+                    &managed_biguint!(amount),
+                );
+            }
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(TICKET_COST),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                MAX_TIER_TICKETS,
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&whale, &lp_sc, &rust_zero, |sc| {
+            sc.register_for_launchpad();
+        })
+        .assert_user_error("Allowance snapshot not set");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let mut tiers = MultiValueEncoded::new();
+            tiers.push((managed_biguint!(500), 3).into());
+            tiers.push((managed_biguint!(100), 1).into());
+            sc.set_allowance_tiers(AllowanceSource::Staking, tiers);
+        })
+        .assert_user_error("Tiers must be sorted ascending");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_allowance_snapshot(managed_address!(staking_sc.address_ref()), snapshot_round);
+
+            let mut staking_tiers = MultiValueEncoded::new();
+            staking_tiers.push((managed_biguint!(100), 1).into());
+            staking_tiers.push((managed_biguint!(500), MAX_TIER_TICKETS).into());
+            sc.set_allowance_tiers(AllowanceSource::Staking, staking_tiers);
+
+            let mut energy_tiers = MultiValueEncoded::new();
+            energy_tiers.push((managed_biguint!(1_000), 1).into());
+            sc.set_allowance_tiers(AllowanceSource::Energy, energy_tiers);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(snapshot_round);
+    b_mock
+        .execute_tx(&whale, &lp_sc, &rust_zero, |sc| {
+            sc.register_for_launchpad();
+        })
+        .assert_user_error("Snapshot round not reached");
+
+    b_mock.set_block_round(snapshot_round + 1);
+    for user in [&whale, &late_staker] {
+        b_mock
+            .execute_tx(user, &lp_sc, &rust_zero, |sc| {
+                sc.register_for_launchpad();
+            })
+            .assert_ok();
+    }
+    b_mock
+        .execute_tx(&whale, &lp_sc, &rust_zero, |sc| {
+            sc.register_for_launchpad();
+        })
+        .assert_user_error("Duplicate entry for user");
+    b_mock
+        .execute_tx(&small_staker, &lp_sc, &rust_zero, |sc| {
+            sc.register_for_launchpad();
+        })
+        .assert_user_error("Not eligible for tickets");
+
+    // the stake added after the snapshot does not count
+    b_mock
+        .execute_query(&lp_sc, |sc| {
+            let whale_status = sc.user_tickets_status(managed_address!(&whale));
+            assert_eq!(whale_status.into_tuple(), (MAX_TIER_TICKETS, 1, 0, 1, 0));
+            let late_staker_status = sc.user_tickets_status(managed_address!(&late_staker));
+            assert_eq!(late_staker_status.into_tuple(), (1, 0, 0, 0, 0));
+
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    &managed_address!(&whale)
+                ),
+                MAX_TIER_TICKETS + 1
+            );
+            assert_eq!(
+                sc.last_ticket_id(FIRST_SALE_ROUND_ID).get(),
+                MAX_TIER_TICKETS + 2
+            );
+            assert_eq!(
+                sc.nr_winning_tickets(FIRST_SALE_ROUND_ID).get(),
+                NR_WINNING_TICKETS - 1
+            );
+            assert!(sc
+                .users_with_guaranteed_ticket()
+                .contains(&managed_address!(&whale)));
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+    b_mock
+        .execute_tx(&small_staker, &lp_sc, &rust_zero, |sc| {
+            sc.register_for_launchpad();
+        })
+        .assert_user_error("Add tickets period has passed");
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the tests of the launchpad contracts
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+use multiversx_sc_scenario::testing_framework::TxResult;
+
+// the event identifier, followed by the indexed caller, round and epoch
+const EVENT_TOPICS_LEN: usize = 4;
+
+/// Checks the identifiers of the emitted events, in order,
+/// and that all of them are encoded with the current schema version.
+/// Built-in function logs (e.g. token transfers) are skipped.
+pub fn assert_events(tx_result: &TxResult, expected_identifiers: &[&str]) {
+    let event_logs: Vec<_> = tx_result
+        .result_logs
+        .iter()
+        .filter(|log| log.topics.len() == EVENT_TOPICS_LEN && log.data.len() == 1)
+        .collect();
+    let identifiers: Vec<String> = event_logs
+        .iter()
+        .map(|log| String::from_utf8(log.topics[0].clone()).unwrap())
+        .collect();
+    assert_eq!(identifiers, expected_identifiers);
+
+    for log in event_logs {
+        assert_eq!(log.data[0][0], EVENTS_SCHEMA_VERSION);
+    }
+}
@@ -9,12 +9,15 @@ pub trait ClaimNftModule:
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::tickets::TicketsModule
     + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + launchpad_common::permissions::PermissionsModule
     + launchpad_common::user_interactions::UserInteractionsModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
+    + crate::events::EventsModule
     + crate::nft_config::NftConfigModule
     + crate::mystery_sft::MysterySftModule
     + crate::confirm_nft::ConfirmNftModule
@@ -30,20 +33,23 @@ pub trait ClaimNftModule:
             MysterySftTypes::NotConfirmed
         };
 
-        let _ = self.mystery_sft().nft_add_quantity_and_send(
+        let mystery_sft = self.mystery_sft().nft_add_quantity_and_send(
             &caller,
             mystery_sft_type.as_nonce(),
             NFT_AMOUNT.into(),
         );
 
+        let mut nft_cost_refund = EgldOrEsdtTokenPayment::no_payment();
         if matches!(mystery_sft_type, MysterySftTypes::ConfirmedLost) {
-            let nft_cost = self.nft_cost().get();
+            nft_cost_refund = self.nft_cost().get();
             self.send().direct(
                 &caller,
-                &nft_cost.token_identifier,
-                nft_cost.token_nonce,
-                &nft_cost.amount,
+                &nft_cost_refund.token_identifier,
+                nft_cost_refund.token_nonce,
+                &nft_cost_refund.amount,
             );
         }
+
+        self.emit_claim_nft_event(mystery_sft, nft_cost_refund);
     }
 }
@@ -1,23 +1,33 @@
 multiversx_sc::imports!();
 
+use launchpad_common::{config::FIRST_SALE_ROUND_ID, kyc::KycApproval};
+
 #[multiversx_sc::module]
 pub trait ConfirmNftModule:
     launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::events::EventsModule
     + crate::nft_config::NftConfigModule
     + crate::mystery_sft::MysterySftModule
 {
     #[payable("*")]
     #[endpoint(confirmNft)]
-    fn confirm_nft(&self) {
-        self.require_confirmation_period();
+    #[label("nft-draw")]
+    fn confirm_nft(&self, opt_kyc_approval: OptionalValue<KycApproval<Self::Api>>) {
+        self.require_confirmation_period(FIRST_SALE_ROUND_ID);
         self.require_all_sft_setup_steps_complete();
 
         let caller = self.blockchain().get_caller();
-        let nr_base_launchpad_confirmed = self.nr_confirmed_tickets(&caller).get();
+        let _ = self.require_kyc_approval(&caller, FIRST_SALE_ROUND_ID, opt_kyc_approval);
+        let nr_base_launchpad_confirmed = self
+            .nr_confirmed_tickets(FIRST_SALE_ROUND_ID, &caller)
+            .get();
         require!(
             nr_base_launchpad_confirmed > 0,
             "Must confirm launchpad tickets before entering NFT draw"
@@ -25,13 +35,16 @@ pub trait ConfirmNftModule:
 
         let new_user = self.confirmed_nft_user_list().insert(caller);
         require!(new_user, "Already confirmed NFT");
+        self.record_nft_confirmation();
 
         let payment = self.call_value().egld_or_single_esdt();
         self.require_exact_nft_cost(&payment);
+
+        self.emit_confirm_nft_event(payment);
     }
 
     fn claim_nft_payment(&self) {
-        self.require_claim_period();
+        self.require_claim_period(FIRST_SALE_ROUND_ID);
 
         let mapper = self.claimable_nft_payment();
         let claimable_amount = mapper.get();
@@ -48,6 +61,8 @@ pub trait ConfirmNftModule:
             );
 
             mapper.clear();
+
+            self.emit_claim_nft_payment_event(payment);
         }
     }
 
@@ -0,0 +1,355 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::common_events::EVENTS_SCHEMA_VERSION;
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetNftCostEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    nft_cost: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct IssueMysterySftEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    token_display_name: ManagedBuffer<M>,
+    token_ticker: ManagedBuffer<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct CreateInitialSftsEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    mystery_sft_token_id: TokenIdentifier<M>,
+    nr_sfts_created: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetTransferRoleEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ConfirmNftEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    nft_payment: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SelectNftWinnersCompletedEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    total_nft_winners: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct RefundNftCostEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    address: ManagedAddress<M>,
+    nft_cost_refund: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimNftEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    mystery_sft: EsdtTokenPayment<M>,
+    nft_cost_refund: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimNftPaymentEvent<M: ManagedTypeApi> {
+    schema_version: u8,
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    nft_payment: EgldOrEsdtTokenPayment<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_set_nft_cost_event(&self, nft_cost: EgldOrEsdtTokenPayment<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_nft_cost_event(
+            user.clone(),
+            round,
+            epoch,
+            SetNftCostEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                nft_cost,
+            },
+        )
+    }
+
+    fn emit_issue_mystery_sft_event(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.issue_mystery_sft_event(
+            user.clone(),
+            round,
+            epoch,
+            IssueMysterySftEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                token_display_name,
+                token_ticker,
+            },
+        )
+    }
+
+    fn emit_create_initial_sfts_event(
+        &self,
+        mystery_sft_token_id: TokenIdentifier,
+        nr_sfts_created: usize,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.create_initial_sfts_event(
+            user.clone(),
+            round,
+            epoch,
+            CreateInitialSftsEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                mystery_sft_token_id,
+                nr_sfts_created,
+            },
+        )
+    }
+
+    fn emit_set_transfer_role_event(&self, address: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_transfer_role_event(
+            user.clone(),
+            round,
+            epoch,
+            SetTransferRoleEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                address,
+            },
+        )
+    }
+
+    fn emit_confirm_nft_event(&self, nft_payment: EgldOrEsdtTokenPayment<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.confirm_nft_event(
+            user.clone(),
+            round,
+            epoch,
+            ConfirmNftEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                nft_payment,
+            },
+        )
+    }
+
+    fn emit_select_nft_winners_completed_event(&self, total_nft_winners: usize) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.select_nft_winners_completed_event(
+            user.clone(),
+            round,
+            epoch,
+            SelectNftWinnersCompletedEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                total_nft_winners,
+            },
+        )
+    }
+
+    fn emit_refund_nft_cost_event(
+        &self,
+        address: ManagedAddress,
+        nft_cost_refund: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.refund_nft_cost_event(
+            user.clone(),
+            round,
+            epoch,
+            RefundNftCostEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                address,
+                nft_cost_refund,
+            },
+        )
+    }
+
+    fn emit_claim_nft_event(
+        &self,
+        mystery_sft: EsdtTokenPayment<Self::Api>,
+        nft_cost_refund: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_nft_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimNftEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                mystery_sft,
+                nft_cost_refund,
+            },
+        )
+    }
+
+    fn emit_claim_nft_payment_event(&self, nft_payment: EgldOrEsdtTokenPayment<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_nft_payment_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimNftPaymentEvent {
+                schema_version: EVENTS_SCHEMA_VERSION,
+                user,
+                round,
+                epoch,
+                nft_payment,
+            },
+        )
+    }
+
+    #[event("setNftCost")]
+    fn set_nft_cost_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_nft_cost_event: SetNftCostEvent<Self::Api>,
+    );
+
+    #[event("issueMysterySft")]
+    fn issue_mystery_sft_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        issue_mystery_sft_event: IssueMysterySftEvent<Self::Api>,
+    );
+
+    #[event("createInitialSfts")]
+    fn create_initial_sfts_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        create_initial_sfts_event: CreateInitialSftsEvent<Self::Api>,
+    );
+
+    #[event("setTransferRole")]
+    fn set_transfer_role_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_transfer_role_event: SetTransferRoleEvent<Self::Api>,
+    );
+
+    #[event("confirmNft")]
+    fn confirm_nft_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        confirm_nft_event: ConfirmNftEvent<Self::Api>,
+    );
+
+    #[event("selectNftWinnersCompleted")]
+    fn select_nft_winners_completed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        select_nft_winners_completed_event: SelectNftWinnersCompletedEvent<Self::Api>,
+    );
+
+    #[event("refundNftCost")]
+    fn refund_nft_cost_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        refund_nft_cost_event: RefundNftCostEvent<Self::Api>,
+    );
+
+    #[event("claimNft")]
+    fn claim_nft_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_nft_event: ClaimNftEvent<Self::Api>,
+    );
+
+    #[event("claimNftPayment")]
+    fn claim_nft_payment_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_nft_payment_event: ClaimNftPaymentEvent<Self::Api>,
+    );
+}
@@ -3,11 +3,15 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::mystery_sft::SftSetupSteps;
-use launchpad_common::{launch_stage::Flags, random::Random};
+use crate::{mystery_sft::SftSetupSteps, nft_winners_selection::NftSelectionStep};
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID, launch_stage::Flags, operation_progress::OperationProgress,
+    selection_pipeline::SelectionSteps, user_info::UserInfo,
+};
 
 pub mod claim_nft;
 pub mod confirm_nft;
+pub mod events;
 pub mod mystery_sft;
 pub mod nft_blacklist;
 pub mod nft_config;
@@ -19,16 +23,24 @@ pub trait Launchpad:
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
     + launchpad_common::tickets::TicketsModule
     + launchpad_common::winner_selection::WinnerSelectionModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::operation_progress::OperationProgressModule
     + launchpad_common::permissions::PermissionsModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::user_info::UserInfoModule
     + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::selection_pipeline::SelectionPipelineModule
+    + launchpad_common::claim_and_stake::ClaimAndStakeModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
     + nft_config::NftConfigModule
     + nft_blacklist::NftBlacklistModule
     + mystery_sft::MysterySftModule
@@ -84,14 +96,25 @@ pub trait Launchpad:
         &self,
         address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) {
-        self.add_tickets(address_number_pairs);
+        self.add_tickets(FIRST_SALE_ROUND_ID, address_number_pairs);
+    }
+
+    /// Sets the absolute ticket allowance of users who were already added, or adds new users.
+    /// Setting an allowance to 0 removes the user from the sale.
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.set_ticket_allowances(FIRST_SALE_ROUND_ID, address_number_pairs);
     }
 
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
     fn deposit_launchpad_tokens_endpoint(&self) {
-        let nr_winning_tickets = self.nr_winning_tickets().get();
+        let nr_winning_tickets = self.nr_winning_tickets(FIRST_SALE_ROUND_ID).get();
         self.deposit_launchpad_tokens(nr_winning_tickets);
     }
 
@@ -104,43 +127,25 @@ pub trait Launchpad:
 
     #[endpoint(selectNftWinners)]
     fn select_nft_winners_endpoint(&self) -> OperationCompletionStatus {
-        self.require_winner_selection_period();
-
-        let flags_mapper = self.flags();
-        let mut flags = flags_mapper.get();
-        require!(
-            flags.were_winners_selected,
-            "Must select winners for base launchpad first"
-        );
-        require!(
-            !flags.was_additional_step_completed,
-            "Already selected NFT winners"
-        );
-
-        let mut rng: Random<Self::Api> = self.load_additional_selection_operation();
-        let run_result = self.select_nft_winners(&mut rng);
-
-        match run_result {
-            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
-                self.save_additional_selection_progress(&rng);
-            }
-            OperationCompletionStatus::Completed => {
-                flags.was_additional_step_completed = true;
-                flags_mapper.set(&flags);
-
-                let winners_selected = self.nft_selection_winners().len();
-                let nft_cost = self.nft_cost().get();
-                let claimable_nft_payment = nft_cost.amount * winners_selected as u32;
-                self.claimable_nft_payment().set(&claimable_nft_payment);
-            }
-        };
+        self.run_selection_pipeline(self.additional_selection_steps())
+    }
 
-        run_result
+    fn additional_selection_steps(&self) -> SelectionSteps<Self> {
+        &[&NftSelectionStep]
     }
 
     #[endpoint(claimLaunchpadTokens)]
     fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+        self.claim_launchpad_tokens(FIRST_SALE_ROUND_ID, Self::default_send_launchpad_tokens_fn);
+        self.claim_nft();
+    }
+
+    /// Sends the claimed launchpad tokens to the staking endpoint set by the owner,
+    /// and the resulting position to the caller
+    #[endpoint(claimLaunchpadTokensAndStake)]
+    fn claim_launchpad_tokens_and_stake_endpoint(&self) {
+        self.require_claim_staking_endpoint_set();
+        self.claim_launchpad_tokens(FIRST_SALE_ROUND_ID, Self::stake_launchpad_tokens_fn);
         self.claim_nft();
     }
 
@@ -150,4 +155,43 @@ pub trait Launchpad:
         self.claim_ticket_payment();
         self.claim_nft_payment();
     }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::no_vesting_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.cleanup_storage(
+            Self::no_additional_user_cleanup,
+            Self::no_reserved_launchpad_tokens,
+        )
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(&self, address: ManagedAddress) -> UserInfo<Self::Api> {
+        self.get_user_info_base(FIRST_SALE_ROUND_ID, &address, Self::add_user_info)
+    }
+
+    fn add_user_info(&self, address: &ManagedAddress, user_info: &mut UserInfo<Self::Api>) {
+        user_info.nft_status = self.get_user_nft_status(address);
+    }
+
+    fn get_additional_selection_progress(&self, encoded_data: ManagedBuffer) -> OperationProgress {
+        self.get_selection_pipeline_progress(self.additional_selection_steps(), encoded_data)
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(&self) -> OperationProgress {
+        self.get_operation_progress(FIRST_SALE_ROUND_ID, Self::get_additional_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(&self, gas_limit: u64) -> usize {
+        let progress = self.get_ongoing_operation_progress();
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
 }
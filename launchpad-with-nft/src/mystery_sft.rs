@@ -33,13 +33,17 @@ pub struct SftSetupSteps {
 pub trait MysterySftModule:
     multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + crate::events::EventsModule
 {
     #[payable("*")]
     #[endpoint(issueMysterySft)]
+    #[label("nft-draw")]
     fn issue_mystery_sft(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
         self.require_extended_permissions();
 
         let issue_cost = self.call_value().egld_value().clone_value();
+        self.emit_issue_mystery_sft_event(token_display_name.clone(), token_ticker.clone());
         self.mystery_sft().issue_and_set_all_roles(
             EsdtTokenType::SemiFungible,
             issue_cost,
@@ -51,6 +55,7 @@ pub trait MysterySftModule:
     }
 
     #[endpoint(createInitialSfts)]
+    #[label("nft-draw")]
     fn create_initial_sfts(&self) {
         self.require_extended_permissions();
 
@@ -76,9 +81,12 @@ pub trait MysterySftModule:
         steps.issued_token = true;
         steps.created_initial_tokens = true;
         steps_mapper.set(&steps);
+
+        self.emit_create_initial_sfts_event(token_id, SFT_NAMES.len());
     }
 
     #[endpoint(setTransferRole)]
+    #[label("nft-draw")]
     fn set_transfer_role(&self, opt_addr_to_set: OptionalValue<ManagedAddress>) {
         self.require_extended_permissions();
 
@@ -92,6 +100,7 @@ pub trait MysterySftModule:
             }
         };
 
+        self.emit_set_transfer_role_event(addr.clone());
         self.mystery_sft()
             .set_local_roles_for_address(&addr, &[EsdtLocalRole::Transfer], None);
     }
@@ -5,8 +5,12 @@ pub trait NftBlacklistModule:
     launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::events::EventsModule
     + crate::nft_config::NftConfigModule
     + crate::confirm_nft::ConfirmNftModule
     + crate::mystery_sft::MysterySftModule
@@ -22,6 +26,8 @@ pub trait NftBlacklistModule:
                     nft_cost.token_nonce,
                     &nft_cost.amount,
                 );
+
+                self.emit_refund_nft_cost_event(user, nft_cost.clone());
             }
         }
     }
@@ -1,23 +1,30 @@
 multiversx_sc::imports!();
 
+use launchpad_common::config::FIRST_SALE_ROUND_ID;
+
 #[multiversx_sc::module]
 pub trait NftConfigModule:
-    launchpad_common::launch_stage::LaunchStageModule + launchpad_common::config::ConfigModule
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + crate::events::EventsModule
 {
     #[only_owner]
     #[endpoint(setNftCost)]
+    #[label("nft-draw")]
     fn set_nft_cost(
         &self,
         nft_cost_token_id: EgldOrEsdtTokenIdentifier,
         nft_cost_token_nonce: u64,
         nft_cost_token_amount: BigUint,
     ) {
-        self.require_add_tickets_period();
+        self.require_add_tickets_period(FIRST_SALE_ROUND_ID);
         self.try_set_nft_cost(
             nft_cost_token_id,
             nft_cost_token_nonce,
             nft_cost_token_amount,
         );
+
+        self.emit_set_nft_cost_event(self.nft_cost().get());
     }
 
     fn try_set_nft_cost(
@@ -47,6 +54,7 @@ pub trait NftConfigModule:
     }
 
     #[view(getNftCost)]
+    #[label("nft-draw")]
     #[storage_mapper("nftCost")]
     fn nft_cost(&self) -> SingleValueMapper<EgldOrEsdtTokenPayment<Self::Api>>;
 }
@@ -3,7 +3,11 @@ multiversx_sc::derive_imports!();
 
 use launchpad_common::{
     ongoing_operation::{CONTINUE_OP, STOP_OP},
+    operation_progress::{OperationPhase, OperationProgress},
+    pagination::get_page,
     random::Random,
+    selection_pipeline::AdditionalSelectionStep,
+    user_info::UserNftStatus,
 };
 
 const VEC_MAPPER_START_INDEX: usize = 1;
@@ -14,8 +18,12 @@ pub trait NftWinnersSelectionModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::kyc::KycModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::launchpad_stats::LaunchpadStatsModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + crate::events::EventsModule
     + crate::nft_config::NftConfigModule
     + crate::confirm_nft::ConfirmNftModule
     + crate::mystery_sft::MysterySftModule
@@ -46,17 +54,74 @@ pub trait NftWinnersSelectionModule:
         })
     }
 
+    fn get_nft_selection_progress(&self, _encoded_data: ManagedBuffer) -> OperationProgress {
+        let users_left = self.confirmed_nft_user_list().len();
+        let winners_selected = self.nft_selection_winners().len();
+        let nfts_left = self
+            .total_available_nfts()
+            .get()
+            .saturating_sub(winners_selected);
+
+        OperationProgress::new(
+            OperationPhase::NftWinnersSelection,
+            winners_selected,
+            core::cmp::min(users_left, nfts_left),
+        )
+    }
+
     #[view(hasUserConfirmedNft)]
+    #[label("nft-draw")]
     fn has_user_confirmed_nft(&self, user: ManagedAddress) -> bool {
         self.confirmed_nft_user_list().contains(&user)
             || self.nft_selection_winners().contains(&user)
     }
 
     #[view(hasUserWonNft)]
+    #[label("nft-draw")]
     fn has_user_won_nft(&self, user: ManagedAddress) -> bool {
         self.nft_selection_winners().contains(&user)
     }
 
+    fn get_user_nft_status(&self, user: &ManagedAddress) -> UserNftStatus {
+        if self.nft_selection_winners().contains(user) {
+            UserNftStatus::Won
+        } else if self.confirmed_nft_user_list().contains(user) {
+            UserNftStatus::Confirmed
+        } else {
+            UserNftStatus::None
+        }
+    }
+
+    #[view(getNftWinners)]
+    #[label("nft-draw")]
+    fn get_nft_winners(&self, offset: usize, limit: usize) -> MultiValueEncoded<ManagedAddress> {
+        get_page(&self.nft_selection_winners(), offset, limit)
+    }
+
     #[storage_mapper("nftSelectionWinners")]
     fn nft_selection_winners(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
+
+/// Draws the NFT winners among the users who confirmed the NFT
+pub struct NftSelectionStep;
+
+impl<Sc: NftWinnersSelectionModule> AdditionalSelectionStep<Sc> for NftSelectionStep {
+    type Operation = Random<Sc::Api>;
+
+    fn run(&self, sc: &Sc, rng: &mut Self::Operation) -> OperationCompletionStatus {
+        sc.select_nft_winners(rng)
+    }
+
+    fn on_complete(&self, sc: &Sc, _rng: &Self::Operation) {
+        let winners_selected = sc.nft_selection_winners().len();
+        let nft_cost = sc.nft_cost().get();
+        let claimable_nft_payment = nft_cost.amount * winners_selected as u32;
+        sc.claimable_nft_payment().set(&claimable_nft_payment);
+
+        sc.emit_select_nft_winners_completed_event(winners_selected);
+    }
+
+    fn progress(&self, sc: &Sc, _rng: &Self::Operation) -> OperationProgress {
+        sc.get_nft_selection_progress(ManagedBuffer::new())
+    }
+}
@@ -2,7 +2,14 @@
 
 mod launchpad_with_nft_setup;
 
-use launchpad_common::tickets::{TicketsModule, WINNING_TICKET};
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID,
+    launchpad_stats::LaunchpadStatsModule,
+    operation_progress::{OperationPhase, OperationProgress},
+    tickets::{TicketsModule, WINNING_TICKET},
+    user_info::UserNftStatus,
+};
+use launchpad_test_support::assert_events;
 use launchpad_with_nft::{
     confirm_nft::ConfirmNftModule, mystery_sft::MysterySftTypes,
     nft_winners_selection::NftWinnersSelectionModule, Launchpad,
@@ -35,6 +42,9 @@ fn confirm_test() {
             for user in &users {
                 user_mapper.contains(&managed_address!(user));
             }
+
+            let stats = sc.get_launchpad_stats();
+            assert_eq!(stats.nr_nft_confirmations, NR_LAUNCHPAD_PARTICIPANTS);
         })
         .assert_ok();
 
@@ -71,9 +81,12 @@ fn select_winners_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
+            assert_eq!(
+                sc.ticket_status(FIRST_SALE_ROUND_ID, 1).get(),
+                WINNING_TICKET
+            );
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 2).get(), false);
+            assert_eq!(sc.ticket_status(FIRST_SALE_ROUND_ID, 3).get(), false);
         })
         .assert_ok();
 
@@ -93,6 +106,23 @@ fn select_winners_test() {
             assert!(sc
                 .nft_selection_winners()
                 .contains(&managed_address!(&users[0])));
+
+            let nft_winners = sc.get_nft_winners(0, 10).to_vec();
+            assert_eq!(nft_winners.len(), 1);
+            assert_eq!(
+                nft_winners.get(0).clone_value(),
+                managed_address!(&users[0])
+            );
+
+            let expected_nft_statuses = [
+                UserNftStatus::Won,
+                UserNftStatus::Confirmed,
+                UserNftStatus::None,
+            ];
+            for (user, expected_nft_status) in users.iter().zip(expected_nft_statuses) {
+                let user_info = sc.get_user_info(managed_address!(user));
+                assert_eq!(user_info.nft_status, expected_nft_status);
+            }
         })
         .assert_ok();
 }
@@ -230,3 +260,117 @@ fn blacklist_refund_test() {
         &rust_biguint!(BASE_TICKET_COST + NFT_TICKET_COST),
     );
 }
+
+#[test]
+fn nft_selection_progress_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_with_nft::contract_obj);
+    let users = lp_setup.participants.clone();
+
+    lp_setup.confirm_nft(&users[0]).assert_ok();
+    lp_setup.confirm_nft(&users[1]).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.select_base_launchpad_winners().assert_ok();
+
+    // one NFT available for two confirmed users
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::new(OperationPhase::NftWinnersSelection, 0, TOTAL_NFTS)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.select_nft_winners().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_ongoing_operation_progress(),
+                OperationProgress::default()
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn events_test() {
+    let mut lp_setup = LaunchpadSetup::new(launchpad_with_nft::contract_obj);
+    let users = lp_setup.participants.clone();
+
+    for user in &users {
+        let tx_result = lp_setup.confirm_nft(user);
+        tx_result.assert_ok();
+        assert_events(&tx_result, &["confirmNft"]);
+    }
+
+    let tx_result = lp_setup.b_mock.execute_tx(
+        &lp_setup.owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+            args.push(managed_address!(&users[2]));
+
+            sc.add_users_to_blacklist_endpoint(args);
+        },
+    );
+    tx_result.assert_ok();
+    assert_events(
+        &tx_result,
+        &[
+            "refundTicketPayment",
+            "addUsersToBlacklist",
+            "refundNftCost",
+        ],
+    );
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    lp_setup.select_base_launchpad_winners().assert_ok();
+    let tx_result = lp_setup.select_nft_winners();
+    tx_result.assert_ok();
+    assert_events(&tx_result, &["selectNftWinnersCompleted"]);
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    let tx_result = lp_setup.claim(&users[0]);
+    tx_result.assert_ok();
+    assert_events(
+        &tx_result,
+        &[
+            "launchpadResultsComputed",
+            "claimLaunchpadTokens",
+            "claimNft",
+        ],
+    );
+
+    let tx_result = lp_setup.claim(&users[1]);
+    tx_result.assert_ok();
+    assert_events(
+        &tx_result,
+        &[
+            "launchpadResultsComputed",
+            "refundTicketPayment",
+            "claimNft",
+        ],
+    );
+
+    let tx_result = lp_setup.b_mock.execute_tx(
+        &lp_setup.owner_address,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.claim_ticket_payment_endpoint();
+        },
+    );
+    tx_result.assert_ok();
+    assert_events(&tx_result, &["claimTicketPayment", "claimNftPayment"]);
+}
@@ -1,12 +1,14 @@
 use launchpad_common::{
-    tickets::TicketsModule, user_interactions::UserInteractionsModule,
+    config::FIRST_SALE_ROUND_ID, tickets::TicketsModule, user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
+use launchpad_test_support::assert_events;
 use launchpad_with_nft::{
     confirm_nft::ConfirmNftModule,
     mystery_sft::{MysterySftModule, SftSetupSteps},
     Launchpad,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::{
     storage::mappers::StorageTokenWrapper,
     types::{
@@ -103,18 +105,18 @@ where
             ],
         );
 
-        b_mock
-            .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
-                sc.mystery_sft()
-                    .set_token_id(managed_token_id!(SFT_TOKEN_ID));
-                sc.create_initial_sfts();
-                sc.sft_setup_steps().set(&SftSetupSteps {
-                    issued_token: true,
-                    created_initial_tokens: true,
-                    set_transfer_role: true,
-                });
-            })
-            .assert_ok();
+        let tx_result = b_mock.execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
+            sc.mystery_sft()
+                .set_token_id(managed_token_id!(SFT_TOKEN_ID));
+            sc.create_initial_sfts();
+            sc.sft_setup_steps().set(&SftSetupSteps {
+                issued_token: true,
+                created_initial_tokens: true,
+                set_transfer_role: true,
+            });
+        });
+        tx_result.assert_ok();
+        assert_events(&tx_result, &["createInitialSfts"]);
 
         // add tickets
         b_mock
@@ -124,7 +126,7 @@ where
                     args.push((managed_address!(p), 1).into());
                 }
 
-                sc.add_tickets(args);
+                sc.add_tickets(FIRST_SALE_ROUND_ID, args);
             })
             .assert_ok();
 
@@ -148,7 +150,12 @@ where
         for p in &participants {
             b_mock
                 .execute_tx(p, &lp_wrapper, &rust_biguint!(BASE_TICKET_COST), |sc| {
-                    sc.confirm_tickets(1);
+                    sc.confirm_tickets(
+                        1,
+                        OptionalValue::None,
+                        OptionalValue::None,
+                        OptionalValue::None,
+                    );
                 })
                 .assert_ok();
         }
@@ -167,7 +174,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(NFT_TICKET_COST),
             |sc| {
-                sc.confirm_nft();
+                sc.confirm_nft(OptionalValue::None);
             },
         )
     }
@@ -179,7 +186,7 @@ where
                 &self.lp_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let result = sc.filter_tickets();
+                    let result = sc.filter_tickets(OptionalValue::None);
                     assert!(matches!(result, OperationCompletionStatus::Completed));
                 },
             )
@@ -190,7 +197,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(0),
             |sc| {
-                let result = sc.select_winners();
+                let result = sc.select_winners(OptionalValue::None);
                 assert!(matches!(result, OperationCompletionStatus::Completed));
             },
         )
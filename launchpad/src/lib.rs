@@ -3,7 +3,13 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{launch_stage::Flags, *};
+use launchpad_common::{
+    config::{SaleRoundId, FIRST_SALE_ROUND_ID},
+    launch_stage::Flags,
+    operation_progress::OperationProgress,
+    user_info::UserInfo,
+    *,
+};
 
 #[multiversx_sc::contract]
 pub trait Launchpad:
@@ -11,14 +17,21 @@ pub trait Launchpad:
     + launch_stage::LaunchStageModule
     + config::ConfigModule
     + setup::SetupModule
+    + storage_cleanup::StorageCleanupModule
     + tickets::TicketsModule
     + winner_selection::WinnerSelectionModule
     + ongoing_operation::OngoingOperationModule
+    + operation_progress::OperationProgressModule
     + permissions::PermissionsModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
+    + launchpad_stats::LaunchpadStatsModule
     + user_interactions::UserInteractionsModule
+    + user_info::UserInfoModule
     + common_events::CommonEventsModule
+    + kyc::KycModule
+    + claim_and_stake::ClaimAndStakeModule
+    + sale_rounds::SaleRoundsModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -59,20 +72,44 @@ pub trait Launchpad:
         &self,
         address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
     ) {
-        self.add_tickets(address_number_pairs);
+        self.add_tickets(FIRST_SALE_ROUND_ID, address_number_pairs);
+    }
+
+    /// Sets the absolute ticket allowance of users who were already added, or adds new users.
+    /// Setting an allowance to 0 removes the user from the sale.
+    #[only_owner]
+    #[endpoint(setTicketAllowances)]
+    fn set_ticket_allowances_endpoint(
+        &self,
+        address_number_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.set_ticket_allowances(FIRST_SALE_ROUND_ID, address_number_pairs);
     }
 
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
     fn deposit_launchpad_tokens_endpoint(&self) {
-        let nr_winning_tickets = self.nr_winning_tickets().get();
-        self.deposit_launchpad_tokens(nr_winning_tickets);
+        let total_winning_tickets = self.get_total_winning_tickets();
+        self.deposit_launchpad_tokens(total_winning_tickets);
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+    fn claim_launchpad_tokens_endpoint(&self, opt_sale_round_id: OptionalValue<SaleRoundId>) {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.claim_launchpad_tokens(sale_round_id, Self::default_send_launchpad_tokens_fn);
+    }
+
+    /// Sends the claimed launchpad tokens to the staking endpoint set by the owner,
+    /// and the resulting position to the caller
+    #[endpoint(claimLaunchpadTokensAndStake)]
+    fn claim_launchpad_tokens_and_stake_endpoint(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) {
+        self.require_claim_staking_endpoint_set();
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.claim_launchpad_tokens(sale_round_id, Self::stake_launchpad_tokens_fn);
     }
 
     #[only_owner]
@@ -85,4 +122,48 @@ pub trait Launchpad:
     fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
         self.add_users_to_blacklist(&users_list.to_vec());
     }
+
+    #[only_owner]
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round_endpoint(&self, deadline_round: u64) {
+        self.set_claim_deadline_round(deadline_round, Self::no_vesting_last_release_round);
+    }
+
+    #[only_owner]
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage_endpoint(&self) -> OperationCompletionStatus {
+        self.cleanup_storage(
+            Self::no_additional_user_cleanup,
+            Self::no_reserved_launchpad_tokens,
+        )
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(
+        &self,
+        address: ManagedAddress,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> UserInfo<Self::Api> {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_user_info_base(sale_round_id, &address, Self::no_additional_user_info)
+    }
+
+    #[view(getOngoingOperationProgress)]
+    fn get_ongoing_operation_progress(
+        &self,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> OperationProgress {
+        let sale_round_id = self.resolve_sale_round_id(opt_sale_round_id);
+        self.get_operation_progress(sale_round_id, Self::no_additional_selection_progress)
+    }
+
+    #[view(getEstimatedOperationCalls)]
+    fn get_estimated_operation_calls(
+        &self,
+        gas_limit: u64,
+        opt_sale_round_id: OptionalValue<SaleRoundId>,
+    ) -> usize {
+        let progress = self.get_ongoing_operation_progress(opt_sale_round_id);
+        self.estimate_operation_calls(&progress, gas_limit)
+    }
 }
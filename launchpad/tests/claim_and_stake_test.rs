@@ -0,0 +1,235 @@
+use launchpad::Launchpad;
+use launchpad_common::{
+    claim_and_stake::ClaimAndStakeModule, user_interactions::UserInteractionsModule,
+    winner_selection::WinnerSelectionModule,
+};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    contract_base::{CallableContract, ContractBase},
+    types::{EsdtLocalRole, ManagedBuffer, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_egld_token_id, managed_token_id,
+    rust_biguint, testing_framework::BlockchainStateWrapper, DebugApi,
+};
+
+static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+static STAKING_POSITION_TOKEN_ID: &[u8] = b"STAKE-123456";
+static STAKE_FN_NAME: &str = "stake";
+const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100_000;
+const TICKET_PRICE: u64 = 100;
+const NR_WINNING_TICKETS: usize = 3;
+
+#[test]
+fn claim_launchpad_tokens_and_stake_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_biguint!(2 * TICKET_PRICE));
+    let second_user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let staking_sc = b_mock.create_sc_account(&rust_zero, None, StakingMock::new, "staking wasm");
+    b_mock.set_esdt_local_roles(
+        staking_sc.address_ref(),
+        STAKING_POSITION_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate],
+    );
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad::contract_obj,
+        "launchpad wasm",
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                10,
+                20,
+                30,
+            );
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&first_user), 2).into());
+            tickets.push((managed_address!(&second_user), 1).into());
+            sc.add_tickets_endpoint(tickets);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_claim_staking_endpoint(
+                managed_address!(&first_user),
+                managed_buffer!(STAKE_FN_NAME.as_bytes()),
+            );
+        })
+        .assert_user_error("Invalid SC address");
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_claim_staking_endpoint(
+                managed_address!(staking_sc.address_ref()),
+                ManagedBuffer::new(),
+            );
+        })
+        .assert_user_error("Invalid endpoint name");
+
+    b_mock.set_esdt_balance(
+        &owner,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64),
+    );
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &lp_sc,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_WINNING_TICKETS as u64),
+            |sc| {
+                sc.deposit_launchpad_tokens_endpoint();
+            },
+        )
+        .assert_ok();
+
+    b_mock.set_block_round(10);
+    b_mock
+        .execute_tx(
+            &first_user,
+            &lp_sc,
+            &rust_biguint!(2 * TICKET_PRICE),
+            |sc| {
+                sc.confirm_tickets(
+                    2,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    b_mock
+        .execute_tx(&second_user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
+            sc.confirm_tickets(
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(20);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.filter_tickets(OptionalValue::None);
+            let _ = sc.select_winners(OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(30);
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_and_stake_endpoint(OptionalValue::None);
+        })
+        .assert_user_error("Staking endpoint not set");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_claim_staking_endpoint(
+                managed_address!(staking_sc.address_ref()),
+                managed_buffer!(STAKE_FN_NAME.as_bytes()),
+            );
+        })
+        .assert_ok();
+
+    // the first user stakes, while the second one claims the launchpad tokens as usual
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_and_stake_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&second_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(&first_user, LAUNCHPAD_TOKEN_ID, &rust_zero);
+    b_mock.check_nft_balance::<ManagedBuffer<DebugApi>>(
+        &first_user,
+        STAKING_POSITION_TOKEN_ID,
+        1,
+        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+        None,
+    );
+    b_mock.check_esdt_balance(
+        staking_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    b_mock.check_nft_balance::<ManagedBuffer<DebugApi>>(
+        lp_sc.address_ref(),
+        STAKING_POSITION_TOKEN_ID,
+        1,
+        &rust_zero,
+        None,
+    );
+    b_mock.check_esdt_balance(
+        &second_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_and_stake_endpoint(OptionalValue::None);
+        })
+        .assert_user_error("Already claimed");
+}
+
+/// Mints a position of the same amount as the staked tokens, and sends it back to the caller
+#[derive(Clone, Default)]
+pub struct StakingMock {}
+
+impl ContractBase for StakingMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for StakingMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name != STAKE_FN_NAME {
+            return false;
+        }
+
+        self.call_stake();
+
+        true
+    }
+}
+
+impl StakingMock {
+    pub fn new() -> Self {
+        StakingMock {}
+    }
+
+    fn call_stake(&self) {
+        let payment = self.call_value().single_esdt();
+        let position_token_id = managed_token_id!(STAKING_POSITION_TOKEN_ID);
+        let position_nonce = self.send().esdt_nft_create_compact_named(
+            &position_token_id,
+            &payment.amount,
+            &payment.token_identifier.into_managed_buffer(),
+            &ManagedBuffer::<DebugApi>::new(),
+        );
+        self.send().direct_esdt(
+            &self.blockchain().get_caller(),
+            &position_token_id,
+            position_nonce,
+            &payment.amount,
+        );
+    }
+}
@@ -0,0 +1,333 @@
+mod launchpad_setup;
+
+use ed25519_dalek::{Signer, SigningKey};
+use launchpad_common::{
+    config::{SaleRoundId, FIRST_SALE_ROUND_ID},
+    kyc::{KycApproval, KycModule},
+    sale_rounds::SaleRoundsModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    allowances, LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, TICKET_PRICE,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, ManagedByteArray},
+};
+use multiversx_sc_scenario::{
+    managed_biguint, rust_biguint, testing_framework::TxResult, DebugApi,
+};
+
+const NR_TICKETS: usize = 3;
+const SECOND_SALE_ROUND_ID: SaleRoundId = FIRST_SALE_ROUND_ID + 1;
+const KYC_EXPIRY: u64 = 1_000;
+
+/// `(max tickets, expiry, signature)`, converted into a `KycApproval` within the transaction
+type RawKycApproval = (usize, u64, [u8; 64]);
+
+/// Signs `(contract address, caller, max tickets, expiry)`, nested-encoded
+fn kyc_approval(
+    signing_key: &SigningKey,
+    sc_address: &Address,
+    caller: &Address,
+    max_tickets: usize,
+    expiry: u64,
+) -> RawKycApproval {
+    let mut message = Vec::new();
+    message.extend_from_slice(sc_address.as_bytes());
+    message.extend_from_slice(caller.as_bytes());
+    message.extend_from_slice(&(max_tickets as u32).to_be_bytes());
+    message.extend_from_slice(&expiry.to_be_bytes());
+    let signature = signing_key.sign(&message).to_bytes();
+
+    (max_tickets, expiry, signature)
+}
+
+struct KycSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    lp_setup: LaunchpadSetup<LaunchpadBuilder>,
+    user: Address,
+    other_user: Address,
+    signing_key: SigningKey,
+    second_round_signing_key: SigningKey,
+}
+
+impl<LaunchpadBuilder> KycSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    /// A launchpad in the confirmation period of both its sale rounds,
+    /// each with its own KYC signer
+    fn new(lp_builder: LaunchpadBuilder) -> Self {
+        let mut lp_setup = LaunchpadSetup::new(lp_builder, 1);
+        let user = lp_setup
+            .b_mock
+            .create_user_account(&rust_biguint!(NR_TICKETS as u64 * TICKET_PRICE));
+        let other_user = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let second_round_signing_key = SigningKey::from_bytes(&[8u8; 32]);
+        lp_setup.add_tickets(&[(&user, NR_TICKETS)]).assert_ok();
+
+        lp_setup
+            .b_mock
+            .execute_tx(&lp_setup.owner, &lp_setup.lp_sc, &rust_biguint!(0), |sc| {
+                let _ = sc.add_sale_round(
+                    managed_biguint!(TICKET_PRICE),
+                    1,
+                    CONFIRM_START_ROUND,
+                    WINNER_SELECTION_START_ROUND,
+                    CLAIM_START_ROUND,
+                );
+                sc.add_tickets_for_sale_round(
+                    SECOND_SALE_ROUND_ID,
+                    allowances(&[(&user, NR_TICKETS)]),
+                );
+
+                sc.set_kyc_signer(
+                    ManagedByteArray::new_from_bytes(signing_key.verifying_key().as_bytes()),
+                    OptionalValue::None,
+                );
+                sc.set_kyc_signer(
+                    ManagedByteArray::new_from_bytes(
+                        second_round_signing_key.verifying_key().as_bytes(),
+                    ),
+                    OptionalValue::Some(SECOND_SALE_ROUND_ID),
+                );
+            })
+            .assert_ok();
+
+        lp_setup.nr_winning_tickets = 2;
+        lp_setup.deposit_launchpad_tokens();
+        lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+        lp_setup.b_mock.set_block_timestamp(KYC_EXPIRY);
+
+        Self {
+            lp_setup,
+            user,
+            other_user,
+            signing_key,
+            second_round_signing_key,
+        }
+    }
+
+    /// Signs an approval for the first sale round, to be used by `caller`
+    fn approval(&self, caller: &Address, max_tickets: usize, expiry: u64) -> RawKycApproval {
+        kyc_approval(
+            &self.signing_key,
+            self.lp_setup.lp_sc.address_ref(),
+            caller,
+            max_tickets,
+            expiry,
+        )
+    }
+
+    fn confirm(&mut self, nr_tickets: usize, opt_kyc_approval: Option<RawKycApproval>) -> TxResult {
+        self.confirm_for_sale_round(FIRST_SALE_ROUND_ID, nr_tickets, opt_kyc_approval)
+    }
+
+    fn confirm_for_sale_round(
+        &mut self,
+        sale_round_id: SaleRoundId,
+        nr_tickets: usize,
+        opt_kyc_approval: Option<RawKycApproval>,
+    ) -> TxResult {
+        self.lp_setup.b_mock.execute_tx(
+            &self.user,
+            &self.lp_setup.lp_sc,
+            &rust_biguint!(nr_tickets as u64 * TICKET_PRICE),
+            |sc| {
+                let opt_kyc_approval: OptionalValue<KycApproval<DebugApi>> = opt_kyc_approval
+                    .map(|(max_tickets, expiry, signature)| {
+                        (
+                            max_tickets,
+                            expiry,
+                            ManagedByteArray::new_from_bytes(&signature),
+                        )
+                            .into()
+                    })
+                    .into();
+                sc.confirm_tickets(
+                    nr_tickets,
+                    OptionalValue::Some(sale_round_id),
+                    OptionalValue::None,
+                    opt_kyc_approval,
+                );
+            },
+        )
+    }
+}
+
+#[test]
+fn confirm_without_kyc_approval_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    setup
+        .confirm(1, None)
+        .assert_user_error("KYC approval required");
+}
+
+#[test]
+fn kyc_approval_of_another_user_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let other_user = setup.other_user.clone();
+    let approval = setup.approval(&other_user, 2, KYC_EXPIRY);
+    setup
+        .confirm(1, Some(approval))
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn kyc_approval_with_changed_max_tickets_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let user = setup.user.clone();
+    let (_, _, signature) = setup.approval(&user, 2, KYC_EXPIRY);
+    setup
+        .confirm(1, Some((NR_TICKETS, KYC_EXPIRY, signature)))
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn kyc_approval_of_another_sale_round_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let user = setup.user.clone();
+    let approval = setup.approval(&user, 2, KYC_EXPIRY);
+    setup
+        .confirm_for_sale_round(SECOND_SALE_ROUND_ID, 1, Some(approval))
+        .assert_error(10, "invalid signature");
+
+    let approval = kyc_approval(
+        &setup.second_round_signing_key,
+        setup.lp_setup.lp_sc.address_ref(),
+        &user,
+        2,
+        KYC_EXPIRY,
+    );
+    setup
+        .confirm_for_sale_round(SECOND_SALE_ROUND_ID, 1, Some(approval))
+        .assert_ok();
+}
+
+#[test]
+fn kyc_signer_of_another_sale_round_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let signer = *setup.signing_key.verifying_key().as_bytes();
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_kyc_signer(
+                    ManagedByteArray::new_from_bytes(&signer),
+                    OptionalValue::Some(SECOND_SALE_ROUND_ID),
+                );
+            },
+        )
+        .assert_user_error("KYC signer already used by another sale round");
+}
+
+#[test]
+fn removed_kyc_signer_of_another_sale_round_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let signer = *setup.signing_key.verifying_key().as_bytes();
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_kyc_signer(OptionalValue::None);
+                sc.remove_kyc_signer(OptionalValue::Some(SECOND_SALE_ROUND_ID));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_kyc_signer(
+                    ManagedByteArray::new_from_bytes(&signer),
+                    OptionalValue::Some(SECOND_SALE_ROUND_ID),
+                );
+            },
+        )
+        .assert_user_error("KYC signer already used by another sale round");
+
+    // the removed signer can still be set again for its own sale round
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_kyc_signer(
+                    ManagedByteArray::new_from_bytes(&signer),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn expired_kyc_approval_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let user = setup.user.clone();
+    let approval = setup.approval(&user, 2, KYC_EXPIRY - 1);
+    setup
+        .confirm(1, Some(approval))
+        .assert_user_error("KYC approval expired");
+}
+
+#[test]
+fn confirm_within_kyc_approved_tickets_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    let user = setup.user.clone();
+    let approval = setup.approval(&user, 2, KYC_EXPIRY);
+    setup
+        .confirm(3, Some(approval))
+        .assert_user_error("Trying to confirm more tickets than KYC approved");
+
+    let approval = setup.approval(&user, 2, KYC_EXPIRY);
+    setup.confirm(2, Some(approval)).assert_ok();
+}
+
+#[test]
+fn remove_kyc_signer_test() {
+    let mut setup = KycSetup::new(launchpad::contract_obj);
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_kyc_signer(OptionalValue::None);
+                assert!(sc.kyc_signer(FIRST_SALE_ROUND_ID).is_empty());
+                assert!(!sc.kyc_signer(SECOND_SALE_ROUND_ID).is_empty());
+            },
+        )
+        .assert_ok();
+
+    // without a signer, confirmations no longer need an approval
+    setup.confirm(NR_TICKETS, None).assert_ok();
+    let user = setup.user.clone();
+    setup
+        .lp_setup
+        .b_mock
+        .check_egld_balance(&user, &rust_biguint!(0));
+}
@@ -0,0 +1,121 @@
+// each test file uses a different part of the setup
+#![allow(dead_code)]
+
+use launchpad::Launchpad;
+use launchpad_common::user_interactions::UserInteractionsModule;
+use multiversx_sc::{
+    codec::multi_types::{MultiValue2, OptionalValue},
+    types::{Address, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_egld_token_id, managed_token_id, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    DebugApi,
+};
+
+pub static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+pub const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100_000;
+pub const TICKET_PRICE: u64 = 100;
+pub const CONFIRM_START_ROUND: u64 = 10;
+pub const WINNER_SELECTION_START_ROUND: u64 = 20;
+pub const CLAIM_START_ROUND: u64 = 30;
+
+pub fn allowances(
+    entries: &[(&Address, usize)],
+) -> MultiValueEncoded<DebugApi, MultiValue2<ManagedAddress<DebugApi>, usize>> {
+    let mut tickets = MultiValueEncoded::new();
+    for (address, nr_tickets) in entries {
+        tickets.push((managed_address!(*address), *nr_tickets).into());
+    }
+
+    tickets
+}
+
+pub struct LaunchpadSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner: Address,
+    pub lp_sc: ContractObjWrapper<launchpad::ContractObj<DebugApi>, LaunchpadBuilder>,
+    pub nr_winning_tickets: usize,
+}
+
+impl<LaunchpadBuilder> LaunchpadSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    /// A launchpad in its add tickets period, without any tickets yet
+    pub fn new(lp_builder: LaunchpadBuilder, nr_winning_tickets: usize) -> Self {
+        let rust_zero = rust_biguint!(0);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner = b_mock.create_user_account(&rust_zero);
+        let lp_sc =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), lp_builder, "launchpad wasm");
+
+        b_mock
+            .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                    managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                    managed_egld_token_id!(),
+                    managed_biguint!(TICKET_PRICE),
+                    nr_winning_tickets,
+                    CONFIRM_START_ROUND,
+                    WINNER_SELECTION_START_ROUND,
+                    CLAIM_START_ROUND,
+                );
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner,
+            lp_sc,
+            nr_winning_tickets,
+        }
+    }
+
+    pub fn add_tickets(&mut self, entries: &[(&Address, usize)]) -> TxResult {
+        self.b_mock
+            .execute_tx(&self.owner, &self.lp_sc, &rust_biguint!(0), |sc| {
+                sc.add_tickets_endpoint(allowances(entries));
+            })
+    }
+
+    /// Deposits the launchpad tokens for all the winning tickets
+    pub fn deposit_launchpad_tokens(&mut self) {
+        let total_launchpad_tokens =
+            rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * self.nr_winning_tickets as u64);
+        self.b_mock
+            .set_esdt_balance(&self.owner, LAUNCHPAD_TOKEN_ID, &total_launchpad_tokens);
+        self.b_mock
+            .execute_esdt_transfer(
+                &self.owner,
+                &self.lp_sc,
+                LAUNCHPAD_TOKEN_ID,
+                0,
+                &total_launchpad_tokens,
+                |sc| {
+                    sc.deposit_launchpad_tokens_endpoint();
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn confirm(&mut self, user: &Address, nr_tickets: usize) -> TxResult {
+        self.b_mock.execute_tx(
+            user,
+            &self.lp_sc,
+            &rust_biguint!(nr_tickets as u64 * TICKET_PRICE),
+            |sc| {
+                sc.confirm_tickets(
+                    nr_tickets,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+}
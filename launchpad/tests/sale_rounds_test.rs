@@ -0,0 +1,262 @@
+use launchpad::Launchpad;
+use launchpad_common::{
+    config::{ConfigModule, FIRST_SALE_ROUND_ID},
+    launchpad_stats::LaunchpadStatsModule,
+    sale_rounds::SaleRoundsModule,
+    user_interactions::UserInteractionsModule,
+    winner_selection::WinnerSelectionModule,
+};
+use multiversx_sc::{
+    codec::multi_types::{MultiValue2, OptionalValue},
+    types::{Address, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_egld_token_id, managed_token_id, rust_biguint,
+    testing_framework::BlockchainStateWrapper, DebugApi,
+};
+
+static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100_000;
+const FIRST_ROUND_TICKET_PRICE: u64 = 100;
+const SECOND_ROUND_TICKET_PRICE: u64 = 200;
+const SECOND_SALE_ROUND_ID: u32 = 2;
+
+fn add_tickets(
+    addresses: &[&Address],
+) -> MultiValueEncoded<DebugApi, MultiValue2<ManagedAddress<DebugApi>, usize>> {
+    let mut tickets = MultiValueEncoded::new();
+    for address in addresses {
+        tickets.push((managed_address!(*address), 1).into());
+    }
+
+    tickets
+}
+
+#[test]
+fn multiple_sale_rounds_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_biguint!(
+        FIRST_ROUND_TICKET_PRICE + SECOND_ROUND_TICKET_PRICE
+    ));
+    let second_user = b_mock.create_user_account(&rust_biguint!(SECOND_ROUND_TICKET_PRICE));
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad::contract_obj,
+        "launchpad wasm",
+    );
+
+    // first round is configured at init, the second one is added afterwards
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(FIRST_ROUND_TICKET_PRICE),
+                1,
+                10,
+                20,
+                30,
+            );
+
+            let sale_round_id =
+                sc.add_sale_round(managed_biguint!(SECOND_ROUND_TICKET_PRICE), 2, 40, 50, 60);
+            assert_eq!(sale_round_id, SECOND_SALE_ROUND_ID);
+            assert_eq!(sc.nr_sale_rounds().get(), 2);
+
+            sc.add_tickets_endpoint(add_tickets(&[&first_user]));
+            sc.add_tickets_for_sale_round(
+                SECOND_SALE_ROUND_ID,
+                add_tickets(&[&first_user, &second_user]),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.add_tickets_for_sale_round(3, add_tickets(&[&second_user]));
+        })
+        .assert_user_error("Invalid sale round");
+
+    // the deposit covers the winning tickets of both rounds
+    b_mock.set_esdt_balance(
+        &owner,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(3 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &lp_sc,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+            |sc| {
+                sc.deposit_launchpad_tokens_endpoint();
+            },
+        )
+        .assert_user_error("Wrong amount");
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &lp_sc,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(3 * LAUNCHPAD_TOKENS_PER_TICKET),
+            |sc| {
+                sc.deposit_launchpad_tokens_endpoint();
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.add_sale_round(managed_biguint!(SECOND_ROUND_TICKET_PRICE), 1, 70, 80, 90);
+        })
+        .assert_user_error("Tokens already deposited");
+
+    // first round
+    b_mock.set_block_round(10);
+    b_mock
+        .execute_tx(
+            &first_user,
+            &lp_sc,
+            &rust_biguint!(SECOND_ROUND_TICKET_PRICE),
+            |sc| {
+                sc.confirm_tickets(
+                    1,
+                    OptionalValue::Some(SECOND_SALE_ROUND_ID),
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Not in confirmation period");
+    b_mock
+        .execute_tx(
+            &first_user,
+            &lp_sc,
+            &rust_biguint!(FIRST_ROUND_TICKET_PRICE),
+            |sc| {
+                sc.confirm_tickets(
+                    1,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_mock.set_block_round(20);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.filter_tickets(OptionalValue::None);
+            let _ = sc.select_winners(OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(30);
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&first_user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_user_error("Already claimed");
+    b_mock.check_esdt_balance(
+        &first_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // the ticket payment may only be claimed once all rounds are in the claim period
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.claim_ticket_payment_endpoint();
+        })
+        .assert_user_error("Not in claim period");
+
+    // second round
+    b_mock.set_block_round(40);
+    for user in [&first_user, &second_user] {
+        b_mock
+            .execute_tx(
+                user,
+                &lp_sc,
+                &rust_biguint!(SECOND_ROUND_TICKET_PRICE),
+                |sc| {
+                    sc.confirm_tickets(
+                        1,
+                        OptionalValue::Some(SECOND_SALE_ROUND_ID),
+                        OptionalValue::None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    b_mock.set_block_round(50);
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.filter_tickets(OptionalValue::Some(SECOND_SALE_ROUND_ID));
+            let _ = sc.select_winners(OptionalValue::Some(SECOND_SALE_ROUND_ID));
+
+            assert_eq!(
+                sc.claimable_ticket_payment().get(),
+                managed_biguint!(FIRST_ROUND_TICKET_PRICE + 2 * SECOND_ROUND_TICKET_PRICE)
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(60);
+    for user in [&first_user, &second_user] {
+        b_mock
+            .execute_tx(user, &lp_sc, &rust_zero, |sc| {
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::Some(SECOND_SALE_ROUND_ID));
+            })
+            .assert_ok();
+    }
+    b_mock.check_esdt_balance(
+        &first_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(2 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    b_mock.check_esdt_balance(
+        &second_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.claim_ticket_payment_endpoint();
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(
+        &owner,
+        &rust_biguint!(FIRST_ROUND_TICKET_PRICE + 2 * SECOND_ROUND_TICKET_PRICE),
+    );
+
+    b_mock
+        .execute_query(&lp_sc, |sc| {
+            assert!(sc.has_user_claimed(FIRST_SALE_ROUND_ID, &managed_address!(&first_user)));
+            assert!(sc.has_user_claimed(SECOND_SALE_ROUND_ID, &managed_address!(&first_user)));
+            assert!(!sc.has_user_claimed(FIRST_SALE_ROUND_ID, &managed_address!(&second_user)));
+
+            // users taking part in several rounds are counted once
+            let stats = sc.get_launchpad_stats();
+            assert_eq!(stats.total_tickets, 3);
+            assert_eq!(stats.nr_confirmed_users, 2);
+            assert_eq!(stats.nr_confirmed_tickets, 3);
+            assert_eq!(stats.nr_claimed_users, 2);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,151 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::{ConfigModule, TermsOfSale},
+    setup::SetupModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, TICKET_PRICE};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, ManagedBuffer, ManagedByteArray},
+};
+use multiversx_sc_scenario::{
+    managed_address, rust_biguint, testing_framework::TxResult, DebugApi,
+};
+
+const NR_TICKETS: usize = 3;
+const FIRST_TERMS_HASH: &[u8; 32] = &[1u8; 32];
+const SECOND_TERMS_HASH: &[u8; 32] = &[2u8; 32];
+
+struct TermsOfSaleSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    lp_setup: LaunchpadSetup<LaunchpadBuilder>,
+    user: Address,
+}
+
+impl<LaunchpadBuilder> TermsOfSaleSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    /// A launchpad in its confirmation period, with the first version of the terms of sale published
+    fn new(lp_builder: LaunchpadBuilder) -> Self {
+        let mut lp_setup = LaunchpadSetup::new(lp_builder, 1);
+        let user = lp_setup
+            .b_mock
+            .create_user_account(&rust_biguint!(NR_TICKETS as u64 * TICKET_PRICE));
+        lp_setup.add_tickets(&[(&user, NR_TICKETS)]).assert_ok();
+
+        let mut setup = Self { lp_setup, user };
+        setup.set_terms_of_sale(FIRST_TERMS_HASH, 1).assert_ok();
+        setup.lp_setup.deposit_launchpad_tokens();
+        setup.lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+        setup
+    }
+
+    fn set_terms_of_sale(&mut self, hash: &[u8; 32], version: u32) -> TxResult {
+        self.lp_setup.b_mock.execute_tx(
+            &self.lp_setup.owner,
+            &self.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_terms_of_sale(ManagedByteArray::new_from_bytes(hash), version);
+            },
+        )
+    }
+
+    /// Confirms a ticket, accepting the terms of sale with the given hash, if any
+    fn confirm(&mut self, opt_accepted_terms_hash: Option<&[u8; 32]>) -> TxResult {
+        self.lp_setup.b_mock.execute_tx(
+            &self.user,
+            &self.lp_setup.lp_sc,
+            &rust_biguint!(TICKET_PRICE),
+            |sc| {
+                sc.confirm_tickets(
+                    1,
+                    OptionalValue::None,
+                    opt_accepted_terms_hash
+                        .map(|hash| ManagedBuffer::new_from_bytes(hash))
+                        .into(),
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
+    fn check_accepted_terms_of_sale(&mut self, hash: &[u8; 32], version: u32) {
+        let user = self.user.clone();
+        self.lp_setup
+            .b_mock
+            .execute_query(&self.lp_setup.lp_sc, |sc| {
+                assert_eq!(
+                    sc.accepted_terms_of_sale(&managed_address!(&user)).get(),
+                    TermsOfSale {
+                        hash: ManagedByteArray::new_from_bytes(hash),
+                        version,
+                    }
+                );
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn terms_of_sale_version_must_increase_test() {
+    let mut setup = TermsOfSaleSetup::new(launchpad::contract_obj);
+    setup
+        .set_terms_of_sale(SECOND_TERMS_HASH, 1)
+        .assert_user_error("Terms of sale version must increase");
+}
+
+#[test]
+fn confirm_without_accepting_terms_of_sale_test() {
+    let mut setup = TermsOfSaleSetup::new(launchpad::contract_obj);
+    setup
+        .confirm(None)
+        .assert_user_error("Must accept the current terms of sale");
+}
+
+#[test]
+fn accept_terms_of_sale_hash_mismatch_test() {
+    let mut setup = TermsOfSaleSetup::new(launchpad::contract_obj);
+    setup
+        .confirm(Some(SECOND_TERMS_HASH))
+        .assert_user_error("Terms of sale hash mismatch");
+}
+
+#[test]
+fn accept_terms_of_sale_test() {
+    let mut setup = TermsOfSaleSetup::new(launchpad::contract_obj);
+    setup.confirm(Some(FIRST_TERMS_HASH)).assert_ok();
+    setup.check_accepted_terms_of_sale(FIRST_TERMS_HASH, 1);
+
+    // accepted terms don't need to be passed again
+    setup.confirm(None).assert_ok();
+}
+
+#[test]
+fn accept_new_terms_of_sale_test() {
+    let mut setup = TermsOfSaleSetup::new(launchpad::contract_obj);
+    setup.confirm(Some(FIRST_TERMS_HASH)).assert_ok();
+
+    // new terms must be accepted before confirming more tickets
+    setup.set_terms_of_sale(SECOND_TERMS_HASH, 2).assert_ok();
+    setup
+        .confirm(None)
+        .assert_user_error("Must accept the current terms of sale");
+    setup
+        .confirm(Some(FIRST_TERMS_HASH))
+        .assert_user_error("Terms of sale hash mismatch");
+
+    setup.confirm(Some(SECOND_TERMS_HASH)).assert_ok();
+    setup.check_accepted_terms_of_sale(SECOND_TERMS_HASH, 2);
+    let user = setup.user.clone();
+    setup
+        .lp_setup
+        .b_mock
+        .check_egld_balance(&user, &rust_biguint!(TICKET_PRICE));
+}
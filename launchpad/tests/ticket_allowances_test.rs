@@ -0,0 +1,288 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    config::FIRST_SALE_ROUND_ID,
+    tickets::{TicketsModule, WINNING_TICKET},
+    winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{
+    allowances, LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND,
+    LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID, TICKET_PRICE, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::{codec::multi_types::OptionalValue, types::Address};
+use multiversx_sc_scenario::{managed_address, rust_biguint, DebugApi};
+
+const NR_WINNING_TICKETS: usize = 4;
+
+struct TicketAllowancesSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    lp_setup: LaunchpadSetup<LaunchpadBuilder>,
+    first_user: Address,
+    second_user: Address,
+    third_user: Address,
+}
+
+impl<LaunchpadBuilder> TicketAllowancesSetup<LaunchpadBuilder>
+where
+    LaunchpadBuilder: 'static + Copy + Fn() -> launchpad::ContractObj<DebugApi>,
+{
+    /// Tickets 1-2 for the first user, 3-5 for the second one and 6 for the third one
+    fn new(lp_builder: LaunchpadBuilder) -> Self {
+        let mut lp_setup = LaunchpadSetup::new(lp_builder, NR_WINNING_TICKETS);
+        let first_user = lp_setup
+            .b_mock
+            .create_user_account(&rust_biguint!(3 * TICKET_PRICE));
+        let second_user = lp_setup
+            .b_mock
+            .create_user_account(&rust_biguint!(TICKET_PRICE));
+        let third_user = lp_setup
+            .b_mock
+            .create_user_account(&rust_biguint!(TICKET_PRICE));
+        lp_setup
+            .add_tickets(&[(&first_user, 2), (&second_user, 3), (&third_user, 1)])
+            .assert_ok();
+
+        Self {
+            lp_setup,
+            first_user,
+            second_user,
+            third_user,
+        }
+    }
+
+    /// The first user gets a second batch, 7-8, the second one keeps ticket 3 only,
+    /// and the third one is removed
+    fn set_ticket_allowances(&mut self) {
+        let entries = [
+            (&self.first_user, 4),
+            (&self.second_user, 1),
+            (&self.third_user, 0),
+        ];
+        self.lp_setup
+            .b_mock
+            .execute_tx(
+                &self.lp_setup.owner,
+                &self.lp_setup.lp_sc,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_ticket_allowances_endpoint(allowances(&entries));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// The first user confirms a ticket out of the second batch as well
+    fn confirm_all(&mut self) {
+        self.lp_setup.deposit_launchpad_tokens();
+        self.lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+        self.lp_setup.confirm(&self.first_user, 3).assert_ok();
+        self.lp_setup.confirm(&self.second_user, 1).assert_ok();
+    }
+}
+
+fn ticket_ranges(sc: &launchpad::ContractObj<DebugApi>, user: &Address) -> Vec<(usize, usize)> {
+    sc.get_ticket_range_for_address(&managed_address!(user), OptionalValue::None)
+        .into_iter()
+        .map(|range| range.into_tuple())
+        .collect()
+}
+
+#[test]
+fn add_tickets_duplicate_entry_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    let first_user = setup.first_user.clone();
+    setup
+        .lp_setup
+        .add_tickets(&[(&first_user, 2)])
+        .assert_user_error("Duplicate entry for user");
+}
+
+#[test]
+fn set_ticket_allowances_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    setup.set_ticket_allowances();
+
+    let (first_user, second_user, third_user) = (
+        setup.first_user.clone(),
+        setup.second_user.clone(),
+        setup.third_user.clone(),
+    );
+    setup
+        .lp_setup
+        .b_mock
+        .execute_query(&setup.lp_setup.lp_sc, |sc| {
+            assert_eq!(ticket_ranges(&sc, &first_user), vec![(1, 2), (7, 8)]);
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    &managed_address!(&first_user)
+                ),
+                4
+            );
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(
+                    FIRST_SALE_ROUND_ID,
+                    &managed_address!(&second_user)
+                ),
+                1
+            );
+            assert!(sc
+                .ticket_ranges_for_address(FIRST_SALE_ROUND_ID, &managed_address!(&third_user))
+                .is_empty());
+            assert_eq!(sc.last_ticket_id(FIRST_SALE_ROUND_ID).get(), 8);
+
+            // the third user no longer has tickets and is skipped
+            let ticket_batches: Vec<_> = sc
+                .get_ticket_batches(0, 10, OptionalValue::None)
+                .into_iter()
+                .map(|ticket_batch| {
+                    let ranges: Vec<_> = ticket_batch
+                        .ticket_ranges
+                        .iter()
+                        .map(|ticket_range| (ticket_range.first_id, ticket_range.last_id))
+                        .collect();
+                    (ticket_batch.address, ranges, ticket_batch.nr_tickets)
+                })
+                .collect();
+            assert_eq!(
+                ticket_batches,
+                vec![
+                    (managed_address!(&first_user), vec![(1, 2), (7, 8)], 4),
+                    (managed_address!(&second_user), vec![(3, 3)], 1),
+                ]
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_ticket_allowances_after_add_tickets_period_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    setup.lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    let third_user = setup.third_user.clone();
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_ticket_allowances_endpoint(allowances(&[(&third_user, 1)]));
+            },
+        )
+        .assert_user_error("Add tickets period has passed");
+}
+
+#[test]
+fn confirm_removed_tickets_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    setup.set_ticket_allowances();
+    setup.lp_setup.deposit_launchpad_tokens();
+    setup.lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    let third_user = setup.third_user.clone();
+    setup
+        .lp_setup
+        .confirm(&third_user, 1)
+        .assert_user_error("Trying to confirm too many tickets");
+}
+
+#[test]
+fn filter_tickets_drops_removed_batches_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    setup.set_ticket_allowances();
+    setup.confirm_all();
+
+    setup
+        .lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    let (first_user, second_user) = (setup.first_user.clone(), setup.second_user.clone());
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.filter_tickets(OptionalValue::None);
+
+                assert_eq!(sc.last_ticket_id(FIRST_SALE_ROUND_ID).get(), 4);
+                assert_eq!(ticket_ranges(&sc, &first_user), vec![(1, 2), (4, 4)]);
+                assert_eq!(ticket_ranges(&sc, &second_user), vec![(3, 3)]);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn claim_tickets_from_several_batches_test() {
+    let mut setup = TicketAllowancesSetup::new(launchpad::contract_obj);
+    setup.set_ticket_allowances();
+    setup.confirm_all();
+
+    setup
+        .lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    setup
+        .lp_setup
+        .b_mock
+        .execute_tx(
+            &setup.lp_setup.owner,
+            &setup.lp_setup.lp_sc,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.filter_tickets(OptionalValue::None);
+                let _ = sc.select_winners(OptionalValue::None);
+                for ticket_id in 1..=4 {
+                    assert_eq!(
+                        sc.ticket_status(FIRST_SALE_ROUND_ID, ticket_id).get(),
+                        WINNING_TICKET
+                    );
+                }
+            },
+        )
+        .assert_ok();
+
+    setup.lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    let (first_user, second_user, third_user) = (
+        setup.first_user.clone(),
+        setup.second_user.clone(),
+        setup.third_user.clone(),
+    );
+    for user in [&first_user, &second_user] {
+        setup
+            .lp_setup
+            .b_mock
+            .execute_tx(user, &setup.lp_setup.lp_sc, &rust_biguint!(0), |sc| {
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+            })
+            .assert_ok();
+    }
+    setup.lp_setup.b_mock.check_esdt_balance(
+        &first_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(3 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    setup.lp_setup.b_mock.check_esdt_balance(
+        &second_user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    setup
+        .lp_setup
+        .b_mock
+        .check_egld_balance(&first_user, &rust_biguint!(0));
+    setup
+        .lp_setup
+        .b_mock
+        .check_egld_balance(&third_user, &rust_biguint!(TICKET_PRICE));
+}